@@ -1,16 +1,130 @@
-use chrono::{Days, Local, NaiveDate, NaiveDateTime};
-use crossterm::event::KeyCode;
+use chrono::{Days, Local, NaiveDate, NaiveDateTime, TimeDelta};
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
 use std::path::PathBuf;
-use std::time::{Duration, Instant};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant, SystemTime};
 
-use crate::task::{Task, TaskStatus};
+use crate::cli;
+use crate::config::{Config, DoneForwardAction};
+use crate::draft;
+use crate::log::Log;
+use crate::state::{State, ViewPreferences};
+use crate::stats::{self, Stats};
+use crate::store::TaskStore;
+use crate::task::{DEADLINE_DATE_FORMAT, Priority, REMINDER_FORMAT, Task, TaskStatus};
+use uuid::Uuid;
 
 const DOUBLE_KEY_TIMEOUT: Duration = Duration::from_millis(500);
+const QUIT_CONFIRM_TIMEOUT: Duration = Duration::from_secs(2);
+const DRAFT_SAVE_INTERVAL: Duration = Duration::from_secs(1);
+const HELP_ROTATE_INTERVAL: Duration = Duration::from_secs(5);
+/// Largest motion count kept while digits are typed.
+const MAX_PENDING_COUNT: usize = 9999;
+/// Number of recently selected tasks kept for `Ctrl-O` / `Ctrl-N`.
+const JUMP_LIST_LIMIT: usize = 100;
+/// Number of keybinding groups the help line rotates through.
+pub const HELP_PAGE_COUNT: usize = 12;
+/// Local hour of the reminder set when a task is deferred to tomorrow with `Z`.
+const DEFER_HOUR: u32 = 9;
+/// Labels of the metadata form fields, in `form_fields` order.
+pub const FORM_LABELS: [&str; 2] = ["Name", "Deadline"];
 
 #[derive(PartialEq)]
 pub enum Mode {
     Normal,
     Editing,
+    Search,
+    EditBody,
+    /// Typing a reminder time for the selected task into `input_buffer`.
+    Reminder,
+    /// Editing the selected task's metadata in the form opened with `M`.
+    Form,
+    /// Typing a tag to add to (or, with a leading `-`, remove from) the marked tasks.
+    Tag,
+    /// Typing a creation date range, `<since>..<until>`, to filter the board with.
+    CreatedRange,
+}
+
+/// How the status groups are arranged on screen.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LayoutMode {
+    /// One column per status, side by side.
+    #[default]
+    Columns,
+    /// All statuses stacked in one full-width list, for narrow terminals.
+    Compact,
+}
+
+/// An action that runs only after the user confirms it with `y` (or its `confirm_word`).
+#[derive(Clone, Debug, PartialEq)]
+pub enum PendingAction {
+    ArchiveDone(usize),
+    /// Moves a DONE task back to the previous status of the flow.
+    Uncomplete {
+        id: Uuid,
+        name: String,
+        to: TaskStatus,
+    },
+    /// Permanently deletes every DONE task; confirmed by typing `purge`.
+    PurgeDone(usize),
+    /// Permanently deletes one task, for `n` on a DONE task with `done_forward_action: delete`.
+    DeleteTask {
+        id: Uuid,
+        name: String,
+    },
+}
+
+/// A task-changing action that `.` repeats on the current selection, like vim's dot command.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RepeatableAction {
+    /// `n`: moves the selected task to the next status of the flow.
+    ForwardStatus,
+    /// `N`: moves the selected task to the previous status, asking first if configured.
+    BackwardStatus,
+    /// `J` / `K`: moves the selected task down (`1`) or up (`-1`) within its status.
+    Reorder(isize),
+    /// `D`: marks the selected task DONE and archives it.
+    CompleteAndArchive,
+    /// `a` / `O`: adds another task with the same name to the same status.
+    Add { name: String, status: TaskStatus },
+}
+
+impl PendingAction {
+    /// Returns the question shown in the confirmation popup.
+    pub fn message(&self) -> String {
+        match self {
+            PendingAction::ArchiveDone(count) => format!("Archive {count} done tasks?"),
+            PendingAction::Uncomplete { name, to, .. } => {
+                format!("Move \"{name}\" back to {}?", to.label())
+            }
+            PendingAction::PurgeDone(count) => format!("Permanently delete {count} done tasks?"),
+            PendingAction::DeleteTask { name, .. } => format!("Permanently delete \"{name}\"?"),
+        }
+    }
+
+    /// Returns the confirmation prompt: a yes/no question answered with `confirm_key`, or the
+    /// word to type followed by what has been typed so far.
+    pub fn prompt(&self, confirm_key: char, typed: &str) -> String {
+        match self.confirm_word() {
+            None => format!("{} ({confirm_key}/n)", self.message()),
+            Some(word) => format!(
+                "{} Type \"{word}\" and press Enter: {typed}",
+                self.message()
+            ),
+        }
+    }
+
+    /// Returns the word that must be typed to confirm, for destructive actions where `y` is too easy.
+    pub fn confirm_word(&self) -> Option<&'static str> {
+        match self {
+            PendingAction::PurgeDone(_) => Some("purge"),
+            _ => None,
+        }
+    }
 }
 
 /// Application state and core logic for the TUI.
@@ -19,17 +133,106 @@ pub struct App {
     pub input_mode: Mode,
     pub input_buffer: String,
     pub input_cursor: usize,
+    /// Status the task typed in `Mode::Editing` is created in; `Tab` cycles it.
+    pub add_status: TaskStatus,
+    /// Task that the task being added with `O` is placed right after, if it stays in that status.
+    pub add_after: Option<Uuid>,
     pub tasks: Vec<Task>,
     pub selected_index: Option<usize>,
     pub parking_loaded: bool,
     pub done_loaded: bool,
     pub done_week_start: NaiveDate,
+    /// Whether DONE tasks of every week were loaded only so that `/` can match them.
+    /// They are dropped again when the search is cleared.
+    pub done_loaded_for_search: bool,
+    /// Whether the search replaced a shown DONE week, which clearing the search shows again.
+    pub done_week_before_search: bool,
     pub last_updated_at: NaiveDateTime,
-    pub open_file: Option<PathBuf>,
+    /// Task files for the event loop to open in neovim, one tab each.
+    pub open_files: Vec<PathBuf>,
+    /// Set by `C`; the event loop opens the config file in the editor, then calls
+    /// `after_config_edit`.
+    pub edit_config: bool,
+    /// Text to put on the clipboard, taken by the event loop after the key is handled.
+    pub clipboard: Option<String>,
     pub error_message: Option<String>,
-    pub(crate) tasks_dir: PathBuf,
+    pub notification: Option<String>,
+    pub pending_confirm: Option<PendingAction>,
+    /// Text typed so far for a `PendingAction::confirm_word` confirmation.
+    pub confirm_input: String,
+    pub show_ids: bool,
+    /// Shows date-times converted to UTC instead of local time; toggled with `U`.
+    pub display_utc: bool,
+    /// Column given most of the width while the others collapse; cycled with `z`.
+    pub expanded_panel: Option<TaskStatus>,
+    /// Toggled with `v`.
+    pub layout_mode: LayoutMode,
+    /// False when the `NO_COLOR` environment variable is set to a non-empty value.
+    pub use_color: bool,
+    pub search_query: String,
+    /// Index of the keybinding group shown in the help line.
+    pub help_page: usize,
+    /// Whether the full keybinding overlay (`?`) is open.
+    pub show_help: bool,
+    /// First `cli::KEYBINDINGS` line shown in the overlay; `j` / `k` scroll it.
+    pub help_scroll: usize,
+    /// Board statistics shown in an overlay after `T`; any key closes it.
+    pub stats: Option<Stats>,
+    /// Whether the welcome overlay is shown: nothing has been onboarded yet and there are no tasks.
+    pub first_run: bool,
+    /// Whether the deadline calendar panel is shown; toggled with `c`.
+    pub show_calendar: bool,
+    /// Text of the task body while `Mode::EditBody` is active.
+    pub body_buffer: String,
+    /// Cursor position in `body_buffer`, counted in characters.
+    pub body_cursor: usize,
+    /// Values typed into the metadata form, one per `FORM_LABELS` entry.
+    pub form_fields: [String; FORM_LABELS.len()],
+    /// Index of the form field receiving input.
+    pub form_focus: usize,
+    pub(crate) store: TaskStore,
     pub(crate) persistent_error: Option<String>,
     pub(crate) pending_g_at: Option<Instant>,
+    /// Count typed before a motion, as in vim's `5j`; cleared by any other key.
+    pub pending_count: Option<usize>,
+    /// Task hidden with `x` until `p` pastes it after the selected task.
+    pub cut_task: Option<Uuid>,
+    /// Tasks marked with `Space` for a bulk action such as tagging with `t`.
+    pub marked: Vec<Uuid>,
+    /// Lowest priority shown, cycled with `f`; `None` shows every task.
+    pub priority_filter: Option<Priority>,
+    /// Only tasks carrying this tag are shown; cycled through the tag bar with `#`.
+    pub active_tag_filter: Option<String>,
+    /// Inclusive creation date bounds set with `F`; `(None, None)` shows every task.
+    pub created_range: (Option<NaiveDate>, Option<NaiveDate>),
+    /// Recently selected tasks, oldest first, walked with `Ctrl-O` / `Ctrl-N`.
+    pub(crate) jump_list: Vec<Uuid>,
+    /// Position of the current selection in `jump_list`.
+    pub(crate) jump_cursor: usize,
+    /// Last action `.` repeats.
+    pub(crate) last_action: Option<RepeatableAction>,
+    pub(crate) config: Config,
+    /// Set by `startup` when a probe file cannot be created in the task directory; keys that
+    /// write task files are then refused.
+    pub(crate) read_only: bool,
+    pub(crate) quit_armed: Option<Instant>,
+    /// When `pending_confirm` was asked, for `confirm_timeout_secs`.
+    pub(crate) pending_confirm_at: Option<Instant>,
+    /// The task name last written to the draft file; empty when no draft exists.
+    pub(crate) saved_draft: String,
+    pub(crate) draft_saved_at: Option<Instant>,
+    pub(crate) help_page_shown_at: Instant,
+    /// Reminders up to this time have already been shown.
+    pub(crate) reminders_checked_at: NaiveDateTime,
+    /// Debug log written when `debug_log` is enabled.
+    pub(crate) log: Option<Log>,
+    /// Id, name, and body of the task opened in neovim, compared after the edit.
+    pub(crate) pre_edit: Option<(Uuid, String, String)>,
+    /// Selected task and the modification time its file had when it was selected or last
+    /// written by rem; compared on every tick to notice edits by other programs.
+    pub(crate) watched_file: Option<(Uuid, Option<SystemTime>)>,
+    /// Whether the selected task's file changed on disk since it was loaded; `r` reloads it.
+    pub selected_changed_on_disk: bool,
 }
 
 impl Default for App {
@@ -49,10 +252,17 @@ impl App {
 
     /// Creates an `App` using the provided task storage directory.
     pub fn with_tasks_dir(tasks_dir: PathBuf) -> Self {
+        Self::with_config(Config::with_tasks_dir(tasks_dir))
+    }
+
+    /// Creates an `App` using the provided user settings.
+    pub fn with_config(config: Config) -> Self {
+        let store = TaskStore::new(config.tasks_dir.clone())
+            .with_frontmatter_format(config.frontmatter_format);
         let now = Local::now().naive_local();
         let done_week_start = Task::week_start(now.date());
-        let todo_result = Task::load_todo_from(&tasks_dir);
-        let doing_result = Task::load_doing_from(&tasks_dir);
+        let todo_result = store.load_by_status(&[TaskStatus::Todo]);
+        let doing_result = store.load_by_status(&[TaskStatus::Doing]);
         let error_message = todo_result
             .as_ref()
             .err()
@@ -62,23 +272,220 @@ impl App {
         tasks.extend(doing_result.unwrap_or_default());
         let tasks = Task::sort(tasks);
         let selected_index = if tasks.is_empty() { None } else { Some(0) };
-        Self {
+        let saved_draft = if config.ephemeral {
+            String::new()
+        } else {
+            draft::load(store.root()).ok().flatten().unwrap_or_default()
+        };
+        let log = config
+            .debug_log
+            .then(Log::default_path)
+            .flatten()
+            .map(Log::new);
+        if let (Some(log), Some(error)) = (&log, &error_message) {
+            log.write(error);
+        }
+        let (state, state_backup) = if config.ephemeral {
+            (State::default(), None)
+        } else {
+            State::load_or_recover(store.root())
+        };
+        let first_run = Self::is_first_run(&store, &state);
+        let state_warning = state_backup.map(|backup| {
+            format!(
+                "State file was corrupt; moved it to {} and started fresh",
+                backup.display()
+            )
+        });
+        let external_links_warning = store
+            .external_links()
+            .ok()
+            .filter(|links| !links.is_empty())
+            .map(|links| {
+                format!(
+                    "{} task files link outside {}; edits change the linked files",
+                    links.len(),
+                    store.root().display()
+                )
+            });
+        let mut app = Self {
             should_quit: false,
-            input_mode: Mode::Normal,
-            input_buffer: String::new(),
-            input_cursor: 0,
+            input_mode: if saved_draft.is_empty() {
+                Mode::Normal
+            } else {
+                Mode::Editing
+            },
+            input_buffer: saved_draft.clone(),
+            input_cursor: saved_draft.chars().count(),
+            add_status: TaskStatus::Todo,
+            add_after: None,
             tasks,
             selected_index,
             parking_loaded: false,
             done_loaded: false,
             done_week_start,
+            done_loaded_for_search: false,
+            done_week_before_search: false,
             last_updated_at: now,
-            open_file: None,
+            open_files: Vec::new(),
+            edit_config: false,
+            clipboard: None,
             error_message: error_message.clone(),
-            tasks_dir,
+            notification: state_warning.or(external_links_warning),
+            pending_confirm: None,
+            confirm_input: String::new(),
+            show_ids: state.view.show_ids,
+            display_utc: state.view.display_utc,
+            expanded_panel: state.view.expanded_panel,
+            layout_mode: state.view.layout_mode,
+            use_color: std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()),
+            search_query: String::new(),
+            help_page: 0,
+            show_help: false,
+            help_scroll: 0,
+            stats: None,
+            first_run,
+            show_calendar: state.view.show_calendar,
+            body_buffer: String::new(),
+            body_cursor: 0,
+            form_fields: Default::default(),
+            form_focus: 0,
+            store,
             persistent_error: error_message,
             pending_g_at: None,
+            pending_count: None,
+            cut_task: None,
+            priority_filter: state.view.priority_filter,
+            active_tag_filter: None,
+            created_range: (None, None),
+            marked: Vec::new(),
+            jump_list: Vec::new(),
+            jump_cursor: 0,
+            last_action: None,
+            read_only: false,
+            config,
+            quit_armed: None,
+            pending_confirm_at: None,
+            saved_draft,
+            draft_saved_at: None,
+            help_page_shown_at: Instant::now(),
+            reminders_checked_at: now,
+            log,
+            pre_edit: None,
+            watched_file: None,
+            selected_changed_on_disk: false,
+        };
+        if state.view.show_done {
+            app.toggle_done();
+        }
+        app.select_visible_task();
+        app
+    }
+
+    /// Returns whether the welcome overlay should be shown: it was never dismissed and no
+    /// status directory contains a task file.
+    fn is_first_run(store: &TaskStore, state: &State) -> bool {
+        !state.onboarded
+            && TaskStatus::ALL
+                .iter()
+                .all(|status| store.count(*status).is_ok_and(|count| count == 0))
+    }
+
+    /// Ends the event loop, saving the view preferences to the state file if they changed,
+    /// unless the session is `ephemeral`.
+    ///
+    /// A failed save is only logged: the TUI is closing and there is no line left to show it on.
+    fn finish_quit(&mut self) {
+        self.should_quit = true;
+        if self.config.ephemeral {
+            return;
+        }
+        let mut state = State::load(self.store.root());
+        let view = ViewPreferences {
+            show_ids: self.show_ids,
+            display_utc: self.display_utc,
+            layout_mode: self.layout_mode,
+            expanded_panel: self.expanded_panel,
+            show_calendar: self.show_calendar,
+            show_done: self.done_loaded
+                && (!self.done_loaded_for_search || self.done_week_before_search),
+            priority_filter: self.priority_filter,
+        };
+        if state.view == view {
+            return;
+        }
+        state.view = view;
+        if let Err(error) = state.save(self.store.root()) {
+            self.log(&format!("Failed to save state: {error}"));
+        }
+    }
+
+    /// Hides the welcome overlay and records in the state file that it was seen, unless the
+    /// session is `ephemeral`.
+    fn dismiss_onboarding(&mut self) {
+        self.first_run = false;
+        if self.config.ephemeral {
+            return;
+        }
+        let mut state = State::load(self.store.root());
+        state.onboarded = true;
+        if let Err(error) = state.save(self.store.root()) {
+            self.error_message =
+                Some(self.error_with_persistent(format!("Failed to save state: {error}")));
+        }
+    }
+
+    /// Runs the startup work that writes to disk, which construction leaves to `main`:
+    /// probing that the task directory is writable, then archiving DONE tasks older than
+    /// `auto_archive_done_days`.
+    ///
+    /// A read-only directory switches the app to `read_only` and skips the archive.
+    pub fn startup(&mut self) {
+        if let Err(error) = self.store.check_writable() {
+            let warning = self.error_with_persistent(format!(
+                "{} is not writable ({error}); adding, moving, and editing tasks is disabled",
+                self.store.root().display()
+            ));
+            self.read_only = true;
+            self.persistent_error = Some(warning.clone());
+            self.error_message = Some(warning);
+            return;
         }
+        match Self::auto_archive_done(&self.store, &self.config, Local::now().naive_local()) {
+            Ok(Some(notice)) => {
+                if self.done_loaded {
+                    self.reload_tasks();
+                }
+                self.notification = self.notification.take().or(Some(notice));
+            }
+            Ok(None) => {}
+            Err(error) => {
+                self.error_message =
+                    Some(self.error_with_persistent(format!(
+                        "Failed to archive old DONE tasks: {error}"
+                    )));
+            }
+        }
+    }
+
+    /// Archives DONE tasks older than `auto_archive_done_days`.
+    ///
+    /// Returns a notice when any task was archived.
+    fn auto_archive_done(
+        store: &TaskStore,
+        config: &Config,
+        now: NaiveDateTime,
+    ) -> io::Result<Option<String>> {
+        let Some(days) = config.auto_archive_done_days else {
+            return Ok(None);
+        };
+        // A day count reaching before the earliest representable date archives nothing.
+        let Some(cutoff) = now.checked_sub_signed(TimeDelta::days(i64::from(days))) else {
+            return Ok(None);
+        };
+        let archived = store.archive_done_before(cutoff)?;
+        Ok((archived > 0)
+            .then(|| format!("Archived {archived} DONE tasks completed over {days} days ago")))
     }
 
     /// Loads PARKING tasks once after the first frame has been rendered.
@@ -90,7 +497,7 @@ impl App {
             .selected_index
             .and_then(|index| self.tasks.get(index))
             .map(|task| task.id);
-        let parking_tasks = match Task::load_parking_from(&self.tasks_dir) {
+        let parking_tasks = match self.store.load_by_status(&[TaskStatus::Parking]) {
             Ok(tasks) => tasks,
             Err(error) => {
                 self.error_message = Some(
@@ -100,7 +507,7 @@ impl App {
             }
         };
         self.tasks.extend(parking_tasks);
-        self.tasks = Task::sort(self.tasks.clone());
+        Task::sort_in_place(&mut self.tasks);
         self.parking_loaded = true;
         self.error_message = self.persistent_error.clone();
         self.selected_index = selected_id
@@ -108,17 +515,101 @@ impl App {
             .or_else(|| (!self.tasks.is_empty()).then_some(0));
     }
 
-    /// Dispatches a key event to the appropriate handler based on the current input mode.
+    /// Dispatches a key without modifiers.
     pub fn handle_key_event(&mut self, key_code: KeyCode) {
+        self.handle_key(key_code, KeyModifiers::NONE);
+    }
+
+    /// Dispatches a key event to the appropriate handler based on the current input mode,
+    /// then records the selection in the jump list if the key moved it.
+    ///
+    /// Modifiers are only consulted for `Ctrl-Q`, which quits from any mode, for the jump list
+    /// keys, and by the body editor (`Ctrl-S` saves).
+    pub fn handle_key(&mut self, key_code: KeyCode, modifiers: KeyModifiers) {
+        let previous_id = self.selected_id();
+        let may_write = self.input_mode != Mode::Normal
+            || self.pending_confirm.is_some()
+            || modifies_tasks(key_code)
+            || key_code == KeyCode::Char('r');
+        self.dispatch_key(key_code, modifiers);
+        self.record_jump(previous_id);
+        if may_write {
+            self.forget_watched_file();
+        }
+    }
+
+    fn dispatch_key(&mut self, key_code: KeyCode, modifiers: KeyModifiers) {
+        self.notification = None;
+        if key_code == KeyCode::Char('q') && modifiers.contains(KeyModifiers::CONTROL) {
+            self.force_quit();
+            return;
+        }
         match self.input_mode {
             Mode::Normal => {
+                if self.show_help {
+                    self.scroll_help(key_code);
+                    return;
+                }
+                if self.stats.take().is_some() {
+                    return;
+                }
+                if self.first_run {
+                    self.dismiss_onboarding();
+                    if key_code == KeyCode::Esc {
+                        return;
+                    }
+                }
+                if let Some(action) = self.pending_confirm.take() {
+                    match (action.confirm_word(), key_code) {
+                        (None, KeyCode::Char(c)) if c == self.config.confirm_key => {
+                            self.run_pending_action(action);
+                        }
+                        (None, _) => {}
+                        (Some(_), KeyCode::Char(c)) => {
+                            self.confirm_input.push(c);
+                            self.pending_confirm = Some(action);
+                        }
+                        (Some(_), KeyCode::Backspace) => {
+                            self.confirm_input.pop();
+                            self.pending_confirm = Some(action);
+                        }
+                        (Some(word), KeyCode::Enter) if self.confirm_input == word => {
+                            self.confirm_input.clear();
+                            self.run_pending_action(action);
+                        }
+                        (Some(_), _) => {
+                            self.confirm_input.clear();
+                            self.notification = Some("Cancelled".to_string());
+                        }
+                    }
+                    return;
+                }
+                if modifiers.contains(KeyModifiers::CONTROL) {
+                    self.pending_g_at = None;
+                    self.pending_count = None;
+                    match key_code {
+                        KeyCode::Char('o') => self.jump(-1),
+                        KeyCode::Char('n') => self.jump(1),
+                        _ => {}
+                    }
+                    return;
+                }
+                if let KeyCode::Char(digit @ '0'..='9') = key_code
+                    && (digit != '0' || self.pending_count.is_some())
+                {
+                    let digit = digit.to_digit(10).unwrap_or(0) as usize;
+                    self.pending_count =
+                        Some((self.pending_count.unwrap_or(0) * 10 + digit).min(MAX_PENDING_COUNT));
+                    return;
+                }
                 if key_code == KeyCode::Char('g') {
                     let now = Instant::now();
                     let is_double_g = self.pending_g_at.is_some_and(|started_at| {
                         now.saturating_duration_since(started_at) <= DOUBLE_KEY_TIMEOUT
                     });
                     if is_double_g {
-                        self.select_first();
+                        let row = self.pending_count.take().map_or(0, |count| count - 1);
+                        self.select_row(row);
                         self.pending_g_at = None;
                     } else {
                         self.pending_g_at = Some(now);
@@ -126,36 +617,175 @@ impl App {
                     return;
                 }
                 self.pending_g_at = None;
+                let count = self.pending_count.take();
+                if !matches!(key_code, KeyCode::Char('q') | KeyCode::Esc) {
+                    self.quit_armed = None;
+                }
+                if self.read_only && modifies_tasks(key_code) {
+                    self.notification = Some(
+                        "Task directory is not writable; fix its permissions and restart rem"
+                            .to_string(),
+                    );
+                    return;
+                }
                 match key_code {
-                    KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+                    KeyCode::Esc if !self.search_query.is_empty() => self.clear_search(),
+                    KeyCode::Char('q') | KeyCode::Esc => self.quit(),
+                    KeyCode::Char('/') => self.start_search(),
                     KeyCode::Char('a') => {
                         self.input_mode = Mode::Editing;
                         self.input_buffer.clear();
                         self.input_cursor = 0;
+                        self.add_status = TaskStatus::Todo;
+                        self.add_after = None;
                     }
-                    KeyCode::Char('j') | KeyCode::Down => self.select_next(),
-                    KeyCode::Char('k') | KeyCode::Up => self.select_previous(),
+                    KeyCode::Char('O') => self.start_add_below(),
+                    KeyCode::Char('j') | KeyCode::Down => self.select_next(count.unwrap_or(1)),
+                    KeyCode::Char('k') | KeyCode::Up => self.select_previous(count.unwrap_or(1)),
                     KeyCode::Char('h') | KeyCode::Left => self.select_left(),
                     KeyCode::Char('l') | KeyCode::Right => self.select_right(),
-                    KeyCode::Char('G') => self.select_last(),
-                    KeyCode::Char('n') => self.forward_status(),
-                    KeyCode::Char('N') => self.backward_status(),
+                    KeyCode::Char('G') => match count {
+                        Some(count) => self.select_row(count - 1),
+                        None => self.select_last(),
+                    },
+                    KeyCode::Char('J') => self.perform(RepeatableAction::Reorder(1)),
+                    KeyCode::Char('K') => self.perform(RepeatableAction::Reorder(-1)),
+                    KeyCode::Char('s') => {
+                        self.sort_column_by("name", |task| task.name.to_lowercase());
+                    }
+                    KeyCode::Char('S') => {
+                        self.sort_column_by("deadline", |task| {
+                            (task.deadline, task.name.to_lowercase())
+                        });
+                    }
+                    KeyCode::Tab => self.select_adjacent_column_first(1),
+                    KeyCode::BackTab => self.select_adjacent_column_first(-1),
+                    // A forward step that deletes is confirmed and never repeated with `.`.
+                    KeyCode::Char('n') if self.forward_deletes() => self.forward_status(),
+                    KeyCode::Char('n') => self.perform(RepeatableAction::ForwardStatus),
+                    KeyCode::Char('N') => self.perform(RepeatableAction::BackwardStatus),
                     KeyCode::Char('r') => self.reload_tasks(),
                     KeyCode::Char('d') => self.toggle_done(),
                     KeyCode::Char('[') => self.show_previous_done_week(),
                     KeyCode::Char(']') => self.show_next_done_week(),
+                    KeyCode::Char('A') => self.request_archive_done(),
+                    KeyCode::Char('P') => self.request_purge_done(),
+                    KeyCode::Char('D') => self.perform(RepeatableAction::CompleteAndArchive),
+                    KeyCode::Char('.') => self.repeat_last_action(),
+                    KeyCode::Char('i') => self.show_ids = !self.show_ids,
+                    KeyCode::Char('U') => self.toggle_display_utc(),
+                    KeyCode::Char('c') => self.show_calendar = !self.show_calendar,
+                    KeyCode::Char('z') => self.cycle_expanded_panel(),
+                    KeyCode::Char('v') => {
+                        self.layout_mode = match self.layout_mode {
+                            LayoutMode::Columns => LayoutMode::Compact,
+                            LayoutMode::Compact => LayoutMode::Columns,
+                        };
+                    }
+                    KeyCode::Char('!') => self.next_overdue(),
+                    KeyCode::Char('f') => self.cycle_priority_filter(),
+                    KeyCode::Char('#') => self.cycle_tag_filter(),
+                    KeyCode::Char('F') => self.start_created_range_input(),
+                    KeyCode::Char(' ') => self.toggle_mark(),
+                    KeyCode::Char('t') => self.start_tag_input(),
+                    KeyCode::Char('?') => {
+                        self.show_help = true;
+                        self.help_scroll = 0;
+                    }
+                    KeyCode::Char('T') => self.show_stats(),
+                    KeyCode::Char('e') => self.edit_body(),
+                    KeyCode::Char('R') => self.start_reminder_input(),
+                    KeyCode::Char('Z') => self.defer_to_tomorrow(),
                     KeyCode::Enter => self.open_task(),
+                    KeyCode::Char('o') => self.start_and_open_task(),
+                    KeyCode::Char('W') => self.open_doing_tasks(),
+                    KeyCode::Char('C') => self.edit_config = true,
+                    KeyCode::Char('m') => self.share_task(),
+                    KeyCode::Char('y') => self.copy_file_path(),
+                    KeyCode::Char('I') => self.reassign_selected_id(),
+                    KeyCode::Char('L') => self.follow_link(),
+                    KeyCode::Char('x') => self.cut_selected_task(),
+                    KeyCode::Char('p') => self.paste_task(),
+                    KeyCode::Char('M') => self.start_form(),
                     _ => {}
                 }
             }
             Mode::Editing => match key_code {
                 KeyCode::Enter => {
-                    self.add_task();
+                    self.add_task(self.add_status);
+                }
+                KeyCode::Tab => self.cycle_add_status(1),
+                KeyCode::BackTab => self.cycle_add_status(-1),
+                KeyCode::Esc => {
+                    self.input_buffer.clear();
+                    self.input_cursor = 0;
+                    self.input_mode = Mode::Normal;
+                    self.discard_draft();
+                }
+                KeyCode::Left => self.input_cursor = self.input_cursor.saturating_sub(1),
+                KeyCode::Right => {
+                    self.input_cursor =
+                        (self.input_cursor + 1).min(self.input_buffer.chars().count());
+                }
+                KeyCode::Backspace => self.delete_character_before_cursor(),
+                KeyCode::Char(c) => self.insert_character_at_cursor(c),
+                _ => {}
+            },
+            Mode::Search => match key_code {
+                KeyCode::Enter if self.search_query.is_empty() => self.clear_search(),
+                KeyCode::Enter => self.input_mode = Mode::Normal,
+                KeyCode::Esc => self.clear_search(),
+                KeyCode::Backspace => {
+                    self.search_query.pop();
+                    self.select_visible_task();
+                }
+                KeyCode::Char(c) => {
+                    self.search_query.push(c);
+                    self.select_visible_task();
+                }
+                _ => {}
+            },
+            Mode::Reminder => match key_code {
+                KeyCode::Enter => self.toggle_reminder(),
+                KeyCode::Esc => {
+                    self.input_buffer.clear();
+                    self.input_cursor = 0;
+                    self.input_mode = Mode::Normal;
+                    self.error_message = self.persistent_error.clone();
+                }
+                KeyCode::Left => self.input_cursor = self.input_cursor.saturating_sub(1),
+                KeyCode::Right => {
+                    self.input_cursor =
+                        (self.input_cursor + 1).min(self.input_buffer.chars().count());
+                }
+                KeyCode::Backspace => self.delete_character_before_cursor(),
+                KeyCode::Char(c) => self.insert_character_at_cursor(c),
+                _ => {}
+            },
+            Mode::Tag => match key_code {
+                KeyCode::Enter => self.apply_tag(),
+                KeyCode::Esc => {
+                    self.input_buffer.clear();
+                    self.input_cursor = 0;
+                    self.input_mode = Mode::Normal;
+                    self.error_message = self.persistent_error.clone();
+                }
+                KeyCode::Left => self.input_cursor = self.input_cursor.saturating_sub(1),
+                KeyCode::Right => {
+                    self.input_cursor =
+                        (self.input_cursor + 1).min(self.input_buffer.chars().count());
                 }
+                KeyCode::Backspace => self.delete_character_before_cursor(),
+                KeyCode::Char(c) => self.insert_character_at_cursor(c),
+                _ => {}
+            },
+            Mode::CreatedRange => match key_code {
+                KeyCode::Enter => self.apply_created_range(),
                 KeyCode::Esc => {
                     self.input_buffer.clear();
                     self.input_cursor = 0;
                     self.input_mode = Mode::Normal;
+                    self.error_message = self.persistent_error.clone();
                 }
                 KeyCode::Left => self.input_cursor = self.input_cursor.saturating_sub(1),
                 KeyCode::Right => {
@@ -166,1009 +796,5136 @@ impl App {
                 KeyCode::Char(c) => self.insert_character_at_cursor(c),
                 _ => {}
             },
+            Mode::Form => match key_code {
+                KeyCode::Enter => self.save_form(),
+                KeyCode::Esc => {
+                    self.input_mode = Mode::Normal;
+                    self.error_message = self.persistent_error.clone();
+                }
+                KeyCode::Tab | KeyCode::Down => {
+                    self.form_focus = (self.form_focus + 1) % FORM_LABELS.len();
+                }
+                KeyCode::BackTab | KeyCode::Up => {
+                    self.form_focus = (self.form_focus + FORM_LABELS.len() - 1) % FORM_LABELS.len();
+                }
+                KeyCode::Backspace => {
+                    self.form_fields[self.form_focus].pop();
+                }
+                KeyCode::Char(c) => self.form_fields[self.form_focus].push(c),
+                _ => {}
+            },
+            Mode::EditBody => match key_code {
+                KeyCode::Char('s') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.save_body();
+                }
+                KeyCode::Esc => {
+                    self.body_buffer.clear();
+                    self.body_cursor = 0;
+                    self.input_mode = Mode::Normal;
+                }
+                KeyCode::Enter => {
+                    insert_character(&mut self.body_buffer, &mut self.body_cursor, '\n')
+                }
+                KeyCode::Char(c) => {
+                    insert_character(&mut self.body_buffer, &mut self.body_cursor, c)
+                }
+                KeyCode::Backspace => {
+                    delete_character_before(&mut self.body_buffer, &mut self.body_cursor);
+                }
+                KeyCode::Left => self.body_cursor = self.body_cursor.saturating_sub(1),
+                KeyCode::Right => {
+                    self.body_cursor = (self.body_cursor + 1).min(self.body_buffer.chars().count());
+                }
+                KeyCode::Up => self.move_body_cursor_vertically(-1),
+                KeyCode::Down => self.move_body_cursor_vertically(1),
+                _ => {}
+            },
         }
     }
 
-    fn insert_character_at_cursor(&mut self, character: char) {
-        let byte_index = self
-            .input_buffer
-            .char_indices()
-            .nth(self.input_cursor)
-            .map_or(self.input_buffer.len(), |(index, _)| index);
-        self.input_buffer.insert(byte_index, character);
-        self.input_cursor += 1;
+    /// Inserts pasted text into the active input.
+    ///
+    /// Task names and search queries are single-line, so line breaks become spaces;
+    /// the body editor keeps them. Pastes in Normal mode are ignored.
+    pub fn handle_paste(&mut self, text: &str) {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        match self.input_mode {
+            Mode::Normal => {}
+            Mode::Editing | Mode::Reminder | Mode::Tag | Mode::CreatedRange => text
+                .chars()
+                .map(|c| if c == '\n' { ' ' } else { c })
+                .for_each(|c| self.insert_character_at_cursor(c)),
+            Mode::Search => {
+                self.search_query.push_str(&text.replace('\n', " "));
+                self.select_visible_task();
+            }
+            Mode::Form => self.form_fields[self.form_focus].push_str(&text.replace('\n', " ")),
+            Mode::EditBody => text
+                .chars()
+                .for_each(|c| insert_character(&mut self.body_buffer, &mut self.body_cursor, c)),
+        }
     }
 
-    fn delete_character_before_cursor(&mut self) {
-        if self.input_cursor == 0 {
-            return;
-        }
-        let start = self
-            .input_buffer
-            .char_indices()
-            .nth(self.input_cursor - 1)
-            .map_or(0, |(index, _)| index);
-        let end = self
-            .input_buffer
-            .char_indices()
-            .nth(self.input_cursor)
-            .map_or(self.input_buffer.len(), |(index, _)| index);
-        self.input_buffer.replace_range(start..end, "");
-        self.input_cursor -= 1;
-    }
-
-    /// Moves the cursor to the next task in the current status column.
-    fn select_next(&mut self) {
-        let Some(index) = self.selected_index else {
+    /// Opens the selected task's body in the built-in editor.
+    ///
+    /// Files larger than `body_editor_max_bytes` are refused rather than truncated,
+    /// because saving a truncated buffer would drop the rest of the body.
+    fn edit_body(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.notification = Some("No task selected".to_string());
             return;
         };
-        let status = self.tasks[index].status;
-        let status_indices = self.indices_for_status(status);
-        let row = status_indices
-            .iter()
-            .position(|candidate| *candidate == index)
-            .unwrap_or(0);
-        self.selected_index = status_indices
-            .get((row + 1).min(status_indices.len() - 1))
-            .copied();
+        match self.tasks[index].load_body_up_to(self.config.body_editor_max_bytes) {
+            Ok(body) => {
+                self.body_buffer = body.strip_suffix('\n').unwrap_or(&body).to_string();
+                self.body_cursor = self.body_buffer.chars().count();
+                self.input_mode = Mode::EditBody;
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::FileTooLarge => {
+                self.error_message = Some(self.error_with_persistent(format!(
+                    "Cannot edit body here: {error}; press Enter to open it in neovim"
+                )));
+            }
+            Err(error) => {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to read task body: {error}")));
+            }
+        }
     }
 
-    /// Moves the cursor to the previous task in the current status column.
-    fn select_previous(&mut self) {
-        let Some(index) = self.selected_index else {
+    /// Writes the edited body back to the selected task's file and leaves the editor.
+    ///
+    /// On failure the editor stays open so that no text is lost.
+    fn save_body(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.input_mode = Mode::Normal;
             return;
         };
-        let status = self.tasks[index].status;
-        let status_indices = self.indices_for_status(status);
-        let row = status_indices
-            .iter()
-            .position(|candidate| *candidate == index)
-            .unwrap_or(0);
-        self.selected_index = status_indices.get(row.saturating_sub(1)).copied();
+        let body = if self.body_buffer.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", self.body_buffer)
+        };
+        if let Err(error) = self.tasks[index].save_body(&body) {
+            self.error_message =
+                Some(self.error_with_persistent(format!("Failed to save task body: {error}")));
+            return;
+        }
+        self.body_buffer.clear();
+        self.body_cursor = 0;
+        self.input_mode = Mode::Normal;
+        self.error_message = self.persistent_error.clone();
+        self.notification = Some("Saved task body".to_string());
     }
 
-    fn select_first(&mut self) {
-        let Some(index) = self.selected_index else {
+    /// Opens the reminder prompt for the selected task.
+    fn start_reminder_input(&mut self) {
+        if self.checked_selection().is_none() {
+            self.notification = Some("No task selected".to_string());
             return;
-        };
-        self.selected_index = self
-            .indices_for_status(self.tasks[index].status)
-            .first()
-            .copied();
+        }
+        self.input_mode = Mode::Reminder;
+        self.input_buffer.clear();
+        self.input_cursor = 0;
     }
 
-    fn select_last(&mut self) {
-        let Some(index) = self.selected_index else {
+    /// Marks the selected task for a bulk action, or unmarks it.
+    fn toggle_mark(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.notification = Some("No task selected".to_string());
             return;
         };
-        self.selected_index = self
-            .indices_for_status(self.tasks[index].status)
-            .last()
-            .copied();
+        let id = self.tasks[index].id;
+        match self.marked.iter().position(|marked| *marked == id) {
+            Some(position) => {
+                self.marked.remove(position);
+            }
+            None => self.marked.push(id),
+        }
+        self.notification = Some(format!("{} marked (t: tag)", self.marked.len()));
     }
 
-    fn select_left(&mut self) {
-        self.select_horizontal(-1);
+    /// Returns the indices of the loaded marked tasks, or of the selected task when none is marked.
+    pub(crate) fn tag_targets(&self) -> Vec<usize> {
+        let marked = self
+            .tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, task)| self.marked.contains(&task.id))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        if marked.is_empty() {
+            self.selected_index
+                .filter(|index| *index < self.tasks.len())
+                .into_iter()
+                .collect()
+        } else {
+            marked
+        }
     }
 
-    fn select_right(&mut self) {
-        self.select_horizontal(1);
+    /// Starts typing a tag for the marked tasks, or for the selected task when none is marked.
+    fn start_tag_input(&mut self) {
+        if self.tag_targets().is_empty() {
+            self.notification = Some("No task selected".to_string());
+            return;
+        }
+        self.input_mode = Mode::Tag;
+        self.input_buffer.clear();
+        self.input_cursor = 0;
     }
 
-    fn select_horizontal(&mut self, direction: isize) {
-        let Some(index) = self.selected_index else {
-            return;
+    /// Adds the typed tag to every target task, or removes it when it starts with `-`, saving
+    /// each changed task. Marks are cleared afterwards.
+    fn apply_tag(&mut self) {
+        let input = self.input_buffer.trim();
+        let (remove, tag) = match input.strip_prefix('-') {
+            Some(tag) => (true, tag),
+            None => (false, input),
         };
-        let current_status = self.tasks[index].status;
-        let current_indices = self.indices_for_status(current_status);
-        let row = current_indices
-            .iter()
-            .position(|candidate| *candidate == index)
-            .unwrap_or(0);
-        let statuses = self.visible_statuses();
-        let Some(column) = statuses.iter().position(|status| *status == current_status) else {
+        let Some(tag) = Task::normalize_tag(tag) else {
+            self.error_message = Some(format!(
+                "Invalid tag \"{input}\": use one word, with or without #"
+            ));
             return;
         };
-        let candidates: Box<dyn Iterator<Item = usize>> = if direction < 0 {
-            Box::new((0..column).rev())
+        let mut changed = 0;
+        for index in self.tag_targets() {
+            let mut updated = self.tasks[index].clone();
+            let is_changed = if remove {
+                updated.remove_tag(&tag)
+            } else {
+                updated.add_tag(&tag)
+            };
+            if !is_changed {
+                continue;
+            }
+            if let Err(error) = updated.save_metadata() {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to save tags: {error}")));
+                return;
+            }
+            self.tasks[index] = updated;
+            changed += 1;
+        }
+        self.marked.clear();
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+        self.input_mode = Mode::Normal;
+        self.error_message = self.persistent_error.clone();
+        let tasks = if changed == 1 { "task" } else { "tasks" };
+        self.notification = Some(if remove {
+            format!("Removed #{tag} from {changed} {tasks}")
         } else {
-            Box::new((column + 1)..statuses.len())
-        };
-        self.selected_index = candidates
-            .map(|candidate| self.indices_for_status(statuses[candidate]))
-            .find(|indices| !indices.is_empty())
-            .and_then(|indices| indices.get(row.min(indices.len() - 1)).copied())
-            .or(self.selected_index);
+            format!("Tagged {changed} {tasks} with #{tag}")
+        });
     }
 
-    fn visible_statuses(&self) -> Vec<TaskStatus> {
-        let statuses = [
-            TaskStatus::Parking,
-            TaskStatus::Todo,
-            TaskStatus::Doing,
-            TaskStatus::Done,
+    /// Opens the metadata form filled with the selected task's name and deadline.
+    fn start_form(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.notification = Some("No task selected".to_string());
+            return;
+        };
+        let task = &self.tasks[index];
+        self.form_fields = [
+            task.name.clone(),
+            task.deadline.format(DEADLINE_DATE_FORMAT).to_string(),
         ];
-        statuses
-            .into_iter()
-            .filter(|status| *status != TaskStatus::Done || self.done_loaded)
-            .collect()
+        self.form_focus = 0;
+        self.input_mode = Mode::Form;
     }
 
-    fn indices_for_status(&self, status: TaskStatus) -> Vec<usize> {
-        self.tasks
-            .iter()
-            .enumerate()
-            .filter_map(|(index, task)| (task.status == status).then_some(index))
-            .collect()
+    /// Writes the changed form fields to the selected task's file and closes the form.
+    ///
+    /// An empty name or unparsable deadline keeps the form open with an error.
+    fn save_form(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.input_mode = Mode::Normal;
+            return;
+        };
+        let [name, deadline] = &self.form_fields;
+        let name = Task::sanitize_name(name);
+        if name.is_empty() {
+            self.error_message = Some("Name must not be empty".to_string());
+            return;
+        }
+        let Ok(deadline) = NaiveDate::parse_from_str(deadline.trim(), DEADLINE_DATE_FORMAT)
+            .or_else(|_| NaiveDate::parse_from_str(deadline.trim(), "%Y-%m-%d"))
+        else {
+            self.error_message = Some(format!(
+                "Invalid deadline \"{}\": use YYYY/MM/DD",
+                deadline.trim()
+            ));
+            return;
+        };
+        let task = &self.tasks[index];
+        let changed = [
+            (task.name != name).then_some("name"),
+            (task.deadline != deadline).then_some("deadline"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        self.input_mode = Mode::Normal;
+        self.error_message = self.persistent_error.clone();
+        if changed.is_empty() {
+            self.notification = Some("No changes".to_string());
+            return;
+        }
+        let mut updated = task.clone();
+        updated.name = name;
+        updated.deadline = deadline;
+        updated.updated_at = Local::now().naive_local();
+        if let Err(error) = updated.save_metadata() {
+            self.error_message =
+                Some(self.error_with_persistent(format!("Failed to save task: {error}")));
+            return;
+        }
+        self.tasks[index] = updated;
+        self.notification = Some(format!("Updated {}", changed.join(", ")));
     }
 
-    /// Sets the selected task's file path to `open_file` for neovim to open.
+    /// Adds the typed reminder to the selected task, or removes it if the task already has it.
     ///
-    /// The actual neovim invocation is handled in the main event loop (`main.rs`),
-    /// since terminal control must be managed there.
-    fn open_task(&mut self) {
-        if let Some(index) = self.selected_index {
-            self.open_file = Some(self.tasks[index].file_path());
+    /// An unparsable time keeps the prompt open with an error so it can be corrected.
+    fn toggle_reminder(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.input_mode = Mode::Normal;
+            return;
+        };
+        let Ok(at) = NaiveDateTime::parse_from_str(self.input_buffer.trim(), REMINDER_FORMAT)
+        else {
+            self.error_message = Some(format!(
+                "Invalid reminder \"{}\": use YYYY-MM-DD HH:MM",
+                self.input_buffer.trim()
+            ));
+            return;
+        };
+        let mut updated = self.tasks[index].clone();
+        let added = updated.toggle_reminder(at);
+        if let Err(error) = updated.save_metadata() {
+            self.error_message =
+                Some(self.error_with_persistent(format!("Failed to save reminder: {error}")));
+            return;
         }
+        self.tasks[index] = updated;
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+        self.input_mode = Mode::Normal;
+        self.error_message = self.persistent_error.clone();
+        let at = at.format(REMINDER_FORMAT);
+        self.notification = Some(if added {
+            format!("Reminder set for {at}")
+        } else {
+            format!("Reminder at {at} removed")
+        });
     }
 
-    /// Reloads the selected task's metadata from its markdown file to reflect the latest state in memory.
-    fn reload_selected_task(&mut self) {
-        if let Some(index) = self.selected_index
-            && let Ok(reloaded) = self.tasks[index].reload()
-        {
-            self.tasks[index] = reloaded;
+    /// Scrolls the keybinding overlay with `j` / `k` (or the arrow keys); any other key closes it.
+    fn scroll_help(&mut self, key_code: KeyCode) {
+        let last_line = cli::KEYBINDINGS.lines().count().saturating_sub(1);
+        match key_code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.help_scroll = (self.help_scroll + 1).min(last_line);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.help_scroll = self.help_scroll.saturating_sub(1);
+            }
+            _ => self.show_help = false,
         }
     }
 
-    /// Handles post-edit cleanup after returning from neovim.
-    pub fn after_edit(&mut self) {
-        self.reload_selected_task();
+    /// Moves the selected task's deadline to tomorrow and reminds of it tomorrow morning.
+    fn defer_to_tomorrow(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.notification = Some("No task selected".to_string());
+            return;
+        };
+        let now = Local::now().naive_local();
+        let at = tomorrow_morning(now);
+        let mut updated = self.tasks[index].clone();
+        updated.deadline = at.date();
+        if !updated.reminders.contains(&at) {
+            updated.toggle_reminder(at);
+        }
+        updated.updated_at = now;
+        if let Err(error) = updated.save_metadata() {
+            self.error_message =
+                Some(self.error_with_persistent(format!("Failed to defer task: {error}")));
+            return;
+        }
+        self.notification = Some(format!(
+            "Deferred \"{}\" to {} (reminder at {})",
+            updated.name,
+            at.format(DEADLINE_DATE_FORMAT),
+            at.format("%H:%M")
+        ));
+        self.tasks[index] = updated;
     }
 
-    fn reload_tasks(&mut self) {
-        let selection = self.selected_index.and_then(|index| {
-            let selected = self.tasks.get(index)?;
-            let row = self
-                .indices_for_status(selected.status)
-                .iter()
-                .position(|candidate| *candidate == index)
-                .unwrap_or(0);
-            Some((selected.id, selected.status, row))
-        });
-        let mut loaded_tasks =
-            match Self::load_visible_tasks(&self.tasks_dir, self.done_loaded, self.done_week_start)
-            {
-                Ok(tasks) => tasks,
-                Err(error) => {
-                    self.error_message = Some(
-                        self.error_with_persistent(format!("Failed to reload tasks: {error}")),
-                    );
-                    return;
-                }
-            };
-        loaded_tasks = Task::sort(loaded_tasks);
-        self.tasks = loaded_tasks;
-        self.parking_loaded = true;
-        self.last_updated_at = Local::now().naive_local();
-        self.error_message = self.persistent_error.clone();
-        self.selected_index = selection
-            .and_then(|(id, status, row)| {
-                self.tasks
-                    .iter()
-                    .position(|task| task.id == id)
-                    .or_else(|| self.nearby_selection(status, row))
-            })
-            .or_else(|| (!self.tasks.is_empty()).then_some(0));
+    /// Notifies the first loaded task with a reminder that passed since the last check.
+    fn show_due_reminders(&mut self, now: NaiveDateTime) {
+        let since = self.reminders_checked_at;
+        self.reminders_checked_at = now;
+        if let Some(task) = self
+            .tasks
+            .iter()
+            .find(|task| task.has_reminder_between(since, now))
+        {
+            self.notification = Some(format!("Reminder: {}", task.name));
+        }
     }
 
-    fn load_visible_tasks(
-        tasks_dir: &std::path::Path,
-        done_loaded: bool,
-        done_week_start: NaiveDate,
-    ) -> std::io::Result<Vec<Task>> {
-        let parking_tasks = Task::load_parking_from(tasks_dir)?;
-        let todo_tasks = Task::load_todo_from(tasks_dir)?;
-        let doing_tasks = Task::load_doing_from(tasks_dir)?;
-        let done_tasks = if done_loaded {
-            Task::load_done_for_week_from(tasks_dir, done_week_start)?
-        } else {
-            Vec::new()
+    /// Moves the body cursor to the same column on the previous or next line, clamped to its length.
+    fn move_body_cursor_vertically(&mut self, direction: isize) {
+        let lines = self
+            .body_buffer
+            .split('\n')
+            .map(|line| line.chars().count())
+            .collect::<Vec<_>>();
+        let (mut row, mut column) = (0, self.body_cursor);
+        while column > lines[row] {
+            column -= lines[row] + 1;
+            row += 1;
+        }
+        let Some(target) = row
+            .checked_add_signed(direction)
+            .filter(|target| *target < lines.len())
+        else {
+            return;
         };
-        Ok(parking_tasks
-            .into_iter()
-            .chain(todo_tasks)
-            .chain(doing_tasks)
-            .chain(done_tasks)
-            .collect())
+        let line_start = lines[..target]
+            .iter()
+            .map(|length| length + 1)
+            .sum::<usize>();
+        self.body_cursor = line_start + column.min(lines[target]);
     }
 
-    /// Advances the selected task's status: PARKING -> TODO -> DOING -> DONE.
+    /// Returns whether the task passes the active search filter and is not cut with `x`.
     ///
-    /// Does nothing if the task is already DONE.
-    fn forward_status(&mut self) {
-        if let Some(index) = self.selected_index {
-            let next_status = match self.tasks[index].status {
-                TaskStatus::Parking => TaskStatus::Todo,
-                TaskStatus::Todo => TaskStatus::Doing,
-                TaskStatus::Doing => TaskStatus::Done,
-                TaskStatus::Done => return,
-            };
-            self.change_status(index, next_status);
-        }
+    /// Navigation and rendering both use this, so hidden tasks can never be selected.
+    pub fn is_visible(&self, task: &Task) -> bool {
+        self.cut_task != Some(task.id)
+            && task.matches(&self.search_query)
+            && self
+                .priority_filter
+                .is_none_or(|lowest| task.priority.unwrap_or(Priority::Medium) >= lowest)
+            && self
+                .active_tag_filter
+                .as_ref()
+                .is_none_or(|tag| task.tags.contains(tag))
+            && task.created_between(self.created_range.0, self.created_range.1)
+    }
+
+    /// Hides the selected task until it is pasted with `p`; a previously cut task reappears.
+    fn cut_selected_task(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.notification = Some("No task selected".to_string());
+            return;
+        };
+        let status = self.tasks[index].status;
+        let row = self
+            .indices_for_status(status)
+            .iter()
+            .position(|candidate| *candidate == index)
+            .unwrap_or(0);
+        self.cut_task = Some(self.tasks[index].id);
+        self.notification = Some(format!(
+            "Cut \"{}\" (p: paste after the selected task)",
+            self.tasks[index].name
+        ));
+        self.selected_index = self.nearby_selection(status, row);
     }
 
-    /// Reverts the selected task's status: DONE -> DOING -> TODO -> PARKING.
+    /// Moves the cut task right after the selected one, changing its status if needed.
     ///
-    /// Does nothing if the task is already PARKING.
-    fn backward_status(&mut self) {
-        if let Some(index) = self.selected_index {
-            let next_status = match self.tasks[index].status {
-                TaskStatus::Parking => return,
-                TaskStatus::Todo => TaskStatus::Parking,
-                TaskStatus::Doing => TaskStatus::Todo,
-                TaskStatus::Done => TaskStatus::Doing,
-            };
-            self.change_status(index, next_status);
+    /// Without a selection the cut task simply reappears where it was.
+    fn paste_task(&mut self) {
+        let Some(id) = self.cut_task.take() else {
+            self.notification = Some("Nothing to paste; cut a task with x".to_string());
+            return;
+        };
+        let anchor = self
+            .checked_selection()
+            .map(|index| (self.tasks[index].id, self.tasks[index].status));
+        let Some(index) = self.tasks.iter().position(|task| task.id == id) else {
+            self.notification = Some("The cut task no longer exists".to_string());
+            return;
+        };
+        if let Some((_, status)) = anchor
+            && self.tasks[index].status != status
+        {
+            self.change_status(index, status);
+        }
+        if let Some((anchor_id, _)) = anchor {
+            self.place_after(id, anchor_id);
+        }
+        if let Some(index) = self.tasks.iter().position(|task| task.id == id) {
+            self.selected_index = Some(index);
+            self.notification = Some(format!("Pasted \"{}\"", self.tasks[index].name));
         }
     }
 
-    fn change_status(&mut self, index: usize, next_status: TaskStatus) {
-        let id = self.tasks[index].id;
-        let previous_status = self.tasks[index].status;
-        let previous_indices = self.indices_for_status(previous_status);
-        let previous_row = previous_indices
-            .iter()
-            .position(|candidate| *candidate == index)
-            .unwrap_or(0);
-        if let Err(error) = self.tasks[index].update_status(next_status) {
-            self.error_message =
-                Some(self.error_with_persistent(format!("Failed to update task status: {error}")));
+    /// Enters search mode, loading DONE tasks of every week if they are not shown yet
+    /// so that the filter covers the whole board.
+    ///
+    /// They are read once per search rather than on every keystroke.
+    fn start_search(&mut self) {
+        self.input_mode = Mode::Search;
+        if self.done_loaded_for_search {
             return;
         }
-        self.error_message = self.persistent_error.clone();
-        if next_status == TaskStatus::Done {
-            let done_week_end = self
-                .done_week_start
-                .checked_add_days(Days::new(7))
-                .expect("done week end should be a valid date");
-            let belongs_to_visible_done_week = self.done_loaded
-                && self.tasks[index].completed_at.is_some_and(|completed_at| {
-                    let completed_date = completed_at.date();
-                    completed_date >= self.done_week_start && completed_date < done_week_end
-                });
-            if !belongs_to_visible_done_week {
-                self.tasks.retain(|task| task.id != id);
-                self.tasks = Task::sort(self.tasks.clone());
-                self.selected_index = self.nearby_selection(previous_status, previous_row);
-                return;
+        let selected_id = self
+            .selected_index
+            .and_then(|index| self.tasks.get(index))
+            .map(|task| task.id);
+        match self.store.load_by_status(&[TaskStatus::Done]) {
+            Ok(done_tasks) => {
+                self.tasks.retain(|task| task.status != TaskStatus::Done);
+                self.tasks.extend(done_tasks);
+                Task::sort_in_place(&mut self.tasks);
+                self.selected_index =
+                    selected_id.and_then(|id| self.tasks.iter().position(|task| task.id == id));
+                self.done_week_before_search = self.done_loaded;
+                self.done_loaded = true;
+                self.done_loaded_for_search = true;
+            }
+            Err(error) => {
+                self.error_message = Some(self.error_with_persistent(format!(
+                    "Failed to load DONE tasks for search: {error}"
+                )));
             }
         }
-        self.tasks = Task::sort(self.tasks.clone());
-        self.selected_index = self.tasks.iter().position(|task| task.id == id);
     }
 
-    fn nearby_selection(&self, preferred_status: TaskStatus, row: usize) -> Option<usize> {
-        let preferred = self.indices_for_status(preferred_status);
-        if !preferred.is_empty() {
-            return preferred.get(row.min(preferred.len() - 1)).copied();
+    /// Clears the filter and drops DONE tasks that were only loaded for the search, going
+    /// back to the DONE week that was shown before it, if any.
+    fn clear_search(&mut self) {
+        self.search_query.clear();
+        self.input_mode = Mode::Normal;
+        let restored_week =
+            std::mem::take(&mut self.done_week_before_search) && self.load_done_week();
+        if self.done_loaded_for_search && !restored_week {
+            let selected_id = self
+                .selected_index
+                .and_then(|index| self.tasks.get(index))
+                .map(|task| task.id);
+            self.tasks.retain(|task| task.status != TaskStatus::Done);
+            self.done_loaded = false;
+            self.done_loaded_for_search = false;
+            self.selected_index =
+                selected_id.and_then(|id| self.tasks.iter().position(|task| task.id == id));
         }
-        let statuses = self.visible_statuses();
-        let preferred_column = statuses
-            .iter()
-            .position(|status| *status == preferred_status)?;
-        (1..statuses.len())
-            .flat_map(|distance| {
-                [
-                    preferred_column.checked_sub(distance),
-                    preferred_column
-                        .checked_add(distance)
-                        .filter(|column| *column < statuses.len()),
-                ]
-            })
-            .flatten()
-            .map(|column| self.indices_for_status(statuses[column]))
-            .find(|indices| !indices.is_empty())
-            .and_then(|indices| indices.get(row.min(indices.len() - 1)).copied())
+        self.select_visible_task();
     }
 
-    /// Creates a new task from the input buffer and saves it to the filesystem.
-    ///
-    /// Clears the input buffer and returns to Normal mode after completion.
-    fn add_task(&mut self) {
-        if !self.input_buffer.is_empty() {
-            let new_task = Task::new_in(self.input_buffer.clone(), self.tasks_dir.clone());
-            if let Err(error) = new_task.save() {
-                self.error_message =
-                    Some(self.error_with_persistent(format!("Failed to add task: {error}")));
-                return;
+    /// Filters the board to the next tag of the tag bar; after the last tag, shows all tasks again.
+    fn cycle_tag_filter(&mut self) {
+        let tags = Task::collect_tags(&self.tasks);
+        let next = match &self.active_tag_filter {
+            None => tags.first(),
+            Some(active) => tags
+                .iter()
+                .position(|(tag, _)| tag == active)
+                .and_then(|position| tags.get(position + 1)),
+        };
+        self.active_tag_filter = next.map(|(tag, _)| tag.clone());
+        self.notification = Some(match (&self.active_tag_filter, tags.is_empty()) {
+            (Some(tag), _) => format!("Showing #{tag}"),
+            (None, true) => "No tags (t: tag tasks)".to_string(),
+            (None, false) => "Showing all tags".to_string(),
+        });
+        self.select_visible_task();
+    }
+
+    /// Opens the statistics overlay, computed from every task on disk so that PARKING and
+    /// DONE tasks of past weeks count even when they are not loaded.
+    fn show_stats(&mut self) {
+        match self.store.load_all() {
+            Ok(tasks) => {
+                self.stats = Some(stats::compute_stats(&tasks, Local::now().naive_local()));
             }
-            self.tasks.push(new_task);
-            self.tasks = Task::sort(self.tasks.clone());
-            if self.selected_index.is_none() {
-                self.selected_index = Some(0);
+            Err(error) => {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to load tasks: {error}")));
             }
         }
+    }
+
+    /// Cycles the priority filter: all tasks, medium and high, high only, then all again.
+    fn cycle_priority_filter(&mut self) {
+        self.priority_filter = match self.priority_filter {
+            None => Some(Priority::Medium),
+            Some(Priority::Medium) => Some(Priority::High),
+            Some(_) => None,
+        };
+        self.notification = Some(match self.priority_filter {
+            Some(Priority::High) => "Showing high priority tasks".to_string(),
+            Some(lowest) => format!("Showing {} priority and above", lowest.label()),
+            None => "Showing all priorities".to_string(),
+        });
+        self.select_visible_task();
+    }
+
+    /// Starts typing the creation date range, filled in with the current one.
+    fn start_created_range_input(&mut self) {
+        self.input_buffer = match self.created_range {
+            (None, None) => String::new(),
+            (since, until) => format_created_range(since, until),
+        };
+        self.input_cursor = self.input_buffer.chars().count();
+        self.input_mode = Mode::CreatedRange;
+    }
+
+    /// Filters the board to tasks created within the typed range; an empty range shows all.
+    fn apply_created_range(&mut self) {
+        let (since, until) = match cli::parse_date_range(&self.input_buffer) {
+            Ok(range) => range,
+            Err(error) => {
+                self.error_message = Some(format!("Invalid date range: {error}"));
+                return;
+            }
+        };
+        self.created_range = (since, until);
         self.input_buffer.clear();
         self.input_cursor = 0;
         self.input_mode = Mode::Normal;
         self.error_message = self.persistent_error.clone();
+        self.notification = Some(match (since, until) {
+            (None, None) => "Showing tasks created at any time".to_string(),
+            (since, until) => format!(
+                "Showing tasks created {}",
+                format_created_range(since, until)
+            ),
+        });
+        self.select_visible_task();
     }
 
-    /// Toggles the visibility of DONE tasks.
-    ///
-    /// When enabled, loads DONE tasks from the filesystem and appends them to the task list.
-    /// When disabled, removes all DONE tasks from the in-memory list.
-    fn toggle_done(&mut self) {
-        if self.done_loaded {
-            let selection = self.selected_index.and_then(|index| {
-                let status = self.tasks[index].status;
-                let row = self
-                    .indices_for_status(status)
-                    .iter()
-                    .position(|candidate| *candidate == index)?;
-                Some((status, row))
-            });
-            self.tasks.retain(|t| t.status != TaskStatus::Done);
-            self.done_loaded = false;
-            self.done_week_start = Task::week_start(Local::now().date_naive());
-            if selection.is_some_and(|(status, _)| status == TaskStatus::Done) {
-                self.selected_index =
-                    selection.and_then(|(_, row)| self.nearby_selection(TaskStatus::Doing, row));
-            }
-        } else {
-            self.done_week_start = Task::week_start(Local::now().date_naive());
-            if !self.load_done_week() {
-                return;
-            }
-            self.done_loaded = true;
+    /// Keeps the selection on a visible task after the filter changes.
+    fn select_visible_task(&mut self) {
+        let selected = self
+            .selected_index
+            .and_then(|index| self.tasks.get(index))
+            .map(|task| (self.is_visible(task), task.status));
+        self.selected_index = match selected {
+            Some((true, _)) => self.selected_index,
+            Some((false, status)) => self.nearby_selection(status, 0),
+            None => self.nearby_selection(TaskStatus::Todo, 0),
+        };
+    }
+
+    /// Expires time-limited prompts, rotates the help line, and saves the input draft.
+    /// Called on every iteration of the event loop.
+    pub fn tick(&mut self) {
+        if self
+            .quit_armed
+            .is_some_and(|armed_at| armed_at.elapsed() > QUIT_CONFIRM_TIMEOUT)
+        {
+            self.quit_armed = None;
+            self.notification = None;
         }
-        if self.tasks.is_empty() {
-            self.selected_index = None;
-        } else if let Some(i) = self.selected_index
-            && i >= self.tasks.len()
+        if self.config.confirm_timeout_secs > 0
+            && self.pending_confirm.is_some()
+            && self.pending_confirm_at.is_some_and(|asked_at| {
+                asked_at.elapsed() >= Duration::from_secs(self.config.confirm_timeout_secs)
+            })
         {
-            self.selected_index = Some(self.tasks.len() - 1);
+            self.pending_confirm = None;
+            self.confirm_input.clear();
+            self.notification = Some("Cancelled".to_string());
         }
-    }
-
-    fn show_previous_done_week(&mut self) {
-        if !self.done_loaded {
-            return;
+        if self.input_mode == Mode::Normal
+            && self.help_page_shown_at.elapsed() >= HELP_ROTATE_INTERVAL
+        {
+            self.help_page = (self.help_page + 1) % HELP_PAGE_COUNT;
+            self.help_page_shown_at = Instant::now();
         }
-        let previous_week_start = self
-            .done_week_start
-            .checked_sub_days(Days::new(7))
-            .expect("previous week should be a valid date");
-        let current_week_start = self.done_week_start;
-        self.done_week_start = previous_week_start;
-        if !self.load_done_week() {
-            self.done_week_start = current_week_start;
-            return;
+        self.show_due_reminders(Local::now().naive_local());
+        self.check_selected_file();
+        if self.input_mode == Mode::Editing
+            && !self.config.ephemeral
+            && self.input_buffer != self.saved_draft
+            && self
+                .draft_saved_at
+                .is_none_or(|saved_at| saved_at.elapsed() >= DRAFT_SAVE_INTERVAL)
+        {
+            self.save_draft();
         }
-        self.select_done_column();
     }
 
-    fn show_next_done_week(&mut self) {
-        if !self.done_loaded {
+    /// Marks the selected task as changed on disk when its file's modification time differs
+    /// from the one recorded when it was selected; selecting another task starts over.
+    fn check_selected_file(&mut self) {
+        let Some(task) = self.selected_index.and_then(|index| self.tasks.get(index)) else {
+            self.forget_watched_file();
             return;
+        };
+        let modified = fs::metadata(task.file_path())
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        match self.watched_file {
+            Some((id, recorded)) if id == task.id => {
+                // A file that appeared or disappeared counts as changed too.
+                self.selected_changed_on_disk = recorded != modified;
+            }
+            _ => {
+                self.watched_file = Some((task.id, modified));
+                self.selected_changed_on_disk = false;
+            }
         }
-        let current_week_start = Task::week_start(Local::now().date_naive());
-        let next_week_start = self
-            .done_week_start
-            .checked_add_days(Days::new(7))
-            .expect("next week should be a valid date");
-        if next_week_start > current_week_start {
+    }
+
+    /// Drops the recorded modification time after rem itself wrote or reloaded tasks, so that
+    /// its own writes are not reported as outside edits.
+    fn forget_watched_file(&mut self) {
+        self.watched_file = None;
+        self.selected_changed_on_disk = false;
+    }
+
+    /// Persists the input buffer so that a crash does not lose a half-typed task name.
+    ///
+    /// Failures are retried on a later tick; the draft is best-effort and never blocks input.
+    fn save_draft(&mut self) {
+        self.draft_saved_at = Some(Instant::now());
+        let result = if self.input_buffer.is_empty() {
+            draft::clear(self.store.root())
+        } else {
+            draft::save(self.store.root(), &self.input_buffer)
+        };
+        if result.is_ok() {
+            self.saved_draft = self.input_buffer.clone();
+        }
+    }
+
+    fn discard_draft(&mut self) {
+        if !self.saved_draft.is_empty() && draft::clear(self.store.root()).is_ok() {
+            self.saved_draft.clear();
+        }
+    }
+
+    /// Quits immediately, or on the second press within the timeout when `confirm_on_quit` is set.
+    fn quit(&mut self) {
+        self.confirm_quit("q");
+    }
+
+    /// Quits from any mode with `Ctrl-Q`, discarding the task name, search, or body being typed.
+    ///
+    /// Nothing is discarded until the quit is confirmed, so a single press with
+    /// `confirm_on_quit` set leaves the current input intact.
+    fn force_quit(&mut self) {
+        self.confirm_quit("Ctrl-Q");
+        if !self.should_quit {
             return;
         }
-        let current_done_week_start = self.done_week_start;
-        self.done_week_start = next_week_start;
-        if !self.load_done_week() {
-            self.done_week_start = current_done_week_start;
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+        self.body_buffer.clear();
+        self.body_cursor = 0;
+        self.input_mode = Mode::Normal;
+        self.discard_draft();
+    }
+
+    fn confirm_quit(&mut self, key: &str) {
+        if !self.config.confirm_on_quit {
+            self.finish_quit();
             return;
         }
-        self.select_done_column();
+        let now = Instant::now();
+        let is_confirmed = self.quit_armed.is_some_and(|armed_at| {
+            now.saturating_duration_since(armed_at) <= QUIT_CONFIRM_TIMEOUT
+        });
+        if is_confirmed {
+            self.finish_quit();
+        } else {
+            self.quit_armed = Some(now);
+            self.notification = Some(format!("Press {key} again to quit"));
+        }
     }
 
-    fn load_done_week(&mut self) -> bool {
-        let selected_id = self
-            .selected_index
-            .and_then(|index| self.tasks.get(index))
-            .map(|task| task.id);
-        let done_tasks = match Task::load_done_for_week_from(&self.tasks_dir, self.done_week_start)
+    fn insert_character_at_cursor(&mut self, character: char) {
+        insert_character(&mut self.input_buffer, &mut self.input_cursor, character);
+    }
+
+    fn delete_character_before_cursor(&mut self) {
+        delete_character_before(&mut self.input_buffer, &mut self.input_cursor);
+    }
+
+    /// Moves the cursor down `steps` tasks in the current status column, stopping at the last one.
+    fn select_next(&mut self, steps: usize) {
+        let Some(index) = self.selected_index else {
+            return;
+        };
+        let status = self.tasks[index].status;
+        let status_indices = self.indices_for_status(status);
+        let row = status_indices
+            .iter()
+            .position(|candidate| *candidate == index)
+            .unwrap_or(0);
+        self.selected_index = status_indices
+            .get(row.saturating_add(steps).min(status_indices.len() - 1))
+            .copied();
+    }
+
+    /// Moves the cursor up `steps` tasks in the current status column, stopping at the first one.
+    fn select_previous(&mut self, steps: usize) {
+        let Some(index) = self.selected_index else {
+            return;
+        };
+        let status = self.tasks[index].status;
+        let status_indices = self.indices_for_status(status);
+        let row = status_indices
+            .iter()
+            .position(|candidate| *candidate == index)
+            .unwrap_or(0);
+        self.selected_index = status_indices.get(row.saturating_sub(steps)).copied();
+    }
+
+    /// Selects the task at `row` (0-based) in the current status column, clamped to its length.
+    fn select_row(&mut self, row: usize) {
+        let Some(index) = self.selected_index else {
+            return;
+        };
+        let status_indices = self.indices_for_status(self.tasks[index].status);
+        self.selected_index = status_indices
+            .get(row.min(status_indices.len() - 1))
+            .copied();
+    }
+
+    fn select_last(&mut self) {
+        let Some(index) = self.selected_index else {
+            return;
+        };
+        self.selected_index = self
+            .indices_for_status(self.tasks[index].status)
+            .last()
+            .copied();
+    }
+
+    fn select_left(&mut self) {
+        self.select_horizontal(-1);
+    }
+
+    fn select_right(&mut self) {
+        self.select_horizontal(1);
+    }
+
+    fn select_horizontal(&mut self, direction: isize) {
+        let Some(index) = self.selected_index else {
+            return;
+        };
+        let current_status = self.tasks[index].status;
+        let current_indices = self.indices_for_status(current_status);
+        let row = current_indices
+            .iter()
+            .position(|candidate| *candidate == index)
+            .unwrap_or(0);
+        let statuses = self.visible_statuses();
+        let Some(column) = statuses.iter().position(|status| *status == current_status) else {
+            return;
+        };
+        self.selected_index = Self::columns_towards(column, statuses.len(), direction)
+            .map(|candidate| self.indices_for_status(statuses[candidate]))
+            .find(|indices| !indices.is_empty())
+            .and_then(|indices| indices.get(row.min(indices.len() - 1)).copied())
+            .or(self.selected_index);
+    }
+
+    /// Moves the cursor to the first task of the next (`Tab`) or previous (`Shift-Tab`) non-empty column.
+    fn select_adjacent_column_first(&mut self, direction: isize) {
+        let Some(task) = self.selected_index.and_then(|index| self.tasks.get(index)) else {
+            return;
+        };
+        let status = task.status;
+        let statuses = self.visible_statuses();
+        let Some(column) = statuses.iter().position(|candidate| *candidate == status) else {
+            return;
+        };
+        self.selected_index = Self::columns_towards(column, statuses.len(), direction)
+            .find_map(|candidate| {
+                self.indices_for_status(statuses[candidate])
+                    .first()
+                    .copied()
+            })
+            .or(self.selected_index);
+    }
+
+    /// Returns the column positions from `column` towards the given direction, nearest first.
+    fn columns_towards(
+        column: usize,
+        column_count: usize,
+        direction: isize,
+    ) -> Box<dyn Iterator<Item = usize>> {
+        if direction < 0 {
+            Box::new((0..column).rev())
+        } else {
+            Box::new((column + 1)..column_count)
+        }
+    }
+
+    /// Expands the next visible column, returning to equal widths after the last one.
+    fn cycle_expanded_panel(&mut self) {
+        let statuses = self.visible_statuses();
+        self.expanded_panel = match self
+            .expanded_panel
+            .and_then(|expanded| statuses.iter().position(|status| *status == expanded))
         {
-            Ok(tasks) => tasks,
-            Err(error) => {
-                self.error_message =
-                    Some(self.error_with_persistent(format!("Failed to load DONE tasks: {error}")));
-                return false;
+            Some(column) => statuses.get(column + 1).copied(),
+            None => statuses.first().copied(),
+        };
+    }
+
+    fn visible_statuses(&self) -> Vec<TaskStatus> {
+        TaskStatus::ALL
+            .into_iter()
+            .filter(|status| *status != TaskStatus::Done || self.done_loaded)
+            .collect()
+    }
+
+    /// Returns the selected index if it still points into `tasks`.
+    ///
+    /// A stale index left behind by a list mutation is cleared instead of being used.
+    fn checked_selection(&mut self) -> Option<usize> {
+        let index = self.selected_index?;
+        if index < self.tasks.len() {
+            return Some(index);
+        }
+        self.selected_index = None;
+        None
+    }
+
+    /// Selects the next visible task, in board order and wrapping around, whose deadline has passed.
+    ///
+    /// DONE tasks are never overdue. The selection stays put when nothing is overdue.
+    fn next_overdue(&mut self) {
+        let today = Local::now().date_naive();
+        let board = self
+            .visible_statuses()
+            .into_iter()
+            .flat_map(|status| self.indices_for_status(status))
+            .collect::<Vec<_>>();
+        let start = self
+            .checked_selection()
+            .and_then(|index| board.iter().position(|candidate| *candidate == index))
+            .map_or(0, |row| row + 1);
+        let next = (0..board.len())
+            .map(|offset| board[(start + offset) % board.len()])
+            .find(|index| {
+                let task = &self.tasks[*index];
+                task.status != TaskStatus::Done && task.deadline < today
+            });
+        match next {
+            Some(index) => self.selected_index = Some(index),
+            None => self.notification = Some("No overdue tasks".to_string()),
+        }
+    }
+
+    /// Switches date-time display between local time and UTC; task files are unaffected.
+    fn toggle_display_utc(&mut self) {
+        self.display_utc = !self.display_utc;
+        self.notification = Some(
+            if self.display_utc {
+                "Showing times in UTC"
+            } else {
+                "Showing times in local time"
             }
+            .to_string(),
+        );
+    }
+
+    fn selected_id(&self) -> Option<Uuid> {
+        self.selected_index
+            .and_then(|index| self.tasks.get(index))
+            .map(|task| task.id)
+    }
+
+    /// Appends the current selection to the jump list when it differs from `previous_id`.
+    ///
+    /// Selections reached with `jump` already sit under `jump_cursor` and are not recorded
+    /// again. Entries after the cursor are dropped, as in vim's jump list.
+    fn record_jump(&mut self, previous_id: Option<Uuid>) {
+        let Some(current_id) = self.selected_id() else {
+            return;
         };
-        self.tasks.retain(|task| task.status != TaskStatus::Done);
-        self.tasks.extend(done_tasks);
-        self.tasks = Task::sort(self.tasks.clone());
-        self.selected_index = selected_id
-            .and_then(|id| self.tasks.iter().position(|task| task.id == id))
-            .or_else(|| {
-                self.tasks
-                    .iter()
-                    .position(|task| task.status == TaskStatus::Done)
+        if previous_id == Some(current_id)
+            || self.jump_list.get(self.jump_cursor) == Some(&current_id)
+        {
+            return;
+        }
+        self.jump_list.truncate(self.jump_cursor + 1);
+        if let Some(previous_id) = previous_id
+            && self.jump_list.last() != Some(&previous_id)
+        {
+            self.jump_list.push(previous_id);
+        }
+        self.jump_list.push(current_id);
+        let overflow = self.jump_list.len().saturating_sub(JUMP_LIST_LIMIT);
+        self.jump_list.drain(..overflow);
+        self.jump_cursor = self.jump_list.len() - 1;
+    }
+
+    /// Selects the next older (`-1`) or newer (`1`) task in the jump list, skipping tasks that
+    /// were deleted or are hidden by the search filter.
+    fn jump(&mut self, direction: isize) {
+        let mut cursor = self.jump_cursor;
+        while let Some(next) = cursor
+            .checked_add_signed(direction)
+            .filter(|next| *next < self.jump_list.len())
+        {
+            cursor = next;
+            let id = self.jump_list[cursor];
+            if let Some(index) = self
+                .tasks
+                .iter()
+                .position(|task| task.id == id && self.is_visible(task))
+            {
+                self.jump_cursor = cursor;
+                self.selected_index = Some(index);
+                return;
+            }
+        }
+        self.notification = Some(
+            if direction < 0 {
+                "Already at the oldest task"
+            } else {
+                "Already at the newest task"
+            }
+            .to_string(),
+        );
+    }
+
+    fn indices_for_status(&self, status: TaskStatus) -> Vec<usize> {
+        self.tasks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, task)| {
+                (task.status == status && self.is_visible(task)).then_some(index)
             })
-            .or_else(|| (!self.tasks.is_empty()).then_some(0));
-        self.error_message = self.persistent_error.clone();
-        true
+            .collect()
     }
 
-    fn select_done_column(&mut self) {
-        self.selected_index = self
+    /// Swaps the selected task with its visible neighbor and persists the column's manual order.
+    ///
+    /// Every task in the column is renumbered, but only files whose rank changed are rewritten.
+    fn move_within_status(&mut self, direction: isize) {
+        let Some(index) = self.selected_index else {
+            return;
+        };
+        let Some(task) = self.tasks.get(index) else {
+            return;
+        };
+        let (status, id) = (task.status, task.id);
+        let visible = self.indices_for_status(status);
+        let Some(row) = visible.iter().position(|candidate| *candidate == index) else {
+            return;
+        };
+        let Some(neighbor) = row
+            .checked_add_signed(direction)
+            .and_then(|neighbor_row| visible.get(neighbor_row))
+            .copied()
+        else {
+            return;
+        };
+        let mut column = self
             .tasks
             .iter()
-            .position(|task| task.status == TaskStatus::Done);
+            .enumerate()
+            .filter_map(|(candidate, task)| (task.status == status).then_some(candidate))
+            .collect::<Vec<_>>();
+        let position = column.iter().position(|candidate| *candidate == index);
+        let neighbor_position = column.iter().position(|candidate| *candidate == neighbor);
+        if let (Some(position), Some(neighbor_position)) = (position, neighbor_position) {
+            column.swap(position, neighbor_position);
+        }
+        self.save_column_order(column, id);
     }
 
-    fn error_with_persistent(&self, error: String) -> String {
-        self.persistent_error
-            .as_deref()
-            .map_or(error.clone(), |persistent| {
-                format!("{persistent} | {error}")
-            })
+    /// Sorts the selected task's column by `key` and persists the result as its manual order.
+    ///
+    /// Only that column is renumbered, so the manual order of every other column is kept.
+    fn sort_column_by<K: Ord>(&mut self, label: &str, key: impl Fn(&Task) -> K) {
+        let Some(index) = self.checked_selection() else {
+            self.notification = Some("No task selected".to_string());
+            return;
+        };
+        let status = self.tasks[index].status;
+        let mut column = self
+            .tasks
+            .iter()
+            .enumerate()
+            .filter_map(|(candidate, task)| (task.status == status).then_some(candidate))
+            .collect::<Vec<_>>();
+        column.sort_by_key(|candidate| key(&self.tasks[*candidate]));
+        self.save_column_order(column, self.tasks[index].id);
+        self.notification = Some(format!("Sorted {} by {label}", status.label()));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-    use uuid::Uuid;
-
-    fn create_app(tasks: Vec<Task>, selected_index: Option<usize>) -> App {
-        App {
-            should_quit: false,
-            input_mode: Mode::Normal,
-            input_buffer: String::new(),
-            input_cursor: 0,
-            tasks: Task::sort(tasks),
-            selected_index,
-            parking_loaded: true,
-            done_loaded: false,
-            done_week_start: Task::week_start(Local::now().date_naive()),
-            last_updated_at: Local::now().naive_local(),
-            open_file: None,
-            error_message: None,
-            tasks_dir: Task::default_base_dir(),
-            persistent_error: None,
-            pending_g_at: None,
+    /// Ranks the tasks at `column` in the given order, rewriting only files whose rank changed,
+    /// then re-sorts and keeps `selected_id` selected.
+    fn save_column_order(&mut self, column: Vec<usize>, selected_id: Uuid) {
+        for (rank, task_index) in column.into_iter().enumerate() {
+            let rank = rank as u32;
+            if self.tasks[task_index].order == Some(rank) {
+                continue;
+            }
+            let mut reordered = self.tasks[task_index].clone();
+            reordered.order = Some(rank);
+            if let Err(error) = reordered.save_metadata() {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to reorder tasks: {error}")));
+                break;
+            }
+            self.tasks[task_index] = reordered;
         }
+        Task::sort_in_place(&mut self.tasks);
+        self.selected_index = self.tasks.iter().position(|task| task.id == selected_id);
     }
 
-    fn create_task(name: &str, status: TaskStatus) -> Task {
-        let mut task = Task::new(name.to_string());
-        task.status = status;
-        task
-    }
+    /// Sets the selected task's file path to `open_files` for neovim to open.
+    ///
+    /// The actual neovim invocation is handled in the main event loop (`main.rs`),
+    /// since terminal control must be managed there.
+    ///
+    /// The name and body are kept so that `after_edit` can summarize what changed.
+    fn open_task(&mut self) {
+        match self.checked_selection() {
+            Some(index) => {
+                let task = &self.tasks[index];
+                self.pre_edit = task
+                    .load_body()
+                    .ok()
+                    .map(|body| (task.id, task.name.clone(), body));
+                self.open_files = vec![task.file_path()];
+            }
+            None => self.notification = Some("No task selected".to_string()),
+        }
+    }
+
+    /// Selects the first task whose UUID appears in the selected task's body.
+    ///
+    /// Referenced tasks that are not loaded or are hidden by the search are skipped;
+    /// `Ctrl-O` goes back to the referencing task.
+    fn follow_link(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.notification = Some("No task selected".to_string());
+            return;
+        };
+        let task = &self.tasks[index];
+        let body = match task.load_body() {
+            Ok(body) => body,
+            Err(error) => {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to read task: {error}")));
+                return;
+            }
+        };
+        let references: Vec<Uuid> = Task::referenced_ids(&body)
+            .into_iter()
+            .filter(|id| *id != task.id)
+            .collect();
+        if references.is_empty() {
+            self.notification = Some("No linked task in the body".to_string());
+            return;
+        }
+        let target = references.iter().find_map(|id| {
+            self.tasks
+                .iter()
+                .position(|task| task.id == *id && self.is_visible(task))
+        });
+        match target {
+            Some(target) => {
+                self.selected_index = Some(target);
+                self.notification = Some(format!(
+                    "Linked to \"{}\" (Ctrl-O: back)",
+                    self.tasks[target].name
+                ));
+            }
+            None => self.notification = Some("Linked task is not loaded".to_string()),
+        }
+    }
+
+    /// Sets the file paths of every shown DOING task, in board order, to `open_files`.
+    fn open_doing_tasks(&mut self) {
+        let paths: Vec<PathBuf> = self
+            .indices_for_status(TaskStatus::Doing)
+            .into_iter()
+            .map(|index| self.tasks[index].file_path())
+            .collect();
+        if paths.is_empty() {
+            self.notification = Some("No DOING tasks to open".to_string());
+            return;
+        }
+        self.pre_edit = None;
+        self.open_files = paths;
+    }
+
+    /// Opens a new email in the default mail client with the selected task's name as the
+    /// subject and its body as the message.
+    fn share_task(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.notification = Some("No task selected".to_string());
+            return;
+        };
+        let task = &self.tasks[index];
+        let result = task
+            .load_body()
+            .and_then(|body| open_url(&mailto_url(&task.name, &body)));
+        match result {
+            Ok(()) => self.notification = Some(format!("Opened an email for \"{}\"", task.name)),
+            Err(error) => {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to share task: {error}")));
+            }
+        }
+    }
+
+    /// Queues the selected task's absolute file path for the clipboard.
+    fn copy_file_path(&mut self) {
+        match self.checked_selection() {
+            Some(index) => {
+                let path = self.tasks[index].file_path();
+                let path = std::path::absolute(&path).unwrap_or(path);
+                self.clipboard = Some(path.display().to_string());
+                self.notification = Some("Path copied".to_string());
+            }
+            None => self.notification = Some("No task selected".to_string()),
+        }
+    }
+
+    /// Gives the selected task a fresh UUID, renaming its file, to repair a duplicated id.
+    fn reassign_selected_id(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.notification = Some("No task selected".to_string());
+            return;
+        };
+        let old_id = self.tasks[index].id;
+        if let Err(error) = self.store.reassign_id(&mut self.tasks[index]) {
+            self.error_message =
+                Some(self.error_with_persistent(format!("Failed to reassign task id: {error}")));
+            return;
+        }
+        let new_id = self.tasks[index].id;
+        self.log(&format!("Reassigned {old_id} to {new_id}"));
+        for id in &mut self.jump_list {
+            if *id == old_id {
+                *id = new_id;
+            }
+        }
+        Task::sort_in_place(&mut self.tasks);
+        self.selected_index = self.tasks.iter().position(|task| task.id == new_id);
+        self.notification = Some(format!("New id: {new_id}"));
+    }
+
+    /// Moves a selected TODO task to DOING, then opens the selected task in neovim.
+    ///
+    /// Tasks in any other status are opened without changing their status.
+    fn start_and_open_task(&mut self) {
+        if let Some(index) = self.checked_selection()
+            && self.tasks[index].status == TaskStatus::Todo
+        {
+            self.change_status(index, TaskStatus::Doing);
+        }
+        self.open_task();
+    }
+
+    /// Reloads the selected task's metadata from its markdown file to reflect the latest state in memory.
+    fn reload_selected_task(&mut self) {
+        if let Some(index) = self.checked_selection()
+            && let Ok(reloaded) = self.tasks[index].reload()
+        {
+            self.tasks[index] = reloaded;
+        }
+    }
+
+    /// Applies the config file reloaded after `C`, or reports why it could not be reloaded.
+    ///
+    /// `tasks_dir` and `ephemeral` stay as they were for the rest of the session; every
+    /// other setting takes effect immediately.
+    pub fn after_config_edit(&mut self, result: io::Result<Config>) {
+        let mut config = match result {
+            Ok(config) => config,
+            Err(error) => {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to reload config: {error}")));
+                return;
+            }
+        };
+        let tasks_dir_changed = config.tasks_dir != self.config.tasks_dir;
+        config.tasks_dir = self.config.tasks_dir.clone();
+        config.ephemeral = self.config.ephemeral;
+        self.store = TaskStore::new(config.tasks_dir.clone())
+            .with_frontmatter_format(config.frontmatter_format);
+        self.log = config
+            .debug_log
+            .then(Log::default_path)
+            .flatten()
+            .map(Log::new);
+        self.config = config;
+        self.reload_tasks();
+        self.notification = Some(if tasks_dir_changed {
+            "Reloaded config; restart rem to use the new tasks_dir".to_string()
+        } else {
+            "Reloaded config".to_string()
+        });
+    }
+
+    /// Handles post-edit cleanup after returning from neovim.
+    ///
+    /// Reloads the task and notifies a one-line summary of how its name and body changed.
+    /// After several files were opened at once, every task is reloaded instead.
+    pub fn after_edit(&mut self) {
+        self.forget_watched_file();
+        let Some((id, name, body)) = self.pre_edit.take() else {
+            self.reload_tasks();
+            return;
+        };
+        self.reload_selected_task();
+        let Some(task) = self.tasks.iter().find(|task| task.id == id) else {
+            return;
+        };
+        if let Ok(edited_body) = task.load_body() {
+            self.notification = Some(edit_summary(&name, &body, &task.name, &edited_body));
+        }
+    }
+
+    fn reload_tasks(&mut self) {
+        let selection = self.selected_index.and_then(|index| {
+            let selected = self.tasks.get(index)?;
+            let row = self
+                .indices_for_status(selected.status)
+                .iter()
+                .position(|candidate| *candidate == index)
+                .unwrap_or(0);
+            Some((selected.id, selected.status, row))
+        });
+        let mut loaded_tasks = match Self::load_visible_tasks(
+            &self.store,
+            self.done_loaded,
+            self.done_loaded_for_search,
+            self.done_week_start,
+        ) {
+            Ok(tasks) => tasks,
+            Err(error) => {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to reload tasks: {error}")));
+                return;
+            }
+        };
+        loaded_tasks = Task::sort(loaded_tasks);
+        self.tasks = loaded_tasks;
+        self.parking_loaded = true;
+        self.last_updated_at = Local::now().naive_local();
+        self.error_message = self.persistent_error.clone();
+        self.selected_index = selection
+            .and_then(|(id, status, row)| {
+                self.tasks
+                    .iter()
+                    .position(|task| task.id == id)
+                    .or_else(|| self.nearby_selection(status, row))
+            })
+            .or_else(|| (!self.tasks.is_empty()).then_some(0));
+    }
+
+    fn load_visible_tasks(
+        store: &TaskStore,
+        done_loaded: bool,
+        all_done_weeks: bool,
+        done_week_start: NaiveDate,
+    ) -> std::io::Result<Vec<Task>> {
+        let active_tasks =
+            store.load_by_status(&[TaskStatus::Parking, TaskStatus::Todo, TaskStatus::Doing])?;
+        let done_tasks = if all_done_weeks {
+            store.load_by_status(&[TaskStatus::Done])?
+        } else if done_loaded {
+            store.load_done_for_week(done_week_start)?
+        } else {
+            Vec::new()
+        };
+        Ok(active_tasks.into_iter().chain(done_tasks).collect())
+    }
+
+    /// Advances the selected task to the next status of `status_flow`
+    /// (PARKING -> TODO -> DOING -> DONE by default).
+    ///
+    /// At the end of the flow, a DONE task is archived or, after a `y` confirmation, deleted
+    /// as `done_forward_action` says; any other task stays put.
+    fn forward_status(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            return;
+        };
+        let task = &self.tasks[index];
+        match task.status.next_in(&self.config.status_flow) {
+            Some(next_status) => self.change_status(index, next_status),
+            None if task.status == TaskStatus::Done => match self.config.done_forward_action {
+                DoneForwardAction::None => {}
+                DoneForwardAction::Archive => self.complete_and_archive(),
+                DoneForwardAction::Delete => {
+                    let action = PendingAction::DeleteTask {
+                        id: task.id,
+                        name: task.name.clone(),
+                    };
+                    self.request_confirm(action);
+                }
+            },
+            None => {}
+        }
+    }
+
+    /// Returns whether `n` on the current selection would ask to delete it.
+    fn forward_deletes(&self) -> bool {
+        self.config.done_forward_action == DoneForwardAction::Delete
+            && self
+                .selected_index
+                .and_then(|index| self.tasks.get(index))
+                .is_some_and(|task| {
+                    task.status == TaskStatus::Done
+                        && task.status.next_in(&self.config.status_flow).is_none()
+                })
+    }
+
+    /// Reverts the selected task to the previous status of `status_flow`
+    /// (DONE -> DOING -> TODO -> PARKING by default).
+    ///
+    /// Does nothing if the task is already at the start of the flow. With `confirm_uncomplete`,
+    /// moving a DONE task back waits for a `y` confirmation.
+    fn backward_status(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            return;
+        };
+        let task = &self.tasks[index];
+        let Some(previous_status) = task.status.previous_in(&self.config.status_flow) else {
+            return;
+        };
+        if task.status == TaskStatus::Done && self.config.confirm_uncomplete {
+            let action = PendingAction::Uncomplete {
+                id: task.id,
+                name: task.name.clone(),
+                to: previous_status,
+            };
+            self.request_confirm(action);
+            return;
+        }
+        self.change_status(index, previous_status);
+    }
+
+    fn change_status(&mut self, index: usize, next_status: TaskStatus) {
+        let id = self.tasks[index].id;
+        let previous_status = self.tasks[index].status;
+        let previous_indices = self.indices_for_status(previous_status);
+        let previous_row = previous_indices
+            .iter()
+            .position(|candidate| *candidate == index)
+            .unwrap_or(0);
+        if let Err(error) = self.store.move_status(&mut self.tasks[index], next_status) {
+            self.error_message =
+                Some(self.error_with_persistent(format!("Failed to update task status: {error}")));
+            return;
+        }
+        self.log(&format!(
+            "Moved {} from {} to {}",
+            id,
+            previous_status.label(),
+            next_status.label()
+        ));
+        self.error_message = self.persistent_error.clone();
+        if next_status == TaskStatus::Done && !self.belongs_to_visible_done_week(&self.tasks[index])
+        {
+            self.tasks.retain(|task| task.id != id);
+            Task::sort_in_place(&mut self.tasks);
+            self.selected_index = self.nearby_selection(previous_status, previous_row);
+            return;
+        }
+        Task::sort_in_place(&mut self.tasks);
+        self.selected_index = self.tasks.iter().position(|task| task.id == id);
+    }
+
+    fn belongs_to_visible_done_week(&self, task: &Task) -> bool {
+        let done_week_end = self
+            .done_week_start
+            .checked_add_days(Days::new(7))
+            .expect("done week end should be a valid date");
+        self.done_loaded
+            && task.completed_at.is_some_and(|completed_at| {
+                let completed_date = completed_at.date();
+                completed_date >= self.done_week_start && completed_date < done_week_end
+            })
+    }
+
+    fn nearby_selection(&self, preferred_status: TaskStatus, row: usize) -> Option<usize> {
+        let preferred = self.indices_for_status(preferred_status);
+        if !preferred.is_empty() {
+            return preferred.get(row.min(preferred.len() - 1)).copied();
+        }
+        let statuses = self.visible_statuses();
+        let preferred_column = statuses
+            .iter()
+            .position(|status| *status == preferred_status)?;
+        (1..statuses.len())
+            .flat_map(|distance| {
+                [
+                    preferred_column.checked_sub(distance),
+                    preferred_column
+                        .checked_add(distance)
+                        .filter(|column| *column < statuses.len()),
+                ]
+            })
+            .flatten()
+            .map(|column| self.indices_for_status(statuses[column]))
+            .find(|indices| !indices.is_empty())
+            .and_then(|indices| indices.get(row.min(indices.len() - 1)).copied())
+    }
+
+    /// Starts adding a task in the selected task's status, to be placed right below it.
+    fn start_add_below(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            self.notification = Some("No task selected".to_string());
+            return;
+        };
+        self.input_mode = Mode::Editing;
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+        self.add_status = self.tasks[index].status;
+        self.add_after = Some(self.tasks[index].id);
+    }
+
+    /// Ranks the task `id` directly after `anchor_id` in their column and selects it.
+    ///
+    /// Does nothing when the anchor is gone or is in another status.
+    fn place_after(&mut self, id: Uuid, anchor_id: Uuid) {
+        let Some(anchor) = self.tasks.iter().find(|task| task.id == anchor_id) else {
+            return;
+        };
+        let status = anchor.status;
+        let Some(index) = self.tasks.iter().position(|task| task.id == id) else {
+            return;
+        };
+        if self.tasks[index].status != status {
+            return;
+        }
+        let mut column = self
+            .tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, task)| task.status == status && task.id != id)
+            .map(|(candidate, _)| candidate)
+            .collect::<Vec<_>>();
+        let Some(anchor_row) = column
+            .iter()
+            .position(|candidate| self.tasks[*candidate].id == anchor_id)
+        else {
+            return;
+        };
+        column.insert(anchor_row + 1, index);
+        self.save_column_order(column, id);
+    }
+
+    fn cycle_add_status(&mut self, direction: isize) {
+        let statuses = TaskStatus::ALL;
+        let current = statuses
+            .iter()
+            .position(|status| *status == self.add_status)
+            .unwrap_or(0);
+        let next = (current as isize + direction).rem_euclid(statuses.len() as isize);
+        self.add_status = statuses[next as usize];
+    }
+
+    /// Creates a new task from the input buffer in the given status and saves it to the filesystem.
+    ///
+    /// The file is written straight into the status directory, inside the project subdirectory
+    /// of the selected task if it has one. A DONE task outside the
+    /// displayed DONE week is saved but not shown. Clears the input buffer and returns to
+    /// Normal mode after completion.
+    fn add_task(&mut self, status: TaskStatus) {
+        if !self.input_buffer.is_empty() {
+            let mut new_task = self
+                .store
+                .create_with_status(self.input_buffer.clone(), status);
+            new_task.project = self
+                .selected_index
+                .and_then(|index| self.tasks.get(index))
+                .and_then(|task| task.project.clone());
+            if self.config.sequential_ids {
+                match self.take_next_seq() {
+                    Ok(seq) => new_task.seq = Some(seq),
+                    Err(error) => {
+                        self.error_message =
+                            Some(self.error_with_persistent(format!(
+                                "Failed to assign task id: {error}"
+                            )));
+                        return;
+                    }
+                }
+            }
+            if let Err(error) = self.store.save(&new_task) {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to add task: {error}")));
+                return;
+            }
+            if self.first_run {
+                self.dismiss_onboarding();
+            }
+            self.last_action = Some(RepeatableAction::Add {
+                name: new_task.name.clone(),
+                status,
+            });
+            self.log(&format!(
+                "Added {} \"{}\" to {}",
+                new_task.id,
+                new_task.name,
+                status.label()
+            ));
+            if status == TaskStatus::Done && !self.belongs_to_visible_done_week(&new_task) {
+                self.notification = Some(format!("Added \"{}\" to DONE", new_task.name));
+            } else {
+                let id = new_task.id;
+                self.tasks.push(new_task);
+                Task::sort_in_place(&mut self.tasks);
+                if self.selected_index.is_none() {
+                    self.selected_index = Some(0);
+                }
+                if let Some(anchor_id) = self.add_after {
+                    self.place_after(id, anchor_id);
+                }
+            }
+        }
+        self.add_after = None;
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+        self.input_mode = Mode::Normal;
+        self.error_message = self.persistent_error.clone();
+        self.discard_draft();
+    }
+
+    /// Runs a repeatable action and remembers it for `.`.
+    fn perform(&mut self, action: RepeatableAction) {
+        self.last_action = Some(action.clone());
+        self.run_repeatable(action);
+    }
+
+    /// Applies the last repeatable action to the current selection again.
+    fn repeat_last_action(&mut self) {
+        match self.last_action.clone() {
+            Some(action) => self.run_repeatable(action),
+            None => self.notification = Some("Nothing to repeat".to_string()),
+        }
+    }
+
+    fn run_repeatable(&mut self, action: RepeatableAction) {
+        match action {
+            RepeatableAction::ForwardStatus => self.forward_status(),
+            RepeatableAction::BackwardStatus => self.backward_status(),
+            RepeatableAction::Reorder(direction) => self.move_within_status(direction),
+            RepeatableAction::CompleteAndArchive => self.complete_and_archive(),
+            RepeatableAction::Add { name, status } => {
+                self.input_buffer = name;
+                self.add_after = None;
+                self.add_task(status);
+                self.input_buffer.clear();
+                self.input_cursor = 0;
+            }
+        }
+    }
+
+    /// Returns the next sequential id, skipping past the id of every task on disk.
+    ///
+    /// Reads all statuses like `rem add`, since hidden DONE tasks and edits made outside
+    /// rem are not in `self.tasks`.
+    fn take_next_seq(&mut self) -> std::io::Result<u64> {
+        let known_max = self
+            .store
+            .load_all()?
+            .iter()
+            .filter_map(|task| task.seq)
+            .max();
+        self.store.take_next_seq(known_max)
+    }
+
+    /// Toggles the visibility of DONE tasks.
+    ///
+    /// When enabled, loads DONE tasks from the filesystem and appends them to the task list.
+    /// When disabled, removes all DONE tasks from the in-memory list.
+    fn toggle_done(&mut self) {
+        if self.done_loaded {
+            let selection = self.selected_index.and_then(|index| {
+                let status = self.tasks[index].status;
+                let row = self
+                    .indices_for_status(status)
+                    .iter()
+                    .position(|candidate| *candidate == index)?;
+                Some((status, row))
+            });
+            self.tasks.retain(|t| t.status != TaskStatus::Done);
+            self.done_loaded = false;
+            self.done_loaded_for_search = false;
+            self.done_week_before_search = false;
+            self.done_week_start = Task::week_start(Local::now().date_naive());
+            if selection.is_some_and(|(status, _)| status == TaskStatus::Done) {
+                self.selected_index =
+                    selection.and_then(|(_, row)| self.nearby_selection(TaskStatus::Doing, row));
+            }
+        } else {
+            self.done_week_start = Task::week_start(Local::now().date_naive());
+            if !self.load_done_week() {
+                return;
+            }
+            self.done_loaded = true;
+        }
+        if self.tasks.is_empty() {
+            self.selected_index = None;
+        } else if let Some(i) = self.selected_index
+            && i >= self.tasks.len()
+        {
+            self.selected_index = Some(self.tasks.len() - 1);
+        }
+    }
+
+    fn show_previous_done_week(&mut self) {
+        if !self.done_loaded {
+            return;
+        }
+        let previous_week_start = self
+            .done_week_start
+            .checked_sub_days(Days::new(7))
+            .expect("previous week should be a valid date");
+        let current_week_start = self.done_week_start;
+        self.done_week_start = previous_week_start;
+        if !self.load_done_week() {
+            self.done_week_start = current_week_start;
+            return;
+        }
+        self.select_done_column();
+    }
+
+    fn show_next_done_week(&mut self) {
+        if !self.done_loaded {
+            return;
+        }
+        let current_week_start = Task::week_start(Local::now().date_naive());
+        let next_week_start = self
+            .done_week_start
+            .checked_add_days(Days::new(7))
+            .expect("next week should be a valid date");
+        if next_week_start > current_week_start {
+            return;
+        }
+        let current_done_week_start = self.done_week_start;
+        self.done_week_start = next_week_start;
+        if !self.load_done_week() {
+            self.done_week_start = current_done_week_start;
+            return;
+        }
+        self.select_done_column();
+    }
+
+    fn load_done_week(&mut self) -> bool {
+        let selected_id = self
+            .selected_index
+            .and_then(|index| self.tasks.get(index))
+            .map(|task| task.id);
+        let done_tasks = match self.store.load_done_for_week(self.done_week_start) {
+            Ok(tasks) => tasks,
+            Err(error) => {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to load DONE tasks: {error}")));
+                return false;
+            }
+        };
+        self.tasks.retain(|task| task.status != TaskStatus::Done);
+        self.tasks.extend(done_tasks);
+        self.done_loaded_for_search = false;
+        self.done_week_before_search = false;
+        Task::sort_in_place(&mut self.tasks);
+        self.selected_index = selected_id
+            .and_then(|id| self.tasks.iter().position(|task| task.id == id))
+            .or_else(|| {
+                self.tasks
+                    .iter()
+                    .position(|task| task.status == TaskStatus::Done)
+            })
+            .or_else(|| (!self.tasks.is_empty()).then_some(0));
+        self.error_message = self.persistent_error.clone();
+        true
+    }
+
+    /// Shows the confirmation popup for `action`; every confirmation goes through here.
+    fn request_confirm(&mut self, action: PendingAction) {
+        self.confirm_input.clear();
+        self.pending_confirm = Some(action);
+        self.pending_confirm_at = Some(Instant::now());
+    }
+
+    /// Asks for confirmation before archiving every DONE task.
+    ///
+    /// The count comes from the `done/` directory, so it includes weeks that are not displayed.
+    fn request_archive_done(&mut self) {
+        match self.store.count(TaskStatus::Done) {
+            Ok(0) => self.notification = Some("Nothing to archive".to_string()),
+            Ok(count) => self.request_confirm(PendingAction::ArchiveDone(count)),
+            Err(error) => {
+                self.error_message = Some(
+                    self.error_with_persistent(format!("Failed to count DONE tasks: {error}")),
+                );
+            }
+        }
+    }
+
+    /// Asks the user to type `purge` before permanently deleting every DONE task.
+    fn request_purge_done(&mut self) {
+        match self.store.count(TaskStatus::Done) {
+            Ok(0) => self.notification = Some("Nothing to purge".to_string()),
+            Ok(count) => self.request_confirm(PendingAction::PurgeDone(count)),
+            Err(error) => {
+                self.error_message = Some(
+                    self.error_with_persistent(format!("Failed to count DONE tasks: {error}")),
+                );
+            }
+        }
+    }
+
+    /// Marks the selected task as DONE and archives it in one step.
+    fn complete_and_archive(&mut self) {
+        let Some(index) = self.checked_selection() else {
+            return;
+        };
+        let previous_status = self.tasks[index].status;
+        let previous_row = self
+            .indices_for_status(previous_status)
+            .iter()
+            .position(|candidate| *candidate == index)
+            .unwrap_or(0);
+        let mut task = self.tasks[index].clone();
+        if let Err(error) = task.archive() {
+            if task.status != previous_status {
+                self.reload_tasks();
+            }
+            self.error_message =
+                Some(self.error_with_persistent(format!("Failed to archive task: {error}")));
+            return;
+        }
+        self.log(&format!("Archived {} \"{}\"", task.id, task.name));
+        self.tasks.remove(index);
+        self.selected_index = self.nearby_selection(previous_status, previous_row);
+        self.error_message = self.persistent_error.clone();
+        self.notification = Some(format!("Archived \"{}\"", task.name));
+    }
+
+    /// Deletes the file of the task at `index` and drops it from the list.
+    fn delete_task(&mut self, index: usize) {
+        let task = self.tasks[index].clone();
+        let row = self
+            .indices_for_status(task.status)
+            .iter()
+            .position(|candidate| *candidate == index)
+            .unwrap_or(0);
+        if let Err(error) = self.store.delete(&task) {
+            self.error_message =
+                Some(self.error_with_persistent(format!("Failed to delete task: {error}")));
+            return;
+        }
+        self.log(&format!("Deleted {} \"{}\"", task.id, task.name));
+        self.tasks.remove(index);
+        self.selected_index = self.nearby_selection(task.status, row);
+        self.error_message = self.persistent_error.clone();
+        self.notification = Some(format!("Deleted \"{}\"", task.name));
+    }
+
+    fn run_pending_action(&mut self, action: PendingAction) {
+        match action {
+            PendingAction::ArchiveDone(_) => self.archive_done(),
+            PendingAction::PurgeDone(_) => self.purge_done(),
+            PendingAction::Uncomplete { id, to, .. } => {
+                if let Some(index) = self.tasks.iter().position(|task| task.id == id) {
+                    self.change_status(index, to);
+                }
+            }
+            PendingAction::DeleteTask { id, .. } => {
+                if let Some(index) = self.tasks.iter().position(|task| task.id == id) {
+                    self.delete_task(index);
+                }
+            }
+        }
+    }
+
+    fn archive_done(&mut self) {
+        match self.store.archive_done() {
+            Ok(archived) => self.remove_done_tasks(format!("Archived {archived} tasks")),
+            Err(error) => {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to archive tasks: {error}")));
+            }
+        }
+    }
+
+    fn purge_done(&mut self) {
+        match self.store.purge_done() {
+            Ok(purged) => self.remove_done_tasks(format!("Deleted {purged} done tasks")),
+            Err(error) => {
+                self.error_message =
+                    Some(self.error_with_persistent(format!("Failed to purge tasks: {error}")));
+            }
+        }
+    }
+
+    /// Drops DONE tasks from the list after their files left `done/`, keeping the selection nearby.
+    fn remove_done_tasks(&mut self, notification: String) {
+        self.log(&notification);
+        let selection = self.selected_index.and_then(|index| {
+            let selected = self.tasks.get(index)?;
+            let row = self
+                .indices_for_status(selected.status)
+                .iter()
+                .position(|candidate| *candidate == index)?;
+            Some((selected.id, selected.status, row))
+        });
+        self.tasks.retain(|task| task.status != TaskStatus::Done);
+        self.selected_index = selection.and_then(|(id, status, row)| {
+            self.tasks
+                .iter()
+                .position(|task| task.id == id)
+                .or_else(|| self.nearby_selection(status, row))
+        });
+        self.error_message = self.persistent_error.clone();
+        self.notification = Some(notification);
+    }
+
+    fn select_done_column(&mut self) {
+        self.selected_index = self
+            .tasks
+            .iter()
+            .position(|task| task.status == TaskStatus::Done);
+    }
+
+    /// Appends a line to the debug log when it is enabled.
+    fn log(&self, message: &str) {
+        if let Some(log) = &self.log {
+            log.write(message);
+        }
+    }
+
+    fn error_with_persistent(&self, error: String) -> String {
+        self.log(&error);
+        self.persistent_error
+            .as_deref()
+            .map_or(error.clone(), |persistent| {
+                format!("{persistent} | {error}")
+            })
+    }
+}
+
+/// Whether a Normal-mode key writes task files, so it is disabled while the task
+/// directory is not writable.
+fn modifies_tasks(key_code: KeyCode) -> bool {
+    matches!(
+        key_code,
+        KeyCode::Char(
+            'a' | 'O'
+                | '.'
+                | 'J'
+                | 'K'
+                | 's'
+                | 'S'
+                | 'n'
+                | 'N'
+                | 'A'
+                | 'P'
+                | 'D'
+                | 'e'
+                | 'R'
+                | 'o'
+                | 'I'
+                | 'x'
+                | 'p'
+                | 'M'
+                | 't'
+                | 'Z'
+        )
+    )
+}
+
+/// Returns `DEFER_HOUR` o'clock on the day after `now`, in the same local time as `now`.
+fn tomorrow_morning(now: NaiveDateTime) -> NaiveDateTime {
+    now.date()
+        .checked_add_days(Days::new(1))
+        .and_then(|tomorrow| tomorrow.and_hms_opt(DEFER_HOUR, 0, 0))
+        .expect("tomorrow morning should be a valid date-time")
+}
+
+/// Summarizes an external edit by its body line-count delta and whether the name changed.
+///
+/// Only line counts are compared, so an edit that keeps the count is reported as "body edited".
+fn edit_summary(name: &str, body: &str, edited_name: &str, edited_body: &str) -> String {
+    let delta = edited_body.lines().count() as i64 - body.lines().count() as i64;
+    let mut changes = Vec::new();
+    match delta {
+        0 if body != edited_body => changes.push("body edited".to_string()),
+        0 => {}
+        1 | -1 => changes.push(format!("{delta:+} line")),
+        _ => changes.push(format!("{delta:+} lines")),
+    }
+    if name != edited_name {
+        changes.push("name changed".to_string());
+    }
+    if changes.is_empty() {
+        return "No changes to name or body".to_string();
+    }
+    changes.join(", ")
+}
+
+/// Builds a `mailto:` URL with the task name as subject and its body as the message.
+///
+/// Line breaks in the body are sent as CRLF, as RFC 6068 requires.
+fn mailto_url(name: &str, body: &str) -> String {
+    let body = body.replace("\r\n", "\n").replace('\n', "\r\n");
+    format!(
+        "mailto:?subject={}&body={}",
+        percent_encode(name),
+        percent_encode(&body)
+    )
+}
+
+/// Percent-encodes every byte except the RFC 3986 unreserved characters.
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// Opens a URL with the platform's default handler (`open`, `xdg-open`, or `url.dll`).
+///
+/// Fails when the handler cannot be started or exits unsuccessfully, e.g. because no mail
+/// client is configured.
+fn open_url(url: &str) -> io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        // `cmd /C start` splits the URL at `&`; the URL handler takes it as one argument.
+        let mut command = Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    let status = command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "no handler for mailto links ({status})"
+        )))
+    }
+}
+
+/// Writes a creation date range the way `F` reads it, e.g. `2024-01-01..` for an open end.
+pub fn format_created_range(since: Option<NaiveDate>, until: Option<NaiveDate>) -> String {
+    let format = |date: Option<NaiveDate>| {
+        date.map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default()
+    };
+    format!("{}..{}", format(since), format(until))
+}
+
+/// Inserts a character at a cursor counted in characters, then advances the cursor.
+fn insert_character(buffer: &mut String, cursor: &mut usize, character: char) {
+    let byte_index = buffer
+        .char_indices()
+        .nth(*cursor)
+        .map_or(buffer.len(), |(index, _)| index);
+    buffer.insert(byte_index, character);
+    *cursor += 1;
+}
+
+/// Deletes the character before a cursor counted in characters, then moves the cursor back.
+fn delete_character_before(buffer: &mut String, cursor: &mut usize) {
+    if *cursor == 0 {
+        return;
+    }
+    let start = buffer
+        .char_indices()
+        .nth(*cursor - 1)
+        .map_or(0, |(index, _)| index);
+    let end = buffer
+        .char_indices()
+        .nth(*cursor)
+        .map_or(buffer.len(), |(index, _)| index);
+    buffer.replace_range(start..end, "");
+    *cursor -= 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use uuid::Uuid;
+
+    fn create_app(tasks: Vec<Task>, selected_index: Option<usize>) -> App {
+        App {
+            should_quit: false,
+            input_mode: Mode::Normal,
+            input_buffer: String::new(),
+            input_cursor: 0,
+            add_status: TaskStatus::Todo,
+            add_after: None,
+            tasks: Task::sort(tasks),
+            selected_index,
+            parking_loaded: true,
+            done_loaded: false,
+            done_week_start: Task::week_start(Local::now().date_naive()),
+            done_loaded_for_search: false,
+            done_week_before_search: false,
+            last_updated_at: Local::now().naive_local(),
+            open_files: Vec::new(),
+            edit_config: false,
+            clipboard: None,
+            error_message: None,
+            notification: None,
+            pending_confirm: None,
+            confirm_input: String::new(),
+            show_ids: false,
+            display_utc: false,
+            expanded_panel: None,
+            layout_mode: LayoutMode::Columns,
+            use_color: true,
+            search_query: String::new(),
+            help_page: 0,
+            show_help: false,
+            help_scroll: 0,
+            stats: None,
+            first_run: false,
+            show_calendar: false,
+            body_buffer: String::new(),
+            body_cursor: 0,
+            form_fields: Default::default(),
+            form_focus: 0,
+            store: TaskStore::new(Task::default_base_dir()),
+            persistent_error: None,
+            read_only: false,
+            pending_g_at: None,
+            pending_count: None,
+            cut_task: None,
+            priority_filter: None,
+            active_tag_filter: None,
+            created_range: (None, None),
+            marked: Vec::new(),
+            jump_list: Vec::new(),
+            jump_cursor: 0,
+            last_action: None,
+            config: Config::with_tasks_dir(Task::default_base_dir()),
+            quit_armed: None,
+            pending_confirm_at: None,
+            saved_draft: String::new(),
+            draft_saved_at: None,
+            help_page_shown_at: Instant::now(),
+            reminders_checked_at: Local::now().naive_local(),
+            log: None,
+            pre_edit: None,
+            watched_file: None,
+            selected_changed_on_disk: false,
+        }
+    }
+
+    fn create_task(name: &str, status: TaskStatus) -> Task {
+        let mut task = Task::new(name.to_string());
+        task.status = status;
+        task
+    }
+
+    fn temporary_tasks_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("rem-cli-app-test-{}", Uuid::new_v4()))
+    }
+
+    #[test]
+    fn parking_is_not_loaded_during_initialization() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let app = App::with_tasks_dir(tasks_dir.clone());
+
+        // WHEN
+        let parking_tasks = app
+            .tasks
+            .iter()
+            .filter(|task| task.status == TaskStatus::Parking)
+            .count();
+
+        // THEN
+        assert!(!app.parking_loaded);
+        assert_eq!(parking_tasks, 0);
+        assert!(!tasks_dir.exists());
+    }
+
+    #[test]
+    fn parking_is_loaded_after_first_render() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+
+        // WHEN
+        app.load_parking_after_first_render();
+
+        // THEN
+        assert!(app.parking_loaded);
+        assert!(!tasks_dir.exists());
+    }
+
+    #[test]
+    fn parking_load_failure_is_retried() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        fs::create_dir_all(&tasks_dir).unwrap();
+        fs::write(tasks_dir.join("parking"), "not a directory").unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+
+        // WHEN
+        app.load_parking_after_first_render();
+
+        // THEN
+        assert!(!app.parking_loaded);
+        assert!(app.error_message.is_some());
+
+        fs::remove_file(tasks_dir.join("parking")).unwrap();
+        app.load_parking_after_first_render();
+        assert!(app.parking_loaded);
+        assert!(app.error_message.is_none());
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn parking_load_success_keeps_initial_load_error() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        fs::create_dir_all(&tasks_dir).unwrap();
+        fs::write(tasks_dir.join("todo"), "not a directory").unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let expected = app.error_message.clone();
+
+        // WHEN
+        app.load_parking_after_first_render();
+
+        // THEN
+        assert!(app.parking_loaded);
+        assert_eq!(app.error_message, expected);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn reload_key_loads_parking_todo_and_doing_tasks() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let tasks = [
+            ("reloaded parking", TaskStatus::Parking),
+            ("reloaded todo", TaskStatus::Todo),
+            ("reloaded doing", TaskStatus::Doing),
+        ];
+        tasks
+            .into_iter()
+            .map(|(name, status)| {
+                let mut task = Task::new_in(name.to_string(), tasks_dir.clone());
+                task.status = status;
+                task
+            })
+            .try_for_each(|task| task.save())
+            .unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('r'));
+
+        // THEN
+        let actual = app
+            .tasks
+            .iter()
+            .map(|task| task.name.as_str())
+            .collect::<Vec<_>>();
+        let expected = ["reloaded parking", "reloaded todo", "reloaded doing"];
+        assert_eq!(actual, expected);
+        assert!(app.parking_loaded);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn reload_key_keeps_done_hidden_when_done_is_not_loaded() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("hidden done".to_string(), tasks_dir.clone());
+        task.status = TaskStatus::Done;
+        task.completed_at = Some(Local::now().naive_local());
+        task.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('r'));
+
+        // THEN
+        assert!(!app.done_loaded);
+        assert!(app.tasks.iter().all(|task| task.status != TaskStatus::Done));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn reload_key_refreshes_visible_done_week() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('d'));
+        let mut task = Task::new_in("visible done".to_string(), tasks_dir.clone());
+        task.status = TaskStatus::Done;
+        task.completed_at = Some(app.done_week_start.and_hms_opt(12, 0, 0).unwrap());
+        task.save().unwrap();
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('r'));
+
+        // THEN
+        let actual = app
+            .tasks
+            .iter()
+            .find(|task| task.status == TaskStatus::Done)
+            .map(|task| task.name.as_str());
+        let expected = Some("visible done");
+        assert_eq!(actual, expected);
+        assert!(app.done_loaded);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn reload_key_updates_last_updated_at_after_success() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        fs::create_dir_all(&tasks_dir).unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let expected_before = NaiveDate::from_ymd_opt(2026, 6, 15)
+            .unwrap()
+            .and_hms_opt(10, 30, 45)
+            .unwrap();
+        app.last_updated_at = expected_before;
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('r'));
+
+        // THEN
+        assert!(app.last_updated_at > expected_before);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn reload_key_keeps_existing_state_when_load_fails() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("existing todo".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let expected_tasks = app
+            .tasks
+            .iter()
+            .map(|task| (task.name.clone(), task.status))
+            .collect::<Vec<_>>();
+        let expected_selected_index = app.selected_index;
+        let expected_last_updated_at = NaiveDate::from_ymd_opt(2026, 6, 15)
+            .unwrap()
+            .and_hms_opt(10, 30, 45)
+            .unwrap();
+        app.last_updated_at = expected_last_updated_at;
+        fs::remove_dir_all(tasks_dir.join("todo")).unwrap();
+        fs::write(tasks_dir.join("todo"), "not a directory").unwrap();
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('r'));
+
+        // THEN
+        let actual_tasks = app
+            .tasks
+            .iter()
+            .map(|task| (task.name.clone(), task.status))
+            .collect::<Vec<_>>();
+        assert_eq!(actual_tasks, expected_tasks);
+        assert_eq!(app.selected_index, expected_selected_index);
+        assert_eq!(app.last_updated_at, expected_last_updated_at);
+        assert!(
+            app.error_message
+                .as_deref()
+                .unwrap()
+                .contains("Failed to reload tasks")
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn vertical_navigation_stays_within_current_status() {
+        // GIVEN
+        let tasks = vec![
+            create_task("todo one", TaskStatus::Todo),
+            create_task("todo two", TaskStatus::Todo),
+            create_task("doing", TaskStatus::Doing),
+        ];
+        let mut app = create_app(tasks, Some(1));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('j'));
+
+        // THEN
+        assert_eq!(app.selected_index, Some(1));
+        assert_eq!(app.tasks[1].status, TaskStatus::Todo);
+    }
+
+    #[test]
+    fn horizontal_navigation_skips_empty_columns_and_clamps_row() {
+        // GIVEN
+        let tasks = vec![
+            create_task("parking one", TaskStatus::Parking),
+            create_task("parking two", TaskStatus::Parking),
+            create_task("doing", TaskStatus::Doing),
+        ];
+        let mut app = create_app(tasks, Some(1));
+        let expected = app
+            .tasks
+            .iter()
+            .position(|task| task.status == TaskStatus::Doing);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('l'));
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+    }
+
+    #[test]
+    fn tab_jumps_to_first_task_of_next_non_empty_column() {
+        // GIVEN
+        let tasks = vec![
+            create_task("todo one", TaskStatus::Todo),
+            create_task("todo two", TaskStatus::Todo),
+            create_task("doing one", TaskStatus::Doing),
+            create_task("doing two", TaskStatus::Doing),
+        ];
+        let mut app = create_app(tasks, Some(1));
+        let expected = app.tasks.iter().position(|task| task.name == "doing one");
+
+        // WHEN
+        app.handle_key_event(KeyCode::Tab);
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+    }
+
+    #[test]
+    fn back_tab_skips_empty_columns() {
+        // GIVEN
+        let tasks = vec![
+            create_task("parking one", TaskStatus::Parking),
+            create_task("parking two", TaskStatus::Parking),
+            create_task("doing", TaskStatus::Doing),
+        ];
+        let mut app = create_app(tasks, Some(2));
+        let expected = app.tasks.iter().position(|task| task.name == "parking one");
+
+        // WHEN
+        app.handle_key_event(KeyCode::BackTab);
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+    }
+
+    #[test]
+    fn moving_task_up_persists_its_rank_across_reload() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        for name in ["first", "second", "third"] {
+            Task::new_in(name.to_string(), tasks_dir.clone())
+                .save()
+                .unwrap();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.selected_index = app.tasks.iter().position(|task| task.name == "third");
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('K'));
+
+        // THEN
+        let names = |app: &App| {
+            app.tasks
+                .iter()
+                .map(|task| task.name.clone())
+                .collect::<Vec<_>>()
+        };
+        let expected = ["first", "third", "second"];
+        assert_eq!(names(&app), expected);
+        assert_eq!(
+            app.selected_index
+                .map(|index| app.tasks[index].name.as_str()),
+            Some("third")
+        );
+        let reloaded = App::with_tasks_dir(tasks_dir.clone());
+        assert_eq!(names(&reloaded), expected);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn sorting_one_column_persists_its_order_and_leaves_others_untouched() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        for (name, status) in [
+            ("banana", TaskStatus::Todo),
+            ("zeta", TaskStatus::Doing),
+            ("Apple", TaskStatus::Todo),
+            ("alpha", TaskStatus::Doing),
+            ("cherry", TaskStatus::Todo),
+        ] {
+            Task::new_with_status(name.to_string(), status, tasks_dir.clone())
+                .save()
+                .unwrap();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.selected_index = app.tasks.iter().position(|task| task.name == "cherry");
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('s'));
+
+        // THEN
+        let names = |app: &App, status: TaskStatus| {
+            app.tasks
+                .iter()
+                .filter(|task| task.status == status)
+                .map(|task| (task.name.clone(), task.order))
+                .collect::<Vec<_>>()
+        };
+        let expected_todo = vec![
+            ("Apple".to_string(), Some(0)),
+            ("banana".to_string(), Some(1)),
+            ("cherry".to_string(), Some(2)),
+        ];
+        let expected_doing = vec![("zeta".to_string(), None), ("alpha".to_string(), None)];
+        assert_eq!(names(&app, TaskStatus::Todo), expected_todo);
+        assert_eq!(names(&app, TaskStatus::Doing), expected_doing);
+        assert_eq!(
+            app.selected_index
+                .map(|index| app.tasks[index].name.as_str()),
+            Some("cherry")
+        );
+        assert_eq!(app.notification.as_deref(), Some("Sorted TODO by name"));
+        let reloaded = App::with_tasks_dir(tasks_dir.clone());
+        assert_eq!(names(&reloaded, TaskStatus::Todo), expected_todo);
+        assert_eq!(names(&reloaded, TaskStatus::Doing), expected_doing);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn exclamation_mark_cycles_through_overdue_tasks() {
+        // GIVEN
+        let yesterday = Local::now().date_naive().pred_opt().unwrap();
+        let overdue = |name: &str, status| {
+            let mut task = create_task(name, status);
+            task.deadline = yesterday;
+            task
+        };
+        let tasks = vec![
+            create_task("todo on time", TaskStatus::Todo),
+            overdue("todo overdue", TaskStatus::Todo),
+            create_task("doing on time", TaskStatus::Doing),
+            overdue("doing overdue", TaskStatus::Doing),
+            overdue("done overdue", TaskStatus::Done),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        app.done_loaded = true;
+        let selected_name = |app: &App| app.tasks[app.selected_index.unwrap()].name.clone();
+
+        // WHEN
+        let visited = (0..3)
+            .map(|_| {
+                app.handle_key_event(KeyCode::Char('!'));
+                selected_name(&app)
+            })
+            .collect::<Vec<_>>();
+
+        // THEN
+        assert_eq!(visited, ["todo overdue", "doing overdue", "todo overdue"]);
+    }
+
+    #[test]
+    fn capital_f_filters_by_inclusive_creation_date_range() {
+        // GIVEN
+        let created_on = |name: &str, day| {
+            let mut task = create_task(name, TaskStatus::Todo);
+            task.created_at = NaiveDate::from_ymd_opt(2024, 1, day)
+                .unwrap()
+                .and_hms_opt(23, 59, 0)
+                .unwrap();
+            task
+        };
+        let tasks = vec![
+            created_on("before", 1),
+            created_on("first day", 2),
+            created_on("last day", 7),
+            created_on("after", 8),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        let visible = |app: &App| {
+            app.tasks
+                .iter()
+                .filter(|task| app.is_visible(task))
+                .map(|task| task.name.clone())
+                .collect::<Vec<_>>()
+        };
+        let apply = |app: &mut App, range: &str| {
+            app.handle_key_event(KeyCode::Char('F'));
+            while !app.input_buffer.is_empty() {
+                app.handle_key_event(KeyCode::Backspace);
+            }
+            for character in range.chars() {
+                app.handle_key_event(KeyCode::Char(character));
+            }
+            app.handle_key_event(KeyCode::Enter);
+            visible(app)
+        };
+
+        // WHEN
+        let bounded = apply(&mut app, "2024-01-02..2024-01-07");
+        let from = apply(&mut app, "2024-01-07..");
+        let up_to = apply(&mut app, "..2024-01-02");
+        let invalid = apply(&mut app, "soon..");
+        let still_typing = app.input_mode == Mode::CreatedRange;
+        app.handle_key_event(KeyCode::Esc);
+        let cleared = apply(&mut app, "");
+
+        // THEN
+        assert_eq!(bounded, ["first day", "last day"]);
+        assert_eq!(from, ["last day", "after"]);
+        assert_eq!(up_to, ["before", "first day"]);
+        assert_eq!(invalid, up_to);
+        assert!(still_typing);
+        assert_eq!(cleared.len(), 4);
+        assert_eq!(
+            app.notification.as_deref(),
+            Some("Showing tasks created at any time")
+        );
+    }
+
+    #[test]
+    fn f_cycles_priority_filter_and_composes_with_search() {
+        // GIVEN
+        let with_priority = |name: &str, priority| {
+            let mut task = create_task(name, TaskStatus::Todo);
+            task.priority = priority;
+            task
+        };
+        let tasks = vec![
+            with_priority("low report", Some(Priority::Low)),
+            with_priority("unset report", None),
+            with_priority("high report", Some(Priority::High)),
+            with_priority("high chore", Some(Priority::High)),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        app.search_query = "report".to_string();
+        let visible = |app: &App| {
+            app.tasks
+                .iter()
+                .filter(|task| app.is_visible(task))
+                .map(|task| task.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('f'));
+        let medium = visible(&app);
+        app.handle_key_event(KeyCode::Char('f'));
+        let high = visible(&app);
+        let high_selection = app.selected_index;
+        let high_notification = app.notification.clone();
+        app.handle_key_event(KeyCode::Char('f'));
+
+        // THEN
+        assert_eq!(medium, ["unset report", "high report"]);
+        assert_eq!(high, ["high report"]);
+        assert_eq!(high_selection, Some(2));
+        assert_eq!(
+            high_notification.as_deref(),
+            Some("Showing high priority tasks")
+        );
+        assert_eq!(app.priority_filter, None);
+        assert_eq!(visible(&app), ["low report", "unset report", "high report"]);
+    }
+
+    #[test]
+    fn exclamation_mark_without_overdue_tasks_keeps_selection() {
+        // GIVEN
+        let tasks = vec![
+            create_task("first", TaskStatus::Todo),
+            create_task("second", TaskStatus::Doing),
+        ];
+        let mut app = create_app(tasks, Some(1));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('!'));
+
+        // THEN
+        assert_eq!(app.selected_index, Some(1));
+        assert_eq!(app.notification.as_deref(), Some("No overdue tasks"));
+    }
+
+    #[test]
+    fn uppercase_o_adds_task_directly_below_selection() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        for (name, status) in [
+            ("first", TaskStatus::Todo),
+            ("second", TaskStatus::Todo),
+            ("third", TaskStatus::Todo),
+            ("doing", TaskStatus::Doing),
+        ] {
+            Task::new_with_status(name.to_string(), status, tasks_dir.clone())
+                .save()
+                .unwrap();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.selected_index = app.tasks.iter().position(|task| task.name == "first");
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('O'));
+        app.handle_paste("subtask");
+        app.handle_key_event(KeyCode::Enter);
+
+        // THEN
+        let todo_names = |app: &App| {
+            app.tasks
+                .iter()
+                .filter(|task| task.status == TaskStatus::Todo)
+                .map(|task| task.name.clone())
+                .collect::<Vec<_>>()
+        };
+        let expected = ["first", "subtask", "second", "third"];
+        assert_eq!(todo_names(&app), expected);
+        assert_eq!(
+            app.selected_index
+                .map(|index| app.tasks[index].name.as_str()),
+            Some("subtask")
+        );
+        assert_eq!(app.add_after, None);
+        let reloaded = App::with_tasks_dir(tasks_dir.clone());
+        assert_eq!(todo_names(&reloaded), expected);
+        assert!(
+            reloaded
+                .tasks
+                .iter()
+                .filter(|task| task.status == TaskStatus::Doing)
+                .all(|task| task.order.is_none())
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn uppercase_r_toggles_reminder_on_selected_task() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        Task::new_in("remind me".to_string(), tasks_dir.clone())
+            .save()
+            .unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.selected_index = Some(0);
+        let set_reminder = |app: &mut App, text: &str| {
+            app.handle_key_event(KeyCode::Char('R'));
+            app.handle_paste(text);
+            app.handle_key_event(KeyCode::Enter);
+        };
+        let expected = NaiveDate::from_ymd_opt(2030, 1, 2)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+
+        // WHEN
+        set_reminder(&mut app, "2030-01-02 09:30");
+        let reloaded = App::with_tasks_dir(tasks_dir.clone());
+        set_reminder(&mut app, "2030-01-02 09:30");
+
+        // THEN
+        assert_eq!(reloaded.tasks[0].reminders, [expected]);
+        assert!(app.tasks[0].reminders.is_empty());
+        assert!(app.input_mode == Mode::Normal);
+        assert_eq!(
+            app.notification.as_deref(),
+            Some("Reminder at 2030-01-02 09:30 removed")
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn invalid_reminder_keeps_prompt_open_with_error() {
+        // GIVEN
+        let mut app = create_app(vec![create_task("task", TaskStatus::Todo)], Some(0));
+        app.handle_key_event(KeyCode::Char('R'));
+        app.handle_paste("tomorrow");
+
+        // WHEN
+        app.handle_key_event(KeyCode::Enter);
+
+        // THEN
+        assert!(app.input_mode == Mode::Reminder);
+        assert_eq!(app.input_buffer, "tomorrow");
+        assert!(
+            app.error_message
+                .as_deref()
+                .unwrap()
+                .contains("use YYYY-MM-DD HH:MM")
+        );
+    }
+
+    #[test]
+    fn passed_reminder_is_notified_once() {
+        // GIVEN
+        let mut task = create_task("call back", TaskStatus::Todo);
+        let now = Local::now().naive_local();
+        task.reminders = vec![now - Duration::from_secs(1)];
+        let mut app = create_app(vec![task], None);
+        app.reminders_checked_at = now - Duration::from_secs(60);
+
+        // WHEN
+        app.show_due_reminders(now);
+        let first = app.notification.take();
+        app.show_due_reminders(now + Duration::from_secs(60));
+
+        // THEN
+        assert_eq!(first.as_deref(), Some("Reminder: call back"));
+        assert_eq!(app.notification, None);
+    }
+
+    #[test]
+    fn uppercase_g_selects_last_task_in_current_status() {
+        // GIVEN
+        let tasks = vec![
+            create_task("todo one", TaskStatus::Todo),
+            create_task("todo two", TaskStatus::Todo),
+            create_task("todo three", TaskStatus::Todo),
+            create_task("doing", TaskStatus::Doing),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        let expected = app
+            .tasks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, task)| (task.status == TaskStatus::Todo).then_some(index))
+            .next_back();
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('G'));
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+        assert_eq!(
+            app.tasks[app.selected_index.unwrap()].status,
+            TaskStatus::Todo
+        );
+    }
+
+    #[test]
+    fn double_g_selects_first_task_in_current_status() {
+        // GIVEN
+        let tasks = vec![
+            create_task("parking", TaskStatus::Parking),
+            create_task("todo one", TaskStatus::Todo),
+            create_task("todo two", TaskStatus::Todo),
+            create_task("todo three", TaskStatus::Todo),
+        ];
+        let mut app = create_app(tasks, Some(3));
+        let expected = app
+            .tasks
+            .iter()
+            .position(|task| task.status == TaskStatus::Todo);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('g'));
+        app.handle_key_event(KeyCode::Char('g'));
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+        assert_eq!(
+            app.tasks[app.selected_index.unwrap()].status,
+            TaskStatus::Todo
+        );
+    }
+
+    #[test]
+    fn count_prefix_repeats_j() {
+        // GIVEN
+        let tasks = (0..5)
+            .map(|index| create_task(&format!("todo {index}"), TaskStatus::Todo))
+            .collect();
+        let mut app = create_app(tasks, Some(0));
+        let expected = Some(3);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('3'));
+        app.handle_key_event(KeyCode::Char('j'));
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+        assert_eq!(app.pending_count, None);
+    }
+
+    #[test]
+    fn count_prefix_is_clamped_to_the_status_column() {
+        // GIVEN
+        let tasks = vec![
+            create_task("todo one", TaskStatus::Todo),
+            create_task("todo two", TaskStatus::Todo),
+            create_task("doing", TaskStatus::Doing),
+        ];
+        let mut app = create_app(tasks, Some(1));
+        let expected = Some(0);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('1'));
+        app.handle_key_event(KeyCode::Char('2'));
+        app.handle_key_event(KeyCode::Char('k'));
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+    }
+
+    #[test]
+    fn count_prefix_before_g_selects_that_row() {
+        // GIVEN
+        let tasks = (0..4)
+            .map(|index| create_task(&format!("todo {index}"), TaskStatus::Todo))
+            .collect();
+        let mut app = create_app(tasks, Some(3));
+        let expected = Some(1);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('2'));
+        app.handle_key_event(KeyCode::Char('G'));
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+    }
+
+    #[test]
+    fn count_prefix_before_gg_selects_that_row() {
+        // GIVEN
+        let tasks = (0..4)
+            .map(|index| create_task(&format!("todo {index}"), TaskStatus::Todo))
+            .collect();
+        let mut app = create_app(tasks, Some(0));
+        let expected = Some(2);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('3'));
+        app.handle_key_event(KeyCode::Char('g'));
+        app.handle_key_event(KeyCode::Char('g'));
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+        assert_eq!(app.pending_count, None);
+    }
+
+    #[test]
+    fn non_motion_key_clears_count_prefix() {
+        // GIVEN
+        let tasks = (0..4)
+            .map(|index| create_task(&format!("todo {index}"), TaskStatus::Todo))
+            .collect();
+        let mut app = create_app(tasks, Some(0));
+        app.handle_key_event(KeyCode::Char('3'));
+        let expected = Some(1);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('i'));
+        app.handle_key_event(KeyCode::Char('j'));
+
+        // THEN
+        assert_eq!(app.pending_count, None);
+        assert_eq!(app.selected_index, expected);
+    }
+
+    #[test]
+    fn leading_zero_does_not_start_a_count() {
+        // GIVEN
+        let tasks = vec![create_task("todo one", TaskStatus::Todo)];
+        let mut app = create_app(tasks, Some(0));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('0'));
+
+        // THEN
+        assert_eq!(app.pending_count, None);
+    }
+
+    #[test]
+    fn upper_u_toggles_utc_display_and_reports_mode() {
+        // GIVEN
+        let mut app = create_app(vec![], None);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('U'));
+
+        // THEN
+        assert!(app.display_utc);
+        assert_eq!(app.notification.as_deref(), Some("Showing times in UTC"));
+        app.handle_key_event(KeyCode::Char('U'));
+        assert!(!app.display_utc);
+        assert_eq!(
+            app.notification.as_deref(),
+            Some("Showing times in local time")
+        );
+    }
+
+    #[test]
+    fn ctrl_o_twice_returns_to_first_visited_task() {
+        // GIVEN
+        let tasks = vec![
+            create_task("a", TaskStatus::Todo),
+            create_task("b", TaskStatus::Todo),
+            create_task("c", TaskStatus::Todo),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        let expected = app.tasks[0].id;
+        app.handle_key_event(KeyCode::Char('j'));
+        app.handle_key_event(KeyCode::Char('j'));
+
+        // WHEN
+        app.handle_key(KeyCode::Char('o'), KeyModifiers::CONTROL);
+        app.handle_key(KeyCode::Char('o'), KeyModifiers::CONTROL);
+
+        // THEN
+        assert_eq!(app.tasks[app.selected_index.unwrap()].id, expected);
+    }
+
+    #[test]
+    fn ctrl_n_moves_forward_after_ctrl_o() {
+        // GIVEN
+        let tasks = vec![
+            create_task("a", TaskStatus::Todo),
+            create_task("b", TaskStatus::Todo),
+            create_task("c", TaskStatus::Todo),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        let expected = app.tasks[2].id;
+        app.handle_key_event(KeyCode::Char('j'));
+        app.handle_key_event(KeyCode::Char('j'));
+        app.handle_key(KeyCode::Char('o'), KeyModifiers::CONTROL);
+        app.handle_key(KeyCode::Char('o'), KeyModifiers::CONTROL);
+
+        // WHEN
+        app.handle_key(KeyCode::Char('n'), KeyModifiers::CONTROL);
+        app.handle_key(KeyCode::Char('n'), KeyModifiers::CONTROL);
+
+        // THEN
+        assert_eq!(app.tasks[app.selected_index.unwrap()].id, expected);
+        assert_eq!(app.tasks[2].status, TaskStatus::Todo);
+    }
+
+    #[test]
+    fn ctrl_o_skips_tasks_that_no_longer_exist() {
+        // GIVEN
+        let tasks = vec![
+            create_task("a", TaskStatus::Todo),
+            create_task("b", TaskStatus::Todo),
+            create_task("c", TaskStatus::Todo),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        let expected = app.tasks[0].id;
+        app.handle_key_event(KeyCode::Char('j'));
+        app.handle_key_event(KeyCode::Char('j'));
+        app.tasks.remove(1);
+        app.selected_index = Some(1);
+
+        // WHEN
+        app.handle_key(KeyCode::Char('o'), KeyModifiers::CONTROL);
+
+        // THEN
+        assert_eq!(app.tasks[app.selected_index.unwrap()].id, expected);
+    }
+
+    #[test]
+    fn moving_after_ctrl_o_drops_newer_jumps() {
+        // GIVEN
+        let tasks = vec![
+            create_task("a", TaskStatus::Todo),
+            create_task("b", TaskStatus::Todo),
+            create_task("c", TaskStatus::Todo),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        app.handle_key_event(KeyCode::Char('j'));
+        app.handle_key_event(KeyCode::Char('j'));
+        app.handle_key(KeyCode::Char('o'), KeyModifiers::CONTROL);
+        app.handle_key(KeyCode::Char('o'), KeyModifiers::CONTROL);
+        let expected = vec![app.tasks[0].id, app.tasks[2].id];
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('G'));
+
+        // THEN
+        assert_eq!(app.jump_list, expected);
+        assert_eq!(app.jump_cursor, 1);
+    }
+
+    #[test]
+    fn double_g_after_timeout_does_not_select_first_task() {
+        // GIVEN
+        let tasks = vec![
+            create_task("todo one", TaskStatus::Todo),
+            create_task("todo two", TaskStatus::Todo),
+            create_task("todo three", TaskStatus::Todo),
+        ];
+        let mut app = create_app(tasks, Some(2));
+        app.pending_g_at = Some(Instant::now() - DOUBLE_KEY_TIMEOUT - Duration::from_millis(1));
+        let expected = Some(2);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('g'));
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+        assert!(app.pending_g_at.is_some());
+    }
+
+    #[test]
+    fn key_after_single_g_performs_its_normal_action() {
+        // GIVEN
+        let tasks = vec![
+            create_task("todo one", TaskStatus::Todo),
+            create_task("todo two", TaskStatus::Todo),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        app.handle_key_event(KeyCode::Char('g'));
+        let expected = Some(1);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('j'));
+
+        // THEN
+        assert_eq!(app.selected_index, expected);
+    }
+
+    #[test]
+    fn z_cycles_expanded_column_and_navigation_still_crosses_collapsed_ones() {
+        // GIVEN
+        let tasks = vec![
+            create_task("todo", TaskStatus::Todo),
+            create_task("doing", TaskStatus::Doing),
+        ];
+        let mut app = create_app(tasks, Some(0));
+
+        // WHEN
+        let expanded = [(); 4].map(|_| {
+            app.handle_key_event(KeyCode::Char('z'));
+            app.expanded_panel
+        });
+        app.handle_key_event(KeyCode::Char('z'));
+        app.handle_key_event(KeyCode::Char('l'));
+
+        // THEN
+        assert_eq!(
+            expanded,
+            [
+                Some(TaskStatus::Parking),
+                Some(TaskStatus::Todo),
+                Some(TaskStatus::Doing),
+                None
+            ]
+        );
+        assert_eq!(app.expanded_panel, Some(TaskStatus::Parking));
+        assert_eq!(app.tasks[app.selected_index.unwrap()].name, "doing");
+    }
+
+    #[test]
+    fn hiding_done_selects_nearby_visible_task() {
+        // GIVEN
+        let tasks = vec![
+            create_task("doing", TaskStatus::Doing),
+            create_task("done", TaskStatus::Done),
+        ];
+        let mut app = create_app(tasks, Some(1));
+        app.done_loaded = true;
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('d'));
+
+        // THEN
+        assert!(!app.done_loaded);
+        assert_eq!(app.tasks.len(), 1);
+        assert_eq!(app.selected_index, Some(0));
+        assert_eq!(app.tasks[0].status, TaskStatus::Doing);
+    }
+
+    #[test]
+    fn opening_done_shows_only_current_week_tasks() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let current_week_start = Task::week_start(Local::now().date_naive());
+        let completed_dates = [
+            ("current done", current_week_start),
+            (
+                "previous done",
+                current_week_start.checked_sub_days(Days::new(1)).unwrap(),
+            ),
+        ];
+        completed_dates
+            .into_iter()
+            .map(|(name, date)| {
+                let mut task = Task::new_in(name.to_string(), tasks_dir.clone());
+                task.status = TaskStatus::Done;
+                task.completed_at = date.and_hms_opt(12, 0, 0);
+                task
+            })
+            .try_for_each(|task| task.save())
+            .unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let expected = ["current done"];
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('d'));
+
+        // THEN
+        let actual = app
+            .tasks
+            .iter()
+            .filter(|task| task.status == TaskStatus::Done)
+            .map(|task| task.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+        assert_eq!(app.done_week_start, current_week_start);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn left_bracket_shows_previous_done_week() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let current_week_start = Task::week_start(Local::now().date_naive());
+        let previous_week_start = current_week_start.checked_sub_days(Days::new(7)).unwrap();
+        let mut task = Task::new_in("previous done".to_string(), tasks_dir.clone());
+        task.status = TaskStatus::Done;
+        task.completed_at = previous_week_start.and_hms_opt(12, 0, 0);
+        task.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('d'));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('['));
+
+        // THEN
+        let actual = app
+            .tasks
+            .iter()
+            .find(|task| task.status == TaskStatus::Done)
+            .map(|task| task.name.as_str());
+        assert_eq!(actual, Some("previous done"));
+        assert_eq!(app.done_week_start, previous_week_start);
+        assert_eq!(
+            app.selected_index.map(|index| app.tasks[index].status),
+            Some(TaskStatus::Done)
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn changing_to_empty_done_week_clears_task_selection() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut todo = Task::new_in("visible todo".to_string(), tasks_dir.clone());
+        todo.save().unwrap();
+        todo.status = TaskStatus::Parking;
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('d'));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('['));
+
+        // THEN
+        assert_eq!(app.selected_index, None);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn right_bracket_does_not_move_beyond_current_week() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        fs::create_dir_all(&tasks_dir).unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('d'));
+        let expected = app.done_week_start;
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char(']'));
+
+        // THEN
+        assert_eq!(app.done_week_start, expected);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn completing_task_during_past_week_review_keeps_it_out_of_done_column() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut target = Task::new_in("current completion".to_string(), tasks_dir.clone());
+        target.save().unwrap();
+        target.update_status(TaskStatus::Doing).unwrap();
+        let neighbor = Task::new_in("visible neighbor".to_string(), tasks_dir.clone());
+        neighbor.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.done_loaded = true;
+        app.done_week_start = Task::week_start(Local::now().date_naive())
+            .checked_sub_days(Days::new(7))
+            .unwrap();
+        app.selected_index = app
+            .tasks
+            .iter()
+            .position(|task| task.name == "current completion");
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('n'));
+
+        // THEN
+        assert!(
+            app.tasks
+                .iter()
+                .all(|task| task.name != "current completion")
+        );
+        let selected_name = app
+            .selected_index
+            .and_then(|index| app.tasks.get(index))
+            .map(|task| task.name.as_str());
+        assert_eq!(selected_name, Some("visible neighbor"));
+        let done_path = tasks_dir.join("done").join(format!("{}.md", target.id));
+        assert!(done_path.exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn archiving_with_zero_done_tasks_is_a_no_op() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("active todo".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('A'));
+
+        // THEN
+        assert_eq!(app.pending_confirm, None);
+        assert_eq!(app.notification.as_deref(), Some("Nothing to archive"));
+        assert!(task.file_path().exists());
+        assert!(!tasks_dir.join("archive").exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn archiving_done_tasks_requires_confirmation() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("finished".to_string(), tasks_dir.clone());
+        task.status = TaskStatus::Done;
+        task.completed_at = Some(Local::now().naive_local());
+        task.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('d'));
+        let archived_path = tasks_dir.join("archive").join(format!("{}.md", task.id));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('A'));
+
+        // THEN
+        assert_eq!(app.pending_confirm, Some(PendingAction::ArchiveDone(1)));
+        assert!(task.file_path().exists());
+
+        app.handle_key_event(KeyCode::Char('y'));
+        assert_eq!(app.pending_confirm, None);
+        assert!(!task.file_path().exists());
+        assert!(archived_path.exists());
+        assert!(app.tasks.iter().all(|task| task.status != TaskStatus::Done));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn confirmation_runs_action_only_on_configured_key() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("finished".to_string(), tasks_dir.clone());
+        task.status = TaskStatus::Done;
+        task.completed_at = Some(Local::now().naive_local());
+        task.save().unwrap();
+        let mut app = App::with_config(Config {
+            confirm_key: 'o',
+            ..Config::with_tasks_dir(tasks_dir.clone())
+        });
+        app.handle_key_event(KeyCode::Char('A'));
+        app.handle_key_event(KeyCode::Char('y'));
+        assert_eq!(app.pending_confirm, None);
+        assert!(task.file_path().exists());
+        app.handle_key_event(KeyCode::Char('A'));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('o'));
+
+        // THEN
+        assert!(!task.file_path().exists());
+        assert!(
+            tasks_dir
+                .join("archive")
+                .join(format!("{}.md", task.id))
+                .exists()
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn unanswered_confirmation_is_cancelled_after_timeout() {
+        // GIVEN
+        let mut app = create_app(vec![], None);
+        app.config.confirm_timeout_secs = 5;
+        app.request_confirm(PendingAction::ArchiveDone(3));
+        app.pending_confirm_at = Some(Instant::now() - Duration::from_secs(6));
+
+        // WHEN
+        app.tick();
+
+        // THEN
+        assert_eq!(app.pending_confirm, None);
+        assert_eq!(app.notification.as_deref(), Some("Cancelled"));
+    }
+
+    #[test]
+    fn confirmation_prompt_names_configured_key() {
+        // GIVEN
+        let archive = PendingAction::ArchiveDone(2);
+        let purge = PendingAction::PurgeDone(2);
+
+        // WHEN
+        let archive_prompt = archive.prompt('o', "");
+        let purge_prompt = purge.prompt('o', "pur");
+
+        // THEN
+        assert_eq!(archive_prompt, "Archive 2 done tasks? (o/n)");
+        assert_eq!(
+            purge_prompt,
+            "Permanently delete 2 done tasks? Type \"purge\" and press Enter: pur"
+        );
+    }
+
+    #[test]
+    fn stale_selected_index_is_cleared_instead_of_panicking() {
+        // GIVEN
+        let tasks = vec![create_task("only", TaskStatus::Todo)];
+        let mut app = create_app(tasks, Some(5));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Enter);
+        app.handle_key_event(KeyCode::Char('n'));
+        app.handle_key_event(KeyCode::Char('N'));
+
+        // THEN
+        assert_eq!(app.selected_index, None);
+        assert!(app.open_files.is_empty());
+        assert_eq!(app.tasks[0].status, TaskStatus::Todo);
+    }
+
+    #[test]
+    fn column_jump_and_reorder_ignore_stale_selected_index() {
+        // GIVEN: a selection left behind after the list shrank
+        let tasks = vec![
+            create_task("todo", TaskStatus::Todo),
+            create_task("doing", TaskStatus::Doing),
+        ];
+        let mut app = create_app(tasks, Some(5));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Tab);
+        app.handle_key_event(KeyCode::BackTab);
+        app.handle_key_event(KeyCode::Char('J'));
+        app.handle_key_event(KeyCode::Char('K'));
+
+        // THEN
+        assert_eq!(app.selected_index, Some(5));
+        assert!(app.tasks.iter().all(|task| task.order.is_none()));
+    }
+
+    #[test]
+    fn enter_without_selection_shows_notification() {
+        // GIVEN
+        let mut app = create_app(Vec::new(), None);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Enter);
+
+        // THEN
+        assert!(app.open_files.is_empty());
+        assert_eq!(app.notification.as_deref(), Some("No task selected"));
+    }
+
+    #[test]
+    fn after_edit_notifies_summary_of_external_changes() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        Task::new_in("before".to_string(), tasks_dir.clone())
+            .save()
+            .unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Enter);
+        let mut edited = app.tasks[0].clone();
+        edited.name = "after".to_string();
+        edited.save_metadata().unwrap();
+        edited.save_body("one\ntwo\nthree\n").unwrap();
+
+        // WHEN
+        app.after_edit();
+
+        // THEN
+        assert_eq!(app.tasks[0].name, "after");
+        assert_eq!(app.notification.as_deref(), Some("+3 lines, name changed"));
+        assert!(app.pre_edit.is_none());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn startup_archives_done_tasks_older_than_configured_days() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut old = Task::new_in("old".to_string(), tasks_dir.clone());
+        old.save().unwrap();
+        old.update_status(TaskStatus::Done).unwrap();
+        old.completed_at = Some(Local::now().naive_local() - TimeDelta::days(10));
+        old.save_metadata().unwrap();
+        let mut recent = Task::new_in("recent".to_string(), tasks_dir.clone());
+        recent.save().unwrap();
+        recent.update_status(TaskStatus::Done).unwrap();
+        let config = Config {
+            auto_archive_done_days: Some(7),
+            ..Config::with_tasks_dir(tasks_dir.clone())
+        };
+
+        // WHEN
+        let mut app = App::with_config(config);
+        let archived_by_construction = !old.file_path().exists();
+        app.startup();
+
+        // THEN
+        assert!(!archived_by_construction);
+        assert!(
+            tasks_dir
+                .join("archive")
+                .join(format!("{}.md", old.id))
+                .exists()
+        );
+        assert!(recent.file_path().exists());
+        assert_eq!(
+            app.notification.as_deref(),
+            Some("Archived 1 DONE tasks completed over 7 days ago")
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn auto_archive_with_day_count_before_earliest_date_archives_nothing() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let config = Config {
+            auto_archive_done_days: Some(u32::MAX),
+            ..Config::with_tasks_dir(tasks_dir.clone())
+        };
+        let store = TaskStore::new(tasks_dir);
+
+        // WHEN
+        let notice = App::auto_archive_done(&store, &config, Local::now().naive_local());
+
+        // THEN
+        assert!(notice.unwrap().is_none());
+    }
+
+    #[test]
+    fn confirming_form_writes_changed_fields_to_file() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        Task::new_in("draft plan".to_string(), tasks_dir.clone())
+            .save()
+            .unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('M'));
+        for _ in 0.."plan".len() {
+            app.handle_key_event(KeyCode::Backspace);
+        }
+        for c in "report".chars() {
+            app.handle_key_event(KeyCode::Char(c));
+        }
+        app.handle_key_event(KeyCode::Tab);
+        app.form_fields[1].clear();
+        for c in "2030/01/15".chars() {
+            app.handle_key_event(KeyCode::Char(c));
+        }
+
+        // WHEN
+        app.handle_key_event(KeyCode::Enter);
+
+        // THEN
+        let saved = app.tasks[0].reload().unwrap();
+        assert!(app.input_mode == Mode::Normal);
+        assert_eq!(saved.name, "draft report");
+        assert_eq!(
+            saved.deadline,
+            NaiveDate::from_ymd_opt(2030, 1, 15).unwrap()
+        );
+        assert_eq!(app.notification.as_deref(), Some("Updated name, deadline"));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn invalid_form_deadline_keeps_form_open() {
+        // GIVEN
+        let tasks = vec![create_task("task", TaskStatus::Todo)];
+        let mut app = create_app(tasks, Some(0));
+        app.handle_key_event(KeyCode::Char('M'));
+        app.form_fields[1] = "next week".to_string();
+
+        // WHEN
+        app.handle_key_event(KeyCode::Enter);
+
+        // THEN
+        assert!(app.input_mode == Mode::Form);
+        assert_eq!(
+            app.error_message.as_deref(),
+            Some("Invalid deadline \"next week\": use YYYY/MM/DD")
+        );
+    }
+
+    #[test]
+    fn y_queues_selected_task_path_for_clipboard() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("copy me".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let expected = task.file_path().display().to_string();
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('y'));
+
+        // THEN
+        assert_eq!(app.clipboard.as_deref(), Some(expected.as_str()));
+        assert_eq!(app.notification.as_deref(), Some("Path copied"));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn y_without_selection_only_notifies() {
+        // GIVEN
+        let mut app = create_app(vec![], None);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('y'));
+
+        // THEN
+        assert_eq!(app.clipboard, None);
+        assert_eq!(app.notification.as_deref(), Some("No task selected"));
+    }
+
+    #[test]
+    fn first_run_requires_no_tasks_and_no_onboarded_state() {
+        // GIVEN
+        let empty_dir = temporary_tasks_dir();
+        fs::create_dir_all(&empty_dir).unwrap();
+        let onboarded_dir = temporary_tasks_dir();
+        State {
+            onboarded: true,
+            ..State::default()
+        }
+        .save(&onboarded_dir)
+        .unwrap();
+        let parking_dir = temporary_tasks_dir();
+        Task::new_with_status(
+            "parked".to_string(),
+            TaskStatus::Parking,
+            parking_dir.clone(),
+        )
+        .save()
+        .unwrap();
+
+        // WHEN
+        let actual = [&empty_dir, &onboarded_dir, &parking_dir]
+            .map(|dir| App::with_tasks_dir(dir.clone()).first_run);
+
+        // THEN
+        assert_eq!(actual, [true, false, false]);
+
+        for dir in [empty_dir, onboarded_dir, parking_dir] {
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn dismissing_onboarding_is_remembered() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+
+        // WHEN
+        app.handle_key_event(KeyCode::Esc);
+
+        // THEN
+        assert!(!app.first_run);
+        assert!(!app.should_quit);
+        assert!(State::load(&tasks_dir).onboarded);
+        assert!(!App::with_tasks_dir(tasks_dir.clone()).first_run);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn cut_from_todo_and_paste_into_doing_moves_file() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut doing = Task::new_in("doing".to_string(), tasks_dir.clone());
+        doing.save().unwrap();
+        doing.update_status(TaskStatus::Doing).unwrap();
+        let todo = Task::new_in("todo".to_string(), tasks_dir.clone());
+        todo.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.selected_index = app.tasks.iter().position(|task| task.id == todo.id);
+        app.handle_key_event(KeyCode::Char('x'));
+        assert!(!app.is_visible(&todo));
+        app.selected_index = app.tasks.iter().position(|task| task.id == doing.id);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('p'));
+
+        // THEN
+        let pasted = &app.tasks[app.selected_index.unwrap()];
+        assert_eq!(pasted.id, todo.id);
+        assert_eq!(pasted.status, TaskStatus::Doing);
+        assert_eq!(pasted.order, Some(1));
+        assert!(
+            Task::status_dir(&tasks_dir, TaskStatus::Doing)
+                .join(format!("{}.md", todo.id))
+                .exists()
+        );
+        assert_eq!(app.cut_task, None);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn capital_l_selects_task_referenced_in_body() {
+        // GIVEN: a TODO task whose body mentions a DOING task by id
+        let tasks_dir = temporary_tasks_dir();
+        let mut target = Task::new_in("target".to_string(), tasks_dir.clone());
+        target.save().unwrap();
+        target.update_status(TaskStatus::Doing).unwrap();
+        let mut source = Task::new_in("source".to_string(), tasks_dir.clone());
+        source.save().unwrap();
+        source
+            .save_body(&format!("see #{} first\n", target.id))
+            .unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.selected_index = app.tasks.iter().position(|task| task.id == source.id);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('L'));
+
+        // THEN: the target is selected and Ctrl-O returns to the source
+        assert_eq!(app.selected_id(), Some(target.id));
+        app.handle_key(KeyCode::Char('o'), KeyModifiers::CONTROL);
+        assert_eq!(app.selected_id(), Some(source.id));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn capital_l_without_reference_keeps_selection() {
+        // GIVEN: a body that only mentions the task's own id
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("plain".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        task.save_body(&format!("this is {}\n", task.id)).unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.selected_index = app.tasks.iter().position(|loaded| loaded.id == task.id);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('L'));
+
+        // THEN
+        assert_eq!(app.selected_id(), Some(task.id));
+        assert_eq!(
+            app.notification.as_deref(),
+            Some("No linked task in the body")
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn startup_detects_read_only_directory() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        fs::create_dir_all(&tasks_dir).unwrap();
+        fs::set_permissions(&tasks_dir, fs::Permissions::from_mode(0o555)).unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let read_only_after_construction = app.read_only;
+
+        // WHEN
+        app.startup();
+
+        // THEN: unless permissions are bypassed, as for root
+        let bypassed = fs::write(tasks_dir.join("bypass"), "").is_ok();
+        assert!(!read_only_after_construction);
+        assert_eq!(app.read_only, !bypassed);
+        assert_eq!(
+            app.error_message
+                .as_deref()
+                .is_some_and(|error| error.contains("not writable")),
+            !bypassed
+        );
+        fs::set_permissions(&tasks_dir, fs::Permissions::from_mode(0o755)).unwrap();
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn capital_c_requests_config_edit_and_reload_applies_settings() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let mut edited = Config::with_tasks_dir(PathBuf::from("/elsewhere"));
+        edited.confirm_key = 'o';
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('C'));
+        let requested = app.edit_config;
+        app.after_config_edit(Ok(edited));
+
+        // THEN
+        assert!(requested);
+        assert_eq!(app.config.confirm_key, 'o');
+        assert_eq!(app.config.tasks_dir, tasks_dir);
+        assert_eq!(app.store.root(), tasks_dir.as_path());
+        assert_eq!(
+            app.notification.as_deref(),
+            Some("Reloaded config; restart rem to use the new tasks_dir")
+        );
+    }
+
+    #[test]
+    fn failed_config_reload_keeps_current_settings() {
+        // GIVEN
+        let tasks = vec![create_task("kept", TaskStatus::Todo)];
+        let mut app = create_app(tasks, Some(0));
+        let confirm_key = app.config.confirm_key;
+
+        // WHEN
+        app.after_config_edit(Err(io::Error::other("invalid YAML")));
+
+        // THEN
+        assert_eq!(app.config.confirm_key, confirm_key);
+        assert_eq!(
+            app.error_message.as_deref(),
+            Some("Failed to reload config: invalid YAML")
+        );
+    }
+
+    #[test]
+    fn read_only_directory_refuses_keys_that_write_tasks() {
+        // GIVEN
+        let tasks = vec![create_task("locked", TaskStatus::Todo)];
+        let mut app = create_app(tasks, Some(0));
+        app.read_only = true;
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('a'));
+        app.handle_key_event(KeyCode::Char('n'));
+
+        // THEN
+        assert!(app.input_mode == Mode::Normal);
+        assert_eq!(app.tasks[0].status, TaskStatus::Todo);
+        assert!(
+            app.notification
+                .as_deref()
+                .is_some_and(|notification| notification.contains("not writable"))
+        );
+    }
+
+    #[test]
+    fn capital_w_collects_doing_task_files() {
+        // GIVEN
+        let first = create_task("first", TaskStatus::Doing);
+        let second = create_task("second", TaskStatus::Doing);
+        let expected = vec![first.file_path(), second.file_path()];
+        let tasks = vec![create_task("todo", TaskStatus::Todo), first, second];
+        let mut app = create_app(tasks, Some(0));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('W'));
+
+        // THEN
+        assert_eq!(app.open_files, expected);
+        assert!(app.pre_edit.is_none());
+    }
+
+    #[test]
+    fn capital_w_without_doing_tasks_notifies() {
+        // GIVEN
+        let tasks = vec![create_task("todo", TaskStatus::Todo)];
+        let mut app = create_app(tasks, Some(0));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('W'));
+
+        // THEN
+        assert!(app.open_files.is_empty());
+        assert_eq!(app.notification.as_deref(), Some("No DOING tasks to open"));
+    }
+
+    #[test]
+    fn dot_repeats_forward_status_on_the_new_selection() {
+        // GIVEN: the first of two TODO tasks was moved forward with n
+        let tasks_dir = temporary_tasks_dir();
+        let first = Task::new_in("first".to_string(), tasks_dir.clone());
+        first.save().unwrap();
+        let second = Task::new_in("second".to_string(), tasks_dir.clone());
+        second.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.selected_index = app.tasks.iter().position(|task| task.id == first.id);
+        app.handle_key_event(KeyCode::Char('n'));
+        app.selected_index = app.tasks.iter().position(|task| task.id == second.id);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('.'));
+
+        // THEN
+        assert!(
+            app.tasks
+                .iter()
+                .all(|task| task.status == TaskStatus::Doing)
+        );
+        assert!(
+            Task::status_dir(&tasks_dir, TaskStatus::Doing)
+                .join(format!("{}.md", second.id))
+                .exists()
+        );
+        assert_eq!(app.last_action, Some(RepeatableAction::ForwardStatus));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn dot_repeats_adding_a_task_with_the_same_name() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('a'));
+        for character in "standup".chars() {
+            app.handle_key_event(KeyCode::Char(character));
+        }
+        app.handle_key_event(KeyCode::Enter);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('.'));
+
+        // THEN
+        let names: Vec<&str> = app.tasks.iter().map(|task| task.name.as_str()).collect();
+        assert_eq!(names, ["standup", "standup"]);
+        assert!(app.input_mode == Mode::Normal);
+        assert!(app.input_buffer.is_empty());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn dot_without_previous_action_notifies() {
+        // GIVEN
+        let tasks = vec![create_task("only", TaskStatus::Todo)];
+        let mut app = create_app(tasks, Some(0));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('.'));
+
+        // THEN
+        assert_eq!(app.notification.as_deref(), Some("Nothing to repeat"));
+    }
+
+    #[test]
+    fn capital_i_reassigns_selected_task_id() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("duplicate".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.selected_index = app.tasks.iter().position(|loaded| loaded.id == task.id);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('I'));
+
+        // THEN
+        let reassigned = &app.tasks[app.selected_index.unwrap()];
+        assert_ne!(reassigned.id, task.id);
+        assert_eq!(reassigned.name, "duplicate");
+        assert!(reassigned.file_path().exists());
+        assert!(!task.file_path().exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn cutting_again_replaces_the_stashed_task() {
+        // GIVEN
+        let tasks = vec![
+            create_task("first", TaskStatus::Todo),
+            create_task("second", TaskStatus::Todo),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        let first = app.tasks[0].id;
+        let second = app.tasks[1].id;
+        app.handle_key_event(KeyCode::Char('x'));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('x'));
+
+        // THEN
+        assert_eq!(app.cut_task, Some(second));
+        assert_eq!(app.tasks[app.selected_index.unwrap()].id, first);
+    }
+
+    #[test]
+    fn edit_summary_reports_line_delta_and_name_change() {
+        // GIVEN
+        let body = "one\ntwo\nthree\n";
+
+        // WHEN
+        let removed = edit_summary("task", body, "task", "one\n");
+        let added_one = edit_summary("task", body, "task", "one\ntwo\nthree\nfour\n");
+        let rewritten = edit_summary("task", body, "renamed", "uno\ndos\ntres\n");
+        let unchanged = edit_summary("task", body, "task", body);
+
+        // THEN
+        assert_eq!(removed, "-2 lines");
+        assert_eq!(added_one, "+1 line");
+        assert_eq!(rewritten, "body edited, name changed");
+        assert_eq!(unchanged, "No changes to name or body");
+    }
+
+    #[test]
+    fn mailto_url_encodes_subject_and_body() {
+        // GIVEN
+        let name = "Review Q3 plan & budget?";
+        let body = "line one\nsee #42 – 100%\n";
+
+        // WHEN
+        let actual = mailto_url(name, body);
+
+        // THEN
+        assert_eq!(
+            actual,
+            "mailto:?subject=Review%20Q3%20plan%20%26%20budget%3F\
+             &body=line%20one%0D%0Asee%20%2342%20%E2%80%93%20100%25%0D%0A"
+        );
+    }
+
+    #[test]
+    fn o_starts_todo_task_and_opens_moved_file() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        Task::new_in("start me".to_string(), tasks_dir.clone())
+            .save()
+            .unwrap();
+        let mut doing = Task::new_in("already started".to_string(), tasks_dir.clone());
+        doing.save().unwrap();
+        doing.update_status(TaskStatus::Doing).unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let todo_index = app.tasks.iter().position(|task| task.name == "start me");
+        app.selected_index = todo_index;
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('o'));
+        let started = app.tasks[app.selected_index.unwrap()].clone();
+        let started_open_files = std::mem::take(&mut app.open_files);
+        app.selected_index = app
+            .tasks
+            .iter()
+            .position(|task| task.name == "already started");
+        app.handle_key_event(KeyCode::Char('o'));
+
+        // THEN
+        assert_eq!(started.name, "start me");
+        assert_eq!(started.status, TaskStatus::Doing);
+        assert_eq!(started_open_files, vec![started.file_path()]);
+        assert!(started.file_path().exists());
+        assert_eq!(app.open_files, vec![doing.file_path()]);
+        assert_eq!(doing.reload().unwrap().status, TaskStatus::Doing);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn edited_body_is_saved_to_task_file() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("notes".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        task.save_body("old\n").unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('e'));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Backspace);
+        app.handle_key_event(KeyCode::Backspace);
+        app.handle_key_event(KeyCode::Backspace);
+        for key_code in [KeyCode::Char('a'), KeyCode::Enter, KeyCode::Char('b')] {
+            app.handle_key_event(key_code);
+        }
+        app.handle_key_event(KeyCode::Up);
+        app.handle_key_event(KeyCode::Char('c'));
+        app.handle_key(KeyCode::Char('s'), KeyModifiers::CONTROL);
+
+        // THEN
+        assert!(app.input_mode == Mode::Normal);
+        assert_eq!(app.tasks[0].load_body().unwrap(), "ac\nb\n");
+        assert_eq!(app.notification.as_deref(), Some("Saved task body"));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn body_larger_than_limit_is_not_opened_in_editor() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut config = Config::with_tasks_dir(tasks_dir.clone());
+        config.body_editor_max_bytes = 1024;
+        let mut task = Task::new_in("huge log".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        task.save_body(&"log line\n".repeat(1000)).unwrap();
+        let mut app = App::with_config(config);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('e'));
+
+        // THEN
+        assert!(app.input_mode == Mode::Normal);
+        assert!(app.body_buffer.is_empty());
+        assert!(
+            app.error_message
+                .as_deref()
+                .unwrap()
+                .contains("larger than 1024 bytes")
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn pasted_lines_become_single_line_task_name() {
+        // GIVEN
+        let mut app = create_app(Vec::new(), None);
+        app.handle_key_event(KeyCode::Char('a'));
+        app.handle_key_event(KeyCode::Char('>'));
+
+        // WHEN
+        app.handle_paste("first line\r\nsecond\nthird");
+
+        // THEN
+        assert!(app.input_mode == Mode::Editing);
+        assert_eq!(app.input_buffer, ">first line second third");
+        assert_eq!(app.input_cursor, 24);
+    }
+
+    #[test]
+    fn paste_into_body_editor_keeps_line_breaks() {
+        // GIVEN
+        let mut app = create_app(Vec::new(), None);
+        app.input_mode = Mode::EditBody;
+
+        // WHEN
+        app.handle_paste("one\r\ntwo");
+
+        // THEN
+        assert_eq!(app.body_buffer, "one\ntwo");
+        assert_eq!(app.body_cursor, 7);
+    }
+
+    #[test]
+    fn escape_discards_body_edits() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("notes".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('e'));
+        app.handle_key_event(KeyCode::Char('x'));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Esc);
+
+        // THEN
+        assert!(app.input_mode == Mode::Normal);
+        assert!(!app.should_quit);
+        assert_eq!(app.tasks[0].load_body().unwrap(), "");
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn typed_name_is_saved_as_draft_and_removed_after_add() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('a'));
+        for character in "long task".chars() {
+            app.handle_key_event(KeyCode::Char(character));
+        }
+
+        // WHEN
+        app.tick();
+        let saved = draft::load(&tasks_dir).unwrap();
+        app.handle_key_event(KeyCode::Enter);
+
+        // THEN
+        assert_eq!(saved.as_deref(), Some("long task"));
+        assert!(!draft::path(&tasks_dir).exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn existing_draft_reopens_input_on_launch() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        draft::save(&tasks_dir, "recovered").unwrap();
+
+        // WHEN
+        let app = App::with_tasks_dir(tasks_dir.clone());
+
+        // THEN
+        assert!(app.input_mode == Mode::Editing);
+        assert_eq!(app.input_buffer, "recovered");
+        assert_eq!(app.input_cursor, 9);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn help_page_rotates_after_interval_and_wraps_around() {
+        // GIVEN
+        let mut app = create_app(Vec::new(), None);
+        let expired = || {
+            Instant::now()
+                .checked_sub(HELP_ROTATE_INTERVAL)
+                .expect("instant should support subtraction")
+        };
+
+        // WHEN
+        app.tick();
+        let before_interval = app.help_page;
+        let pages = (0..HELP_PAGE_COUNT)
+            .map(|_| {
+                app.help_page_shown_at = expired();
+                app.tick();
+                app.help_page
+            })
+            .collect::<Vec<_>>();
 
-    fn temporary_tasks_dir() -> PathBuf {
-        std::env::temp_dir().join(format!("rem-cli-app-test-{}", Uuid::new_v4()))
+        // THEN
+        assert_eq!(before_interval, 0);
+        assert_eq!(pages, (1..HELP_PAGE_COUNT).chain([0]).collect::<Vec<_>>());
     }
 
     #[test]
-    fn parking_is_not_loaded_during_initialization() {
+    fn question_mark_opens_help_overlay_and_any_key_closes_it() {
         // GIVEN
-        let tasks_dir = temporary_tasks_dir();
-        let app = App::with_tasks_dir(tasks_dir.clone());
+        let tasks = vec![create_task("first", TaskStatus::Todo)];
+        let mut app = create_app(tasks, Some(0));
 
         // WHEN
-        let parking_tasks = app
-            .tasks
-            .iter()
-            .filter(|task| task.status == TaskStatus::Parking)
-            .count();
+        app.handle_key_event(KeyCode::Char('?'));
+        let opened = app.show_help;
+        app.handle_key_event(KeyCode::Char('n'));
 
         // THEN
-        assert!(!app.parking_loaded);
-        assert_eq!(parking_tasks, 0);
-        assert!(!tasks_dir.exists());
+        assert!(opened);
+        assert!(!app.show_help);
+        assert_eq!(app.tasks[0].status, TaskStatus::Todo);
     }
 
     #[test]
-    fn parking_is_loaded_after_first_render() {
+    fn help_overlay_scrolls_with_j_and_k_within_keybindings() {
         // GIVEN
-        let tasks_dir = temporary_tasks_dir();
-        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let tasks = vec![
+            create_task("first", TaskStatus::Todo),
+            create_task("second", TaskStatus::Todo),
+        ];
+        let mut app = create_app(tasks, Some(0));
+        let line_count = cli::KEYBINDINGS.lines().count();
 
         // WHEN
-        app.load_parking_after_first_render();
+        app.handle_key_event(KeyCode::Char('?'));
+        app.handle_key_event(KeyCode::Char('k'));
+        let at_top = app.help_scroll;
+        for _ in 0..line_count + 5 {
+            app.handle_key_event(KeyCode::Char('j'));
+        }
+        let at_bottom = app.help_scroll;
+        app.handle_key_event(KeyCode::Up);
+        let after_up = app.help_scroll;
 
         // THEN
-        assert!(app.parking_loaded);
-        assert!(!tasks_dir.exists());
+        assert_eq!(at_top, 0);
+        assert_eq!(at_bottom, line_count - 1);
+        assert_eq!(after_up, line_count - 2);
+        assert!(app.show_help);
+        assert_eq!(app.selected_index, Some(0));
     }
 
     #[test]
-    fn parking_load_failure_is_retried() {
+    fn n_on_done_task_applies_done_forward_action() {
+        // GIVEN
+        let forward_done = |action| {
+            let tasks_dir = temporary_tasks_dir();
+            let mut config = Config::with_tasks_dir(tasks_dir.clone());
+            config.done_forward_action = action;
+            let mut task = Task::new_in("shipped".to_string(), tasks_dir.clone());
+            task.save().unwrap();
+            task.update_status(TaskStatus::Done).unwrap();
+            let mut app = App::with_config(config);
+            app.handle_key_event(KeyCode::Char('d'));
+            app.selected_index = app.tasks.iter().position(|task| task.name == "shipped");
+
+            // WHEN
+            app.handle_key_event(KeyCode::Char('n'));
+
+            let archived = tasks_dir
+                .join("archive")
+                .join(format!("{}.md", task.id))
+                .exists();
+            let result = (
+                task.file_path().exists(),
+                archived,
+                app.tasks.len(),
+                app.notification.clone(),
+            );
+            fs::remove_dir_all(tasks_dir).unwrap();
+            result
+        };
+
+        // THEN
+        assert_eq!(
+            forward_done(DoneForwardAction::None),
+            (true, false, 1, None)
+        );
+        assert_eq!(
+            forward_done(DoneForwardAction::Archive),
+            (false, true, 0, Some("Archived \"shipped\"".to_string()))
+        );
+        assert_eq!(
+            forward_done(DoneForwardAction::Delete),
+            (true, false, 1, None)
+        );
+    }
+
+    #[test]
+    fn n_deletes_done_task_only_after_confirmation_and_is_not_repeated() {
         // GIVEN
         let tasks_dir = temporary_tasks_dir();
-        fs::create_dir_all(&tasks_dir).unwrap();
-        fs::write(tasks_dir.join("parking"), "not a directory").unwrap();
-        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let mut config = Config::with_tasks_dir(tasks_dir.clone());
+        config.done_forward_action = DoneForwardAction::Delete;
+        let tasks = ["first", "second"].map(|name| {
+            let mut task = Task::new_in(name.to_string(), tasks_dir.clone());
+            task.save().unwrap();
+            task.update_status(TaskStatus::Done).unwrap();
+            task
+        });
+        let mut app = App::with_config(config);
+        app.handle_key_event(KeyCode::Char('d'));
+        app.selected_index = app.tasks.iter().position(|task| task.name == "first");
 
         // WHEN
-        app.load_parking_after_first_render();
+        app.handle_key_event(KeyCode::Char('n'));
+        let pending = app.pending_confirm.clone();
+        app.handle_key_event(KeyCode::Char('y'));
+        app.handle_key_event(KeyCode::Char('.'));
 
         // THEN
-        assert!(!app.parking_loaded);
-        assert!(app.error_message.is_some());
+        assert_eq!(
+            pending,
+            Some(PendingAction::DeleteTask {
+                id: tasks[0].id,
+                name: "first".to_string()
+            })
+        );
+        assert!(!tasks[0].file_path().exists());
+        assert!(tasks[1].reload().is_ok());
+        assert_eq!(app.last_action, None);
+        assert_eq!(app.notification, Some("Nothing to repeat".to_string()));
 
-        fs::remove_file(tasks_dir.join("parking")).unwrap();
-        app.load_parking_after_first_render();
-        assert!(app.parking_loaded);
-        assert!(app.error_message.is_none());
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn parking_load_success_keeps_initial_load_error() {
+    fn uncompleting_done_task_requires_confirmation_when_enabled() {
         // GIVEN
         let tasks_dir = temporary_tasks_dir();
-        fs::create_dir_all(&tasks_dir).unwrap();
-        fs::write(tasks_dir.join("todo"), "not a directory").unwrap();
-        let mut app = App::with_tasks_dir(tasks_dir.clone());
-        let expected = app.error_message.clone();
+        let mut config = Config::with_tasks_dir(tasks_dir.clone());
+        config.confirm_uncomplete = true;
+        let mut task = Task::new_in("shipped".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        task.update_status(TaskStatus::Done).unwrap();
+        let mut app = App::with_config(config);
+        app.handle_key_event(KeyCode::Char('d'));
+        app.selected_index = app.tasks.iter().position(|task| task.name == "shipped");
 
         // WHEN
-        app.load_parking_after_first_render();
+        app.handle_key_event(KeyCode::Char('N'));
+        let pending = app.pending_confirm.clone();
+        let still_done = task.file_path().exists();
+        app.handle_key_event(KeyCode::Char('y'));
 
         // THEN
-        assert!(app.parking_loaded);
-        assert_eq!(app.error_message, expected);
+        assert_eq!(
+            pending.map(|action| action.message()),
+            Some("Move \"shipped\" back to DOING?".to_string())
+        );
+        assert!(still_done);
+        assert!(!task.file_path().exists());
+        assert!(
+            tasks_dir
+                .join("doing")
+                .join(format!("{}.md", task.id))
+                .exists()
+        );
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn reload_key_loads_parking_todo_and_doing_tasks() {
+    fn status_flow_without_doing_moves_todo_straight_to_done_and_back() {
         // GIVEN
         let tasks_dir = temporary_tasks_dir();
-        let tasks = [
-            ("reloaded parking", TaskStatus::Parking),
-            ("reloaded todo", TaskStatus::Todo),
-            ("reloaded doing", TaskStatus::Doing),
-        ];
-        tasks
-            .into_iter()
-            .map(|(name, status)| {
-                let mut task = Task::new_in(name.to_string(), tasks_dir.clone());
-                task.status = status;
-                task
-            })
-            .try_for_each(|task| task.save())
-            .unwrap();
-        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let mut config = Config::with_tasks_dir(tasks_dir.clone());
+        config.status_flow = vec![TaskStatus::Todo, TaskStatus::Done];
+        config.confirm_uncomplete = true;
+        let task = Task::new_in("review".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let mut app = App::with_config(config);
+        app.handle_key_event(KeyCode::Char('d'));
+        app.selected_index = Some(0);
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('r'));
+        app.handle_key_event(KeyCode::Char('n'));
+        let forwarded = app.tasks[app.selected_index.unwrap()].status;
+        app.handle_key_event(KeyCode::Char('N'));
+        let pending = app.pending_confirm.clone();
+        app.handle_key_event(KeyCode::Char('y'));
+        app.handle_key_event(KeyCode::Char('N'));
 
         // THEN
-        let actual = app
-            .tasks
-            .iter()
-            .map(|task| task.name.as_str())
-            .collect::<Vec<_>>();
-        let expected = ["reloaded parking", "reloaded todo", "reloaded doing"];
-        assert_eq!(actual, expected);
-        assert!(app.parking_loaded);
+        assert_eq!(forwarded, TaskStatus::Done);
+        assert_eq!(
+            pending.map(|action| action.message()),
+            Some("Move \"review\" back to TODO?".to_string())
+        );
+        assert_eq!(
+            app.tasks[app.selected_index.unwrap()].status,
+            TaskStatus::Todo
+        );
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn reload_key_keeps_done_hidden_when_done_is_not_loaded() {
+    fn purge_requires_typing_purge_and_keeps_active_tasks() {
         // GIVEN
         let tasks_dir = temporary_tasks_dir();
-        let mut task = Task::new_in("hidden done".to_string(), tasks_dir.clone());
-        task.status = TaskStatus::Done;
-        task.completed_at = Some(Local::now().naive_local());
-        task.save().unwrap();
+        let mut done = Task::new_in("done".to_string(), tasks_dir.clone());
+        done.save().unwrap();
+        done.update_status(TaskStatus::Done).unwrap();
+        let todo = Task::new_in("todo".to_string(), tasks_dir.clone());
+        todo.save().unwrap();
         let mut app = App::with_tasks_dir(tasks_dir.clone());
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('r'));
+        app.handle_key_event(KeyCode::Char('P'));
+        app.handle_key_event(KeyCode::Char('y'));
+        let survives_y = done.file_path().exists() && app.pending_confirm.is_some();
+        for key_code in "urge".chars().map(KeyCode::Char).chain([KeyCode::Enter]) {
+            app.handle_key_event(key_code);
+        }
+        let wrong_word_cancels = app.pending_confirm.is_none() && done.file_path().exists();
+        app.handle_key_event(KeyCode::Char('P'));
+        for key_code in "purge".chars().map(KeyCode::Char).chain([KeyCode::Enter]) {
+            app.handle_key_event(key_code);
+        }
 
         // THEN
-        assert!(!app.done_loaded);
-        assert!(app.tasks.iter().all(|task| task.status != TaskStatus::Done));
+        assert!(survives_y);
+        assert!(wrong_word_cancels);
+        assert!(!done.file_path().exists());
+        assert!(todo.file_path().exists());
+        assert_eq!(app.notification.as_deref(), Some("Deleted 1 done tasks"));
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn reload_key_refreshes_visible_done_week() {
+    fn tab_while_adding_creates_task_directly_in_done() {
         // GIVEN
         let tasks_dir = temporary_tasks_dir();
+        Task::ensure_dirs(&tasks_dir).unwrap();
         let mut app = App::with_tasks_dir(tasks_dir.clone());
         app.handle_key_event(KeyCode::Char('d'));
-        let mut task = Task::new_in("visible done".to_string(), tasks_dir.clone());
-        task.status = TaskStatus::Done;
-        task.completed_at = Some(app.done_week_start.and_hms_opt(12, 0, 0).unwrap());
-        task.save().unwrap();
+        app.handle_key_event(KeyCode::Char('a'));
+        for character in "already finished".chars() {
+            app.handle_key_event(KeyCode::Char(character));
+        }
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('r'));
+        app.handle_key_event(KeyCode::BackTab);
+        app.handle_key_event(KeyCode::BackTab);
+        let chosen_status = app.add_status;
+        app.handle_key_event(KeyCode::Enter);
 
         // THEN
-        let actual = app
-            .tasks
-            .iter()
-            .find(|task| task.status == TaskStatus::Done)
-            .map(|task| task.name.as_str());
-        let expected = Some("visible done");
-        assert_eq!(actual, expected);
-        assert!(app.done_loaded);
+        let task = &app.tasks[0];
+        assert_eq!(chosen_status, TaskStatus::Done);
+        assert_eq!(task.name, "already finished");
+        assert_eq!(task.status, TaskStatus::Done);
+        assert!(task.completed_at.is_some());
+        assert!(task.file_path().starts_with(tasks_dir.join("done")));
+        assert!(task.file_path().exists());
+        assert!(
+            !tasks_dir
+                .join("todo")
+                .join(format!("{}.md", task.id))
+                .exists()
+        );
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn reload_key_updates_last_updated_at_after_success() {
+    fn adding_task_appends_line_to_debug_log_when_enabled() {
         // GIVEN
         let tasks_dir = temporary_tasks_dir();
-        fs::create_dir_all(&tasks_dir).unwrap();
+        let log_path = tasks_dir.join("rem.log");
         let mut app = App::with_tasks_dir(tasks_dir.clone());
-        let expected_before = NaiveDate::from_ymd_opt(2026, 6, 15)
-            .unwrap()
-            .and_hms_opt(10, 30, 45)
-            .unwrap();
-        app.last_updated_at = expected_before;
+        app.log = Some(Log::new(log_path.clone()));
+        app.handle_key_event(KeyCode::Char('a'));
+        for character in "logged".chars() {
+            app.handle_key_event(KeyCode::Char(character));
+        }
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('r'));
+        app.handle_key_event(KeyCode::Enter);
 
         // THEN
-        assert!(app.last_updated_at > expected_before);
+        let content = fs::read_to_string(&log_path).unwrap();
+        let expected = format!("Added {} \"logged\" to TODO", app.tasks[0].id);
+        assert_eq!(content.lines().count(), 1);
+        assert!(content.trim_end().ends_with(&expected));
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn reload_key_keeps_existing_state_when_load_fails() {
+    fn add_task_failure_reports_error_and_keeps_input() {
         // GIVEN
         let tasks_dir = temporary_tasks_dir();
-        let task = Task::new_in("existing todo".to_string(), tasks_dir.clone());
-        task.save().unwrap();
-        let mut app = App::with_tasks_dir(tasks_dir.clone());
-        let expected_tasks = app
-            .tasks
-            .iter()
-            .map(|task| (task.name.clone(), task.status))
-            .collect::<Vec<_>>();
-        let expected_selected_index = app.selected_index;
-        let expected_last_updated_at = NaiveDate::from_ymd_opt(2026, 6, 15)
-            .unwrap()
-            .and_hms_opt(10, 30, 45)
-            .unwrap();
-        app.last_updated_at = expected_last_updated_at;
-        fs::remove_dir_all(tasks_dir.join("todo")).unwrap();
+        fs::create_dir_all(&tasks_dir).unwrap();
         fs::write(tasks_dir.join("todo"), "not a directory").unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char('a'));
+        for character in "unsaved".chars() {
+            app.handle_key_event(KeyCode::Char(character));
+        }
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('r'));
+        app.handle_key_event(KeyCode::Enter);
 
         // THEN
-        let actual_tasks = app
-            .tasks
-            .iter()
-            .map(|task| (task.name.clone(), task.status))
-            .collect::<Vec<_>>();
-        assert_eq!(actual_tasks, expected_tasks);
-        assert_eq!(app.selected_index, expected_selected_index);
-        assert_eq!(app.last_updated_at, expected_last_updated_at);
+        assert!(app.tasks.iter().all(|task| task.name != "unsaved"));
+        assert!(app.input_mode == Mode::Editing);
+        assert_eq!(app.input_buffer, "unsaved");
         assert!(
             app.error_message
                 .as_deref()
                 .unwrap()
-                .contains("Failed to reload tasks")
+                .contains("Failed to add task")
         );
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn vertical_navigation_stays_within_current_status() {
+    fn view_preferences_survive_quit_but_search_does_not() {
         // GIVEN
-        let tasks = vec![
-            create_task("todo one", TaskStatus::Todo),
-            create_task("todo two", TaskStatus::Todo),
-            create_task("doing", TaskStatus::Doing),
-        ];
-        let mut app = create_app(tasks, Some(1));
+        let tasks_dir = temporary_tasks_dir();
+        Task::new_in("report".to_string(), tasks_dir.clone())
+            .save()
+            .unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        for key in ['v', 'i', 'f', 'f', 'd', '/', 'r'] {
+            app.handle_key_event(KeyCode::Char(key));
+        }
+        app.handle_key_event(KeyCode::Enter);
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('q'));
+        let restored = App::with_tasks_dir(tasks_dir.clone());
+
+        // THEN
+        assert!(app.should_quit);
+        assert_eq!(restored.layout_mode, LayoutMode::Compact);
+        assert!(restored.show_ids);
+        assert_eq!(restored.priority_filter, Some(Priority::High));
+        assert!(restored.done_loaded);
+        assert!(restored.search_query.is_empty());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn capital_t_shows_stats_of_every_task_file_until_a_key_is_pressed() {
+        // GIVEN: a TODO task and a DONE task that is not loaded
+        let tasks_dir = temporary_tasks_dir();
+        Task::new_in("open".to_string(), tasks_dir.clone())
+            .save()
+            .unwrap();
+        let mut done = Task::new_in("finished".to_string(), tasks_dir.clone());
+        done.save().unwrap();
+        done.update_status(TaskStatus::Done).unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
 
         // WHEN
+        app.handle_key_event(KeyCode::Char('T'));
+        let shown = app.stats.clone();
         app.handle_key_event(KeyCode::Char('j'));
 
         // THEN
-        assert_eq!(app.selected_index, Some(1));
-        assert_eq!(app.tasks[1].status, TaskStatus::Todo);
+        let shown = shown.unwrap();
+        assert_eq!(shown.counts, [0, 1, 0, 1]);
+        assert_eq!(shown.done_last_7_days, 1);
+        assert!(app.stats.is_none());
+        assert!(!app.done_loaded);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn horizontal_navigation_skips_empty_columns_and_clamps_row() {
+    fn hash_cycles_tag_filter_through_tag_bar() {
         // GIVEN
+        let tagged = |name: &str, tags: &[&str]| {
+            let mut task = create_task(name, TaskStatus::Todo);
+            task.tags = tags.iter().map(|tag| tag.to_string()).collect();
+            task
+        };
         let tasks = vec![
-            create_task("parking one", TaskStatus::Parking),
-            create_task("parking two", TaskStatus::Parking),
-            create_task("doing", TaskStatus::Doing),
+            tagged("plain", &[]),
+            tagged("errand", &["home"]),
+            tagged("report", &["work"]),
         ];
-        let mut app = create_app(tasks, Some(1));
-        let expected = app
+        let mut app = create_app(tasks, Some(0));
+        let visible = |app: &App| {
+            app.tasks
+                .iter()
+                .filter(|task| app.is_visible(task))
+                .map(|task| task.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        // WHEN
+        let shown = (0..3)
+            .map(|_| {
+                app.handle_key_event(KeyCode::Char('#'));
+                (visible(&app), app.notification.clone().unwrap())
+            })
+            .collect::<Vec<_>>();
+
+        // THEN
+        assert_eq!(
+            shown,
+            [
+                (vec!["errand".to_string()], "Showing #home".to_string()),
+                (vec!["report".to_string()], "Showing #work".to_string()),
+                (
+                    vec![
+                        "plain".to_string(),
+                        "errand".to_string(),
+                        "report".to_string()
+                    ],
+                    "Showing all tags".to_string()
+                ),
+            ]
+        );
+        assert_eq!(app.active_tag_filter, None);
+    }
+
+    #[test]
+    fn t_tags_every_marked_task_and_saves_each_file() {
+        // GIVEN: three TODO tasks, the first and last marked
+        let tasks_dir = temporary_tasks_dir();
+        for name in ["first", "second", "third"] {
+            Task::new_in(name.to_string(), tasks_dir.clone())
+                .save()
+                .unwrap();
+        }
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.handle_key_event(KeyCode::Char(' '));
+        app.handle_key_event(KeyCode::Char('j'));
+        app.handle_key_event(KeyCode::Char('j'));
+        app.handle_key_event(KeyCode::Char(' '));
+        let marked = app.marked.clone();
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('t'));
+        for c in " #backlog ".chars() {
+            app.handle_key_event(KeyCode::Char(c));
+        }
+        app.handle_key_event(KeyCode::Enter);
+
+        // THEN
+        assert!(app.input_mode == Mode::Normal);
+        assert_eq!(
+            app.notification.as_deref(),
+            Some("Tagged 2 tasks with #backlog")
+        );
+        assert!(app.marked.is_empty());
+        let reloaded = App::with_tasks_dir(tasks_dir.clone());
+        let tagged = reloaded
             .tasks
             .iter()
-            .position(|task| task.status == TaskStatus::Doing);
+            .filter(|task| task.tags == ["backlog"])
+            .map(|task| task.id)
+            .collect::<Vec<_>>();
+        assert_eq!(tagged.len(), 2);
+        assert!(tagged.iter().all(|id| marked.contains(id)));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn t_with_leading_minus_removes_tag_from_selected_task() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("tagged".to_string(), tasks_dir.clone());
+        task.tags = vec!["work".to_string()];
+        task.save().unwrap();
+        let mut app = create_app(vec![task.clone()], Some(0));
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('l'));
+        app.handle_key_event(KeyCode::Char('t'));
+        for c in "-work".chars() {
+            app.handle_key_event(KeyCode::Char(c));
+        }
+        app.handle_key_event(KeyCode::Enter);
 
         // THEN
-        assert_eq!(app.selected_index, expected);
+        assert_eq!(
+            app.notification.as_deref(),
+            Some("Removed #work from 1 task")
+        );
+        assert!(task.reload().unwrap().tags.is_empty());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn uppercase_g_selects_last_task_in_current_status() {
+    fn tomorrow_morning_crosses_day_month_and_year_boundaries() {
         // GIVEN
-        let tasks = vec![
-            create_task("todo one", TaskStatus::Todo),
-            create_task("todo two", TaskStatus::Todo),
-            create_task("todo three", TaskStatus::Todo),
-            create_task("doing", TaskStatus::Doing),
-        ];
-        let mut app = create_app(tasks, Some(0));
-        let expected = app
-            .tasks
-            .iter()
-            .enumerate()
-            .filter_map(|(index, task)| (task.status == TaskStatus::Todo).then_some(index))
-            .next_back();
+        let at = |year, month, day, hour, minute| {
+            NaiveDate::from_ymd_opt(year, month, day)
+                .unwrap()
+                .and_hms_opt(hour, minute, 0)
+                .unwrap()
+        };
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('G'));
+        let actual = [
+            at(2026, 6, 15, 0, 5),
+            at(2026, 6, 15, 23, 59),
+            at(2026, 2, 28, 18, 0),
+            at(2026, 12, 31, 22, 30),
+        ]
+        .map(tomorrow_morning);
 
         // THEN
-        assert_eq!(app.selected_index, expected);
         assert_eq!(
-            app.tasks[app.selected_index.unwrap()].status,
-            TaskStatus::Todo
+            actual,
+            [
+                at(2026, 6, 16, 9, 0),
+                at(2026, 6, 16, 9, 0),
+                at(2026, 3, 1, 9, 0),
+                at(2027, 1, 1, 9, 0),
+            ]
         );
     }
 
     #[test]
-    fn double_g_selects_first_task_in_current_status() {
+    fn capital_z_defers_selected_task_to_tomorrow_morning() {
         // GIVEN
-        let tasks = vec![
-            create_task("parking", TaskStatus::Parking),
-            create_task("todo one", TaskStatus::Todo),
-            create_task("todo two", TaskStatus::Todo),
-            create_task("todo three", TaskStatus::Todo),
-        ];
-        let mut app = create_app(tasks, Some(3));
-        let expected = app
-            .tasks
-            .iter()
-            .position(|task| task.status == TaskStatus::Todo);
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("end of day".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        let expected = tomorrow_morning(Local::now().naive_local());
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('g'));
-        app.handle_key_event(KeyCode::Char('g'));
+        app.handle_key_event(KeyCode::Char('Z'));
+        app.handle_key_event(KeyCode::Char('Z'));
 
         // THEN
-        assert_eq!(app.selected_index, expected);
+        let reloaded = task.reload().unwrap();
+        assert_eq!(reloaded.deadline, expected.date());
+        assert_eq!(reloaded.reminders, [expected]);
         assert_eq!(
-            app.tasks[app.selected_index.unwrap()].status,
-            TaskStatus::Todo
+            app.notification,
+            Some(format!(
+                "Deferred \"end of day\" to {} (reminder at 09:00)",
+                expected.format(DEADLINE_DATE_FORMAT)
+            ))
         );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn double_g_after_timeout_does_not_select_first_task() {
+    fn outside_edit_of_selected_file_is_flagged_until_reload() {
         // GIVEN
-        let tasks = vec![
-            create_task("todo one", TaskStatus::Todo),
-            create_task("todo two", TaskStatus::Todo),
-            create_task("todo three", TaskStatus::Todo),
-        ];
-        let mut app = create_app(tasks, Some(2));
-        app.pending_g_at = Some(Instant::now() - DOUBLE_KEY_TIMEOUT - Duration::from_millis(1));
-        let expected = Some(2);
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("watched".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let mut app = App::with_tasks_dir(tasks_dir.clone());
+        app.tick();
+        let before_edit = app.selected_changed_on_disk;
 
-        // WHEN
-        app.handle_key_event(KeyCode::Char('g'));
+        // WHEN: another program rewrites the file
+        let content = fs::read_to_string(task.file_path()).unwrap();
+        fs::write(task.file_path(), content.replace("watched", "edited")).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(task.file_path())
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+        app.tick();
+        let after_edit = app.selected_changed_on_disk;
+        app.handle_key_event(KeyCode::Char('r'));
+        app.tick();
 
         // THEN
-        assert_eq!(app.selected_index, expected);
-        assert!(app.pending_g_at.is_some());
+        assert!(!before_edit);
+        assert!(after_edit);
+        assert!(!app.selected_changed_on_disk);
+        assert_eq!(app.tasks[app.selected_index.unwrap()].name, "edited");
+
+        fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn key_after_single_g_performs_its_normal_action() {
-        // GIVEN
-        let tasks = vec![
-            create_task("todo one", TaskStatus::Todo),
-            create_task("todo two", TaskStatus::Todo),
-        ];
-        let mut app = create_app(tasks, Some(0));
-        app.handle_key_event(KeyCode::Char('g'));
-        let expected = Some(1);
+    fn ephemeral_session_writes_tasks_but_no_state_or_draft() {
+        // GIVEN: a first run, so that the welcome overlay is dismissed too
+        let tasks_dir = temporary_tasks_dir();
+        let mut config = Config::with_tasks_dir(tasks_dir.clone());
+        config.ephemeral = true;
+        let mut app = App::with_config(config);
 
-        // WHEN
-        app.handle_key_event(KeyCode::Char('j'));
+        // WHEN: change a view preference, type a draft, add the task, and quit
+        app.handle_key_event(KeyCode::Char('v'));
+        app.handle_key_event(KeyCode::Char('a'));
+        app.handle_key_event(KeyCode::Char('x'));
+        app.tick();
+        app.handle_key_event(KeyCode::Enter);
+        app.handle_key_event(KeyCode::Char('q'));
 
         // THEN
-        assert_eq!(app.selected_index, expected);
+        assert!(app.should_quit);
+        assert_eq!(app.layout_mode, LayoutMode::Compact);
+        assert!(app.tasks.iter().any(|task| task.file_path().exists()));
+        assert!(!State::path(&tasks_dir).exists());
+        assert!(!draft::path(&tasks_dir).exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn hiding_done_selects_nearby_visible_task() {
+    fn confirm_on_quit_requires_two_quick_presses() {
         // GIVEN
-        let tasks = vec![
-            create_task("doing", TaskStatus::Doing),
-            create_task("done", TaskStatus::Done),
-        ];
-        let mut app = create_app(tasks, Some(1));
-        app.done_loaded = true;
+        let mut app = create_app(Vec::new(), None);
+        app.config.confirm_on_quit = true;
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('d'));
+        app.handle_key_event(KeyCode::Char('q'));
 
         // THEN
-        assert!(!app.done_loaded);
-        assert_eq!(app.tasks.len(), 1);
-        assert_eq!(app.selected_index, Some(0));
-        assert_eq!(app.tasks[0].status, TaskStatus::Doing);
+        assert!(!app.should_quit);
+        assert_eq!(app.notification.as_deref(), Some("Press q again to quit"));
+
+        app.handle_key_event(KeyCode::Char('q'));
+        assert!(app.should_quit);
     }
 
     #[test]
-    fn opening_done_shows_only_current_week_tasks() {
+    fn ctrl_q_quits_from_editing_mode_without_adding_task() {
         // GIVEN
         let tasks_dir = temporary_tasks_dir();
-        let current_week_start = Task::week_start(Local::now().date_naive());
-        let completed_dates = [
-            ("current done", current_week_start),
-            (
-                "previous done",
-                current_week_start.checked_sub_days(Days::new(1)).unwrap(),
-            ),
-        ];
-        completed_dates
-            .into_iter()
-            .map(|(name, date)| {
-                let mut task = Task::new_in(name.to_string(), tasks_dir.clone());
-                task.status = TaskStatus::Done;
-                task.completed_at = date.and_hms_opt(12, 0, 0);
-                task
-            })
-            .try_for_each(|task| task.save())
-            .unwrap();
         let mut app = App::with_tasks_dir(tasks_dir.clone());
-        let expected = ["current done"];
+        app.handle_key_event(KeyCode::Char('a'));
+        app.handle_paste("half typed");
+        app.tick();
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('d'));
+        let drafted = draft::path(&tasks_dir).exists();
+        app.handle_key(KeyCode::Char('q'), KeyModifiers::CONTROL);
 
         // THEN
-        let actual = app
-            .tasks
-            .iter()
-            .filter(|task| task.status == TaskStatus::Done)
-            .map(|task| task.name.as_str())
-            .collect::<Vec<_>>();
-        assert_eq!(actual, expected);
-        assert_eq!(app.done_week_start, current_week_start);
+        assert!(drafted);
+        assert!(app.should_quit);
+        assert!(app.input_buffer.is_empty());
+        assert!(app.tasks.is_empty());
+        assert!(!draft::path(&tasks_dir).exists());
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn left_bracket_shows_previous_done_week() {
+    fn ctrl_q_keeps_input_until_quit_is_confirmed() {
         // GIVEN
-        let tasks_dir = temporary_tasks_dir();
-        let current_week_start = Task::week_start(Local::now().date_naive());
-        let previous_week_start = current_week_start.checked_sub_days(Days::new(7)).unwrap();
-        let mut task = Task::new_in("previous done".to_string(), tasks_dir.clone());
-        task.status = TaskStatus::Done;
-        task.completed_at = previous_week_start.and_hms_opt(12, 0, 0);
-        task.save().unwrap();
-        let mut app = App::with_tasks_dir(tasks_dir.clone());
-        app.handle_key_event(KeyCode::Char('d'));
+        let mut app = create_app(Vec::new(), None);
+        app.config.confirm_on_quit = true;
+        app.input_mode = Mode::Editing;
+        app.input_buffer = "keep".to_string();
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('['));
+        app.handle_key(KeyCode::Char('q'), KeyModifiers::CONTROL);
 
         // THEN
-        let actual = app
-            .tasks
-            .iter()
-            .find(|task| task.status == TaskStatus::Done)
-            .map(|task| task.name.as_str());
-        assert_eq!(actual, Some("previous done"));
-        assert_eq!(app.done_week_start, previous_week_start);
+        assert!(!app.should_quit);
+        assert!(app.input_mode == Mode::Editing);
+        assert_eq!(app.input_buffer, "keep");
         assert_eq!(
-            app.selected_index.map(|index| app.tasks[index].status),
-            Some(TaskStatus::Done)
+            app.notification.as_deref(),
+            Some("Press Ctrl-Q again to quit")
         );
 
-        fs::remove_dir_all(tasks_dir).unwrap();
+        app.handle_key(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        assert!(app.should_quit);
+        assert!(app.input_buffer.is_empty());
     }
 
     #[test]
-    fn changing_to_empty_done_week_clears_task_selection() {
+    fn confirm_on_quit_expires_after_timeout() {
+        // GIVEN
+        let mut app = create_app(Vec::new(), None);
+        app.config.confirm_on_quit = true;
+        app.quit_armed = Some(Instant::now() - QUIT_CONFIRM_TIMEOUT - Duration::from_millis(1));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('q'));
+
+        // THEN
+        assert!(!app.should_quit);
+        assert!(app.quit_armed.is_some());
+    }
+
+    #[test]
+    fn search_matches_done_tasks_without_toggling_done_first() {
         // GIVEN
         let tasks_dir = temporary_tasks_dir();
-        let mut todo = Task::new_in("visible todo".to_string(), tasks_dir.clone());
+        let mut old_done = Task::new_with_status(
+            "file taxes".to_string(),
+            TaskStatus::Done,
+            tasks_dir.clone(),
+        );
+        old_done.completed_at = NaiveDate::from_ymd_opt(2020, 4, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0);
+        old_done.save().unwrap();
+        let todo = Task::new_in("water plants".to_string(), tasks_dir.clone());
         todo.save().unwrap();
-        todo.status = TaskStatus::Parking;
         let mut app = App::with_tasks_dir(tasks_dir.clone());
-        app.handle_key_event(KeyCode::Char('d'));
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('['));
+        app.handle_key_event(KeyCode::Char('/'));
+        for character in "taxes".chars() {
+            app.handle_key_event(KeyCode::Char(character));
+        }
+        let matched = app
+            .selected_index
+            .map(|index| app.tasks[index].name.clone());
+        app.handle_key_event(KeyCode::Esc);
 
         // THEN
-        assert_eq!(app.selected_index, None);
+        assert_eq!(matched.as_deref(), Some("file taxes"));
+        assert!(!app.done_loaded);
+        assert!(!app.done_loaded_for_search);
+        assert!(app.tasks.iter().all(|task| task.status != TaskStatus::Done));
+        assert_eq!(
+            app.selected_index
+                .map(|index| app.tasks[index].name.as_str()),
+            Some("water plants")
+        );
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn right_bracket_does_not_move_beyond_current_week() {
-        // GIVEN
+    fn search_from_done_week_matches_other_weeks_and_restores_the_week() {
+        // GIVEN: the current DONE week is shown and an older DONE task exists
         let tasks_dir = temporary_tasks_dir();
-        fs::create_dir_all(&tasks_dir).unwrap();
+        let current_week_start = Task::week_start(Local::now().date_naive());
+        for (name, date) in [
+            ("this week", current_week_start),
+            ("file taxes", NaiveDate::from_ymd_opt(2020, 4, 1).unwrap()),
+        ] {
+            let mut task =
+                Task::new_with_status(name.to_string(), TaskStatus::Done, tasks_dir.clone());
+            task.completed_at = date.and_hms_opt(12, 0, 0);
+            task.save().unwrap();
+        }
         let mut app = App::with_tasks_dir(tasks_dir.clone());
         app.handle_key_event(KeyCode::Char('d'));
-        let expected = app.done_week_start;
+        let done_names = |app: &App| {
+            app.tasks
+                .iter()
+                .filter(|task| task.status == TaskStatus::Done)
+                .map(|task| task.name.clone())
+                .collect::<Vec<_>>()
+        };
 
         // WHEN
-        app.handle_key_event(KeyCode::Char(']'));
+        app.handle_key_event(KeyCode::Char('/'));
+        for character in "taxes".chars() {
+            app.handle_key_event(KeyCode::Char(character));
+        }
+        let matched = app
+            .selected_index
+            .map(|index| app.tasks[index].name.clone());
+        app.handle_key_event(KeyCode::Esc);
 
         // THEN
-        assert_eq!(app.done_week_start, expected);
+        assert_eq!(matched.as_deref(), Some("file taxes"));
+        assert!(app.done_loaded);
+        assert!(!app.done_loaded_for_search);
+        assert_eq!(app.done_week_start, current_week_start);
+        assert_eq!(done_names(&app), ["this week"]);
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn completing_task_during_past_week_review_keeps_it_out_of_done_column() {
+    fn search_hides_non_matching_tasks_from_navigation() {
         // GIVEN
-        let tasks_dir = temporary_tasks_dir();
-        let mut target = Task::new_in("current completion".to_string(), tasks_dir.clone());
-        target.save().unwrap();
-        target.update_status(TaskStatus::Doing).unwrap();
-        let neighbor = Task::new_in("visible neighbor".to_string(), tasks_dir.clone());
-        neighbor.save().unwrap();
-        let mut app = App::with_tasks_dir(tasks_dir.clone());
-        app.done_loaded = true;
-        app.done_week_start = Task::week_start(Local::now().date_naive())
-            .checked_sub_days(Days::new(7))
-            .unwrap();
-        app.selected_index = app
+        let tasks = vec![
+            create_task("write report", TaskStatus::Todo),
+            create_task("buy milk", TaskStatus::Todo),
+            create_task("review report", TaskStatus::Todo),
+        ];
+        let mut app = create_app(tasks, Some(1));
+        let expected = app
             .tasks
             .iter()
-            .position(|task| task.name == "current completion");
+            .position(|task| task.name == "review report");
 
         // WHEN
-        app.handle_key_event(KeyCode::Char('n'));
+        app.handle_key_event(KeyCode::Char('/'));
+        for character in "REPORT".chars() {
+            app.handle_key_event(KeyCode::Char(character));
+        }
+        app.handle_key_event(KeyCode::Enter);
+        app.handle_key_event(KeyCode::Char('j'));
 
         // THEN
-        assert!(
+        assert!(app.input_mode == Mode::Normal);
+        assert_eq!(app.selected_index, expected);
+
+        app.handle_key_event(KeyCode::Esc);
+        assert!(!app.should_quit);
+        assert!(app.search_query.is_empty());
+    }
+
+    #[test]
+    fn sequential_ids_increase_and_skip_past_existing_ids() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut existing = Task::new_in("hand edited".to_string(), tasks_dir.clone());
+        existing.seq = Some(7);
+        existing.save().unwrap();
+        let mut config = Config::with_tasks_dir(tasks_dir.clone());
+        config.sequential_ids = true;
+        let mut app = App::with_config(config);
+
+        // WHEN
+        for name in ["first", "second"] {
+            app.handle_key_event(KeyCode::Char('a'));
+            for character in name.chars() {
+                app.handle_key_event(KeyCode::Char(character));
+            }
+            app.handle_key_event(KeyCode::Enter);
+        }
+
+        // THEN
+        let seq_of = |name: &str| {
             app.tasks
                 .iter()
-                .all(|task| task.name != "current completion")
-        );
-        let selected_name = app
-            .selected_index
-            .and_then(|index| app.tasks.get(index))
-            .map(|task| task.name.as_str());
-        assert_eq!(selected_name, Some("visible neighbor"));
-        let done_path = tasks_dir.join("done").join(format!("{}.md", target.id));
-        assert!(done_path.exists());
+                .find(|task| task.name == name)
+                .and_then(|task| task.seq)
+        };
+        assert_eq!(seq_of("first"), Some(8));
+        assert_eq!(seq_of("second"), Some(9));
+        assert_eq!(State::load(&tasks_dir).next_seq, 10);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn sequential_ids_skip_past_hidden_done_tasks() {
+        // GIVEN: a DONE task with a high id that is not loaded on the board
+        let tasks_dir = temporary_tasks_dir();
+        let mut done = Task::new_in("finished".to_string(), tasks_dir.clone());
+        done.seq = Some(12);
+        done.status = TaskStatus::Done;
+        done.completed_at = Some(Local::now().naive_local());
+        done.save().unwrap();
+        let mut config = Config::with_tasks_dir(tasks_dir.clone());
+        config.sequential_ids = true;
+        let mut app = App::with_config(config);
+        assert!(app.tasks.iter().all(|task| task.name != "finished"));
+
+        // WHEN
+        app.handle_key_event(KeyCode::Char('a'));
+        app.handle_key_event(KeyCode::Char('x'));
+        app.handle_key_event(KeyCode::Enter);
+
+        // THEN
+        let added = app.tasks.iter().find(|task| task.name == "x").unwrap();
+        assert_eq!(added.seq, Some(13));
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
@@ -1219,17 +5976,63 @@ mod tests {
             input_mode: Mode::Normal,
             input_buffer: String::new(),
             input_cursor: 0,
+            add_status: TaskStatus::Todo,
+            add_after: None,
             tasks: vec![task],
             selected_index: Some(0),
             parking_loaded: true,
             done_loaded: false,
             done_week_start: Task::week_start(Local::now().date_naive()),
+            done_loaded_for_search: false,
+            done_week_before_search: false,
             last_updated_at: Local::now().naive_local(),
-            open_file: None,
+            open_files: Vec::new(),
+            edit_config: false,
+            clipboard: None,
             error_message: None,
-            tasks_dir,
+            notification: None,
+            pending_confirm: None,
+            confirm_input: String::new(),
+            show_ids: false,
+            display_utc: false,
+            expanded_panel: None,
+            layout_mode: LayoutMode::Columns,
+            use_color: true,
+            search_query: String::new(),
+            help_page: 0,
+            show_help: false,
+            help_scroll: 0,
+            stats: None,
+            first_run: false,
+            show_calendar: false,
+            body_buffer: String::new(),
+            body_cursor: 0,
+            form_fields: Default::default(),
+            form_focus: 0,
+            store: TaskStore::new(tasks_dir),
             persistent_error: None,
+            read_only: false,
             pending_g_at: None,
+            pending_count: None,
+            cut_task: None,
+            priority_filter: None,
+            active_tag_filter: None,
+            created_range: (None, None),
+            marked: Vec::new(),
+            jump_list: Vec::new(),
+            jump_cursor: 0,
+            last_action: None,
+            config: Config::with_tasks_dir(Task::default_base_dir()),
+            quit_armed: None,
+            pending_confirm_at: None,
+            saved_draft: String::new(),
+            draft_saved_at: None,
+            help_page_shown_at: Instant::now(),
+            reminders_checked_at: Local::now().naive_local(),
+            log: None,
+            pre_edit: None,
+            watched_file: None,
+            selected_changed_on_disk: false,
         };
 
         // WHEN
@@ -0,0 +1,759 @@
+use crate::task::{DEADLINE_DATE_FORMAT, Task, TaskStatus};
+use chrono::NaiveDate;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Keybinding reference shared by `--help` and the `?` overlay in the TUI.
+pub const KEYBINDINGS: &str = "\
+  a          Add a new task (Tab while typing: choose its status)
+  O          Add a task right below the selected one, in its status
+  j / k      Move down / up within a status
+  G / gg     Move to the bottom / top within a status
+  3j / 5G    A count repeats j / k, or picks that row for G / gg
+  Ctrl-O     Go back to the previously selected task (Ctrl-N: forward)
+  L          Select the first task whose UUID appears in the body
+  h / l      Move left / right between statuses
+  J / K      Reorder the task down / up within its status
+  x / p      Cut the task, then paste it after the selected one (any status)
+  Space / t  Mark tasks, then tag them all at once (-tag removes the tag)
+  s / S      Sort the current status by name / deadline (saved as its order)
+  Tab        Jump to the first task of the next status (Shift-Tab: previous)
+  n / N      Move the task to the next / previous status
+  r          Reload tasks from the filesystem
+  d          Toggle this week's DONE tasks
+  [ / ]      Show the previous / next DONE week
+  /          Filter tasks by name (Esc clears the filter)
+  !          Jump to the next overdue task
+  f          Cycle the priority filter: all, medium and high, high only
+  #          Filter by the next tag in the tag bar; after the last, show all
+  F          Filter by creation date (2024-01-01..2024-01-07; ends optional)
+  D          Mark the selected task DONE and archive it
+  .          Repeat the last n, N, J, K, D, or task addition
+  A          Archive all DONE tasks
+  P          Permanently delete all DONE tasks (type \"purge\" to confirm)
+  i          Toggle short task IDs in the list
+  I          Give the selected task a fresh UUID and rename its file
+  U          Toggle showing times in UTC instead of local time
+  z          Expand the next status column and collapse the others
+  v          Toggle the compact layout (all statuses in one list)
+  c          Toggle a calendar of this month shaded by tasks due per day
+  T          Show board statistics (counts, overdue, due soon, completed)
+  e          Edit the task body in the built-in editor (Ctrl-S saves)
+  R          Add a reminder (YYYY-MM-DD HH:MM) or remove an existing one
+  Z          Defer to tomorrow: deadline tomorrow, reminder at 09:00
+  M          Edit the name and deadline in a form (Tab: next field)
+  Enter      Open the task file in neovim
+  o          Start a TODO task (move it to DOING) and open it in neovim
+  W          Open every DOING task in neovim tabs
+  C          Edit the config file in $VISUAL / $EDITOR, then reload it
+  m          Email the task (name as subject, body as message) via mailto
+  y          Copy the task file's absolute path to the clipboard
+  ?          Show all keybindings (any key closes)
+  q / Esc    Quit
+  Ctrl-Q     Quit from any mode, discarding unsaved input
+";
+
+/// Statuses exported by `rem export-md`, one section each.
+const EXPORT_STATUSES: [TaskStatus; 3] = [TaskStatus::Todo, TaskStatus::Doing, TaskStatus::Done];
+
+/// Statuses counted by `rem count` when no status is given.
+const DEFAULT_COUNT_STATUSES: [TaskStatus; 2] = [TaskStatus::Todo, TaskStatus::Doing];
+
+/// Statuses printed by `rem list` when `--status` is not given.
+const DEFAULT_LIST_STATUSES: [TaskStatus; 3] =
+    [TaskStatus::Parking, TaskStatus::Todo, TaskStatus::Doing];
+
+/// What the `rem` binary should do for the given command-line arguments.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    Tui,
+    Version,
+    Help,
+    /// Prints tasks in the given statuses, in lifecycle order without duplicates,
+    /// optionally only those created within an inclusive date range.
+    List {
+        statuses: Vec<TaskStatus>,
+        since: Option<NaiveDate>,
+        until: Option<NaiveDate>,
+    },
+    /// Prints the number of tasks in the given statuses.
+    Count(Vec<TaskStatus>),
+    /// Opens the config file in neovim, creating it from a template first if needed.
+    Config,
+    /// Prints TODO, DOING, and DONE tasks as a Markdown document, or writes it to `output`.
+    ExportMd {
+        output: Option<PathBuf>,
+    },
+    /// Checks the task directory for broken files; with `fix`, renames files whose name
+    /// does not match their frontmatter id.
+    Doctor {
+        fix: bool,
+    },
+    /// Creates a TODO task without starting the TUI.
+    Add {
+        name: String,
+        body: Option<BodySource>,
+        /// `--skip-duplicates`: do nothing if a task with the same name already exists.
+        skip_duplicates: bool,
+    },
+}
+
+/// Where `rem add --body` reads the task body from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BodySource {
+    /// `--body -`: standard input.
+    Stdin,
+    File(PathBuf),
+}
+
+/// Removes `--dir <PATH>` (or `--dir=<PATH>`) from the arguments.
+///
+/// The option may appear anywhere so that it works with the TUI and every subcommand.
+/// Returns the overriding task storage directory, if any, and the remaining arguments.
+pub fn take_dir_option(args: &[String]) -> Result<(Option<PathBuf>, Vec<String>), String> {
+    let mut dir = None;
+    let mut rest = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if let Some(value) = arg.strip_prefix("--dir=") {
+            dir = Some(PathBuf::from(value));
+        } else if arg == "--dir" {
+            let value = args.next().ok_or("missing value for --dir")?;
+            dir = Some(PathBuf::from(value));
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    Ok((dir, rest))
+}
+
+/// Removes every occurrence of a boolean flag such as `--verbose` from the arguments.
+///
+/// Returns whether the flag was present and the remaining arguments.
+pub fn take_flag(args: &[String], flag: &str) -> (bool, Vec<String>) {
+    let rest = args
+        .iter()
+        .filter(|arg| *arg != flag)
+        .cloned()
+        .collect::<Vec<_>>();
+    (rest.len() != args.len(), rest)
+}
+
+/// Parses the command-line arguments (excluding the program name).
+///
+/// Returns an error message for unknown flags or arguments.
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    let Some(first) = args.first() else {
+        return Ok(Command::Tui);
+    };
+    let command = match first.as_str() {
+        "list" => return parse_list(&args[1..]),
+        "add" => return parse_add(&args[1..]),
+        "count" => {
+            return match &args[1..] {
+                [] => Ok(Command::Count(DEFAULT_COUNT_STATUSES.to_vec())),
+                [statuses] => parse_statuses(statuses).map(Command::Count),
+                [_, extra, ..] => Err(format!("unexpected argument: {extra}")),
+            };
+        }
+        "config" => Command::Config,
+        "export-md" => return parse_export(&args[1..]).map(|output| Command::ExportMd { output }),
+        "doctor" => {
+            return match &args[1..] {
+                [] => Ok(Command::Doctor { fix: false }),
+                [flag] if flag == "--fix" => Ok(Command::Doctor { fix: true }),
+                [extra, ..] => Err(format!("unexpected argument: {extra}")),
+            };
+        }
+        "--version" | "-V" => Command::Version,
+        "--help" | "-h" => Command::Help,
+        unknown => return Err(format!("unknown argument: {unknown}")),
+    };
+    if let Some(extra) = args.get(1) {
+        return Err(format!("unexpected argument: {extra}"));
+    }
+    Ok(command)
+}
+
+fn parse_list(args: &[String]) -> Result<Command, String> {
+    let mut statuses = DEFAULT_LIST_STATUSES.to_vec();
+    let mut since = None;
+    let mut until = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (option, inline_value) = match arg.split_once('=') {
+            Some((option, value)) => (option, Some(value)),
+            None => (arg.as_str(), None),
+        };
+        if !matches!(option, "--status" | "-s" | "--since" | "--until") {
+            return Err(format!("unexpected argument: {arg}"));
+        }
+        let value = match inline_value {
+            Some(value) => value,
+            None => args
+                .next()
+                .ok_or_else(|| format!("missing value for {option}"))?,
+        };
+        match option {
+            "--since" => since = Some(parse_date(value)?),
+            "--until" => until = Some(parse_date(value)?),
+            _ => statuses = parse_statuses(value)?,
+        }
+    }
+    Ok(Command::List {
+        statuses,
+        since,
+        until,
+    })
+}
+
+/// Parses the options of an export subcommand: `--output <PATH>` (or `-o`, or `--output=<PATH>`).
+fn parse_export(args: &[String]) -> Result<Option<PathBuf>, String> {
+    let mut output = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = match arg.strip_prefix("--output=") {
+            Some(value) => value,
+            None if arg == "--output" || arg == "-o" => args
+                .next()
+                .ok_or_else(|| format!("missing value for {arg}"))?,
+            None => return Err(format!("unexpected argument: {arg}")),
+        };
+        output = Some(PathBuf::from(value));
+    }
+    Ok(output)
+}
+
+/// Parses a date written as `2024-01-31` or in the deadline format `2024/01/31`.
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, DEADLINE_DATE_FORMAT))
+        .map_err(|_| format!("invalid date: {value}"))
+}
+
+/// Parses the creation date range typed after `F` in the TUI: `<since>..<until>`, where
+/// either side may be left out, or a single date for that day only. Both bounds are inclusive.
+pub fn parse_date_range(value: &str) -> Result<(Option<NaiveDate>, Option<NaiveDate>), String> {
+    let parse_bound = |bound: &str| {
+        let bound = bound.trim();
+        (!bound.is_empty()).then(|| parse_date(bound)).transpose()
+    };
+    match value.split_once("..") {
+        Some((since, until)) => Ok((parse_bound(since)?, parse_bound(until)?)),
+        None => {
+            let day = parse_bound(value)?;
+            Ok((day, day))
+        }
+    }
+}
+
+fn parse_add(args: &[String]) -> Result<Command, String> {
+    let mut name = None;
+    let mut body = None;
+    let mut skip_duplicates = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--skip-duplicates" {
+            skip_duplicates = true;
+            continue;
+        }
+        let value = match arg.strip_prefix("--body=") {
+            Some(value) => value,
+            None if arg == "--body" => args.next().ok_or("missing value for --body")?,
+            None if name.is_none() && !arg.starts_with("--") => {
+                name = Some(arg.clone());
+                continue;
+            }
+            None => return Err(format!("unexpected argument: {arg}")),
+        };
+        body = Some(if value == "-" {
+            BodySource::Stdin
+        } else {
+            BodySource::File(PathBuf::from(value))
+        });
+    }
+    let name = name
+        .filter(|name| !name.trim().is_empty())
+        .ok_or("missing task name")?;
+    Ok(Command::Add {
+        name,
+        body,
+        skip_duplicates,
+    })
+}
+
+/// Parses a comma-separated status list such as `todo,doing`.
+fn parse_statuses(value: &str) -> Result<Vec<TaskStatus>, String> {
+    let requested = value
+        .split(',')
+        .map(|name| TaskStatus::from_name(name.trim()).ok_or(format!("unknown status: {name}")))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(TaskStatus::ALL
+        .into_iter()
+        .filter(|status| requested.contains(status))
+        .collect())
+}
+
+/// Formats a task as one tab-separated `rem list` line: status, deadline, and name.
+pub fn list_line(task: &Task) -> String {
+    format!(
+        "{}\t{}\t{}",
+        task.status.label(),
+        task.deadline.format(DEADLINE_DATE_FORMAT),
+        task.name.replace('\n', " ")
+    )
+}
+
+/// Formats tasks as a Markdown document with a `## TODO`, `## DOING`, and `## DONE` section.
+///
+/// Each task is a bullet with its deadline. Tasks are sorted like the board, so exporting an
+/// unchanged board twice gives identical output.
+pub fn markdown_export(tasks: Vec<Task>) -> String {
+    let tasks = Task::sort(tasks);
+    EXPORT_STATUSES
+        .iter()
+        .map(|status| {
+            let items = tasks
+                .iter()
+                .filter(|task| task.status == *status)
+                .map(|task| {
+                    format!(
+                        "- {} (due {})\n",
+                        task.name.replace('\n', " "),
+                        task.deadline.format(DEADLINE_DATE_FORMAT)
+                    )
+                })
+                .collect::<String>();
+            let items = if items.is_empty() {
+                "_No tasks_\n".to_string()
+            } else {
+                items
+            };
+            format!("## {}\n\n{items}", status.label())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Writes a finished export to `path`, creating its parent directories.
+///
+/// The content goes to `<path>.tmp` first and is renamed over `path`, so a failed write never
+/// leaves a truncated export behind.
+pub fn write_output(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    let mut temporary_name = path.file_name().unwrap_or_default().to_os_string();
+    temporary_name.push(".tmp");
+    let temporary_path = path.with_file_name(temporary_name);
+    let result =
+        fs::write(&temporary_path, content).and_then(|()| fs::rename(&temporary_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temporary_path);
+    }
+    result
+}
+
+/// Returns the usage summary printed by `--help`.
+pub fn help_text() -> String {
+    format!(
+        "\
+rem {version} - Remember everything locally
+
+Usage: rem [OPTIONS]
+       rem [--dir <PATH>] list [--status <STATUS>[,<STATUS>...]] [--since <DATE>] [--until <DATE>]
+       rem [--dir <PATH>] count [<STATUS>[,<STATUS>...]]
+       rem [--dir <PATH>] add <NAME> [--body <-|FILE>] [--skip-duplicates]
+       rem [--dir <PATH>] export-md [--output <PATH>]
+       rem [--dir <PATH>] doctor [--fix]
+       rem config
+
+Running `rem` without arguments starts the TUI.
+
+Commands:
+  list       Print tasks as tab-separated lines (status, deadline, name).
+             --status takes parking, todo, doing, and done
+             (default: parking,todo,doing)
+             --since / --until keep tasks created on or after / on or
+             before DATE (YYYY-MM-DD); either bound may be omitted
+  count      Print the number of tasks in the given statuses
+             (default: todo,doing)
+  add        Create a TODO task. --body reads its markdown body from
+             FILE, or from standard input when FILE is -.
+             --skip-duplicates creates nothing if a task with the same
+             name exists in any status and reports created / skipped
+  export-md  Print TODO, DOING, and DONE tasks as a Markdown document
+             with one section per status. --output (-o) writes it to
+             PATH instead, creating parent directories; the file is only
+             replaced once the whole document is written
+  doctor     Report unparseable task files, file names that do not
+             match their id, duplicate ids, files in a status directory
+             their history disagrees with, and leftover temporary files.
+             Changes nothing unless --fix is given, which renames files
+             to match their id. Exits with 1 when problems remain
+  config     Open ~/.rem-cli/config.yaml in $VISUAL or $EDITOR (neovim
+             by default), creating it with every setting documented if
+             it does not exist
+
+Options:
+  --dir <PATH>   Use PATH as the task directory instead of the configured one
+  --verbose      Append key actions and errors to ~/.rem-cli/rem.log
+  --no-persist   Keep no session state (view preferences, draft); also
+                 REM_CLI_EPHEMERAL=1
+  -h, --help     Print this help and exit
+  -V, --version  Print the version and exit
+
+Keybindings:
+{keybindings}",
+        version = env!("CARGO_PKG_VERSION"),
+        keybindings = KEYBINDINGS
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn no_arguments_start_the_tui() {
+        // GIVEN
+        let arguments = args(&[]);
+
+        // WHEN
+        let actual = parse(&arguments);
+
+        // THEN
+        assert_eq!(actual, Ok(Command::Tui));
+    }
+
+    #[test]
+    fn short_and_long_flags_are_recognized() {
+        // GIVEN
+        let cases = [
+            ("--version", Command::Version),
+            ("-V", Command::Version),
+            ("--help", Command::Help),
+            ("-h", Command::Help),
+            ("config", Command::Config),
+            ("export-md", Command::ExportMd { output: None }),
+            ("doctor", Command::Doctor { fix: false }),
+        ];
+
+        // WHEN
+        let actual = cases.clone().map(|(flag, _)| parse(&args(&[flag])));
+
+        // THEN
+        let expected = cases.map(|(_, command)| Ok(command));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unknown_flag_returns_error() {
+        // GIVEN
+        let arguments = args(&["--frobnicate"]);
+
+        // WHEN
+        let actual = parse(&arguments);
+
+        // THEN
+        assert_eq!(actual, Err("unknown argument: --frobnicate".to_string()));
+    }
+
+    #[test]
+    fn list_status_flag_is_deduplicated_in_lifecycle_order() {
+        // GIVEN
+        let arguments = args(&["list", "--status", "doing,todo,DOING"]);
+
+        // WHEN
+        let actual = parse(&arguments);
+
+        // THEN
+        assert_eq!(
+            actual,
+            Ok(Command::List {
+                statuses: vec![TaskStatus::Todo, TaskStatus::Doing],
+                since: None,
+                until: None,
+            })
+        );
+    }
+
+    #[test]
+    fn list_without_status_uses_active_statuses() {
+        // GIVEN
+        let arguments = args(&["list"]);
+
+        // WHEN
+        let actual = parse(&arguments);
+
+        // THEN
+        assert_eq!(
+            actual,
+            Ok(Command::List {
+                statuses: DEFAULT_LIST_STATUSES.to_vec(),
+                since: None,
+                until: None,
+            })
+        );
+    }
+
+    #[test]
+    fn list_since_and_until_accept_dashed_and_slashed_dates() {
+        // GIVEN
+        let cases = [
+            args(&["list", "--since", "2024-01-01", "--until=2024/01/07"]),
+            args(&["list", "--until", "2024-01-07"]),
+            args(&["list", "--since", "last week"]),
+        ];
+
+        // WHEN
+        let actual = cases.map(|arguments| parse(&arguments));
+
+        // THEN
+        let expected = [
+            Ok(Command::List {
+                statuses: DEFAULT_LIST_STATUSES.to_vec(),
+                since: NaiveDate::from_ymd_opt(2024, 1, 1),
+                until: NaiveDate::from_ymd_opt(2024, 1, 7),
+            }),
+            Ok(Command::List {
+                statuses: DEFAULT_LIST_STATUSES.to_vec(),
+                since: None,
+                until: NaiveDate::from_ymd_opt(2024, 1, 7),
+            }),
+            Err("invalid date: last week".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn date_range_accepts_open_ends_and_single_days() {
+        // GIVEN
+        let cases = [
+            "2024-01-01..2024/01/07",
+            "2024-01-01..",
+            "..2024-01-07",
+            "2024-01-03",
+            "",
+            "2024-01-01..soon",
+        ];
+
+        // WHEN
+        let actual = cases.map(parse_date_range);
+
+        // THEN
+        let date = |day| NaiveDate::from_ymd_opt(2024, 1, day);
+        let expected = [
+            Ok((date(1), date(7))),
+            Ok((date(1), None)),
+            Ok((None, date(7))),
+            Ok((date(3), date(3))),
+            Ok((None, None)),
+            Err("invalid date: soon".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn list_with_unknown_status_returns_error() {
+        // GIVEN
+        let arguments = args(&["list", "--status=todo,later"]);
+
+        // WHEN
+        let actual = parse(&arguments);
+
+        // THEN
+        assert_eq!(actual, Err("unknown status: later".to_string()));
+    }
+
+    #[test]
+    fn dir_option_is_removed_from_any_position() {
+        // GIVEN
+        let cases = [
+            args(&["--dir", "/tmp/board", "list"]),
+            args(&["list", "--dir=/tmp/board"]),
+        ];
+
+        // WHEN
+        let actual = cases.map(|arguments| take_dir_option(&arguments));
+
+        // THEN
+        let expected = Ok((Some(PathBuf::from("/tmp/board")), args(&["list"])));
+        assert_eq!(actual, [expected.clone(), expected]);
+    }
+
+    #[test]
+    fn verbose_flag_is_removed_from_any_position() {
+        // GIVEN
+        let cases = [args(&["--verbose", "list"]), args(&["list"])];
+
+        // WHEN
+        let actual = cases.map(|arguments| take_flag(&arguments, "--verbose"));
+
+        // THEN
+        assert_eq!(actual, [(true, args(&["list"])), (false, args(&["list"]))]);
+    }
+
+    #[test]
+    fn dir_option_without_value_returns_error() {
+        // GIVEN
+        let arguments = args(&["--dir"]);
+
+        // WHEN
+        let actual = take_dir_option(&arguments);
+
+        // THEN
+        assert_eq!(actual, Err("missing value for --dir".to_string()));
+    }
+
+    #[test]
+    fn add_takes_name_and_optional_body_source() {
+        // GIVEN
+        let cases = [
+            args(&["add", "buy milk"]),
+            args(&["add", "--body", "-", "capture"]),
+            args(&["add", "notes", "--body=notes.md"]),
+            args(&["add", "--skip-duplicates", "again"]),
+            args(&["add"]),
+            args(&["add", "one", "two"]),
+        ];
+
+        // WHEN
+        let actual = cases.map(|arguments| parse(&arguments));
+
+        // THEN
+        let expected = [
+            Ok(Command::Add {
+                name: "buy milk".to_string(),
+                body: None,
+                skip_duplicates: false,
+            }),
+            Ok(Command::Add {
+                name: "capture".to_string(),
+                body: Some(BodySource::Stdin),
+                skip_duplicates: false,
+            }),
+            Ok(Command::Add {
+                name: "notes".to_string(),
+                body: Some(BodySource::File(PathBuf::from("notes.md"))),
+                skip_duplicates: false,
+            }),
+            Ok(Command::Add {
+                name: "again".to_string(),
+                body: None,
+                skip_duplicates: true,
+            }),
+            Err("missing task name".to_string()),
+            Err("unexpected argument: two".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn doctor_accepts_only_the_fix_flag() {
+        // GIVEN
+        let cases = [args(&["doctor", "--fix"]), args(&["doctor", "--force"])];
+
+        // WHEN
+        let actual = cases.map(|arguments| parse(&arguments));
+
+        // THEN
+        let expected = [
+            Ok(Command::Doctor { fix: true }),
+            Err("unexpected argument: --force".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn export_md_accepts_an_output_path() {
+        // GIVEN
+        let cases = [
+            args(&["export-md", "--output", "out/status.md"]),
+            args(&["export-md", "-o=status.md"]),
+            args(&["export-md", "--output=status.md"]),
+            args(&["export-md", "--output"]),
+        ];
+
+        // WHEN
+        let actual = cases.map(|arguments| parse(&arguments));
+
+        // THEN
+        let expected = [
+            Ok(Command::ExportMd {
+                output: Some(PathBuf::from("out/status.md")),
+            }),
+            Err("unexpected argument: -o=status.md".to_string()),
+            Ok(Command::ExportMd {
+                output: Some(PathBuf::from("status.md")),
+            }),
+            Err("missing value for --output".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn count_defaults_to_todo_and_doing_and_accepts_statuses() {
+        // GIVEN
+        let cases = [args(&["count"]), args(&["count", "done,parking"])];
+
+        // WHEN
+        let actual = cases.map(|arguments| parse(&arguments));
+
+        // THEN
+        let expected = [
+            Ok(Command::Count(vec![TaskStatus::Todo, TaskStatus::Doing])),
+            Ok(Command::Count(vec![TaskStatus::Parking, TaskStatus::Done])),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn markdown_export_lists_tasks_under_their_status_sections() {
+        // GIVEN
+        let base_dir = PathBuf::from("/tmp/rem-cli-export");
+        let task =
+            |name: &str, status| Task::new_with_status(name.to_string(), status, base_dir.clone());
+        let tasks = vec![
+            task("ship it", TaskStatus::Done),
+            task("write docs", TaskStatus::Doing),
+            task("someday", TaskStatus::Parking),
+            task("review PR", TaskStatus::Todo),
+        ];
+        let due = tasks[0].deadline.format(DEADLINE_DATE_FORMAT);
+
+        // WHEN
+        let actual = markdown_export(tasks);
+
+        // THEN
+        let expected = format!(
+            "## TODO\n\n- review PR (due {due})\n\n\
+             ## DOING\n\n- write docs (due {due})\n\n\
+             ## DONE\n\n- ship it (due {due})\n"
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn markdown_export_marks_empty_sections() {
+        // GIVEN
+        let tasks = Vec::new();
+
+        // WHEN
+        let actual = markdown_export(tasks);
+
+        // THEN
+        assert_eq!(
+            actual,
+            "## TODO\n\n_No tasks_\n\n## DOING\n\n_No tasks_\n\n## DONE\n\n_No tasks_\n"
+        );
+    }
+}
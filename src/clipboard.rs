@@ -0,0 +1,78 @@
+//! Copies text to the system clipboard through the terminal.
+//!
+//! The OSC 52 escape sequence asks the terminal emulator to set the clipboard, so copying
+//! works without a clipboard tool installed and also over SSH.
+
+use std::io::{self, Write};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Writes the OSC 52 sequence that sets the clipboard to `text`.
+pub fn copy(out: &mut impl Write, text: &str) -> io::Result<()> {
+    out.write_all(osc52_sequence(text).as_bytes())?;
+    out.flush()
+}
+
+/// Builds the OSC 52 sequence for the system clipboard with `text` encoded as base64.
+fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+/// Encodes bytes as standard base64 with padding.
+fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (index, byte)| {
+            group | u32::from(*byte) << (16 - 8 * index)
+        });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (group >> (18 - 6 * index)) & 0x3f;
+                encoded.push(BASE64_ALPHABET[sextet as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_pads_partial_groups() {
+        // GIVEN
+        let inputs = ["", "f", "fo", "foo", "foob", "/tmp/タスク.md"];
+
+        // WHEN
+        let actual = inputs.map(|input| base64(input.as_bytes()));
+
+        // THEN
+        assert_eq!(
+            actual,
+            [
+                "",
+                "Zg==",
+                "Zm8=",
+                "Zm9v",
+                "Zm9vYg==",
+                "L3RtcC/jgr/jgrnjgq8ubWQ="
+            ]
+        );
+    }
+
+    #[test]
+    fn copy_writes_osc52_sequence() {
+        // GIVEN
+        let mut out = Vec::new();
+
+        // WHEN
+        copy(&mut out, "foo").unwrap();
+
+        // THEN
+        assert_eq!(out, b"\x1b]52;c;Zm9v\x07");
+    }
+}
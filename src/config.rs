@@ -1,24 +1,331 @@
+use crate::frontmatter::FrontmatterFormat;
+use crate::task::TaskStatus;
 use serde::Deserialize;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 
-#[derive(Deserialize)]
-struct Config {
-    tasks_dir: PathBuf,
+/// User settings read from `~/.rem-cli/config.yaml`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Config {
+    pub tasks_dir: PathBuf,
+    /// Requires pressing `q` twice before quitting.
+    #[serde(default)]
+    pub confirm_on_quit: bool,
+    /// Assigns short sequential ids (`#1`, `#2`, ...) to new tasks in addition to the UUID.
+    #[serde(default)]
+    pub sequential_ids: bool,
+    /// Asks before `N` moves a DONE task back.
+    #[serde(default)]
+    pub confirm_uncomplete: bool,
+    /// Largest task file, in bytes, that the built-in body editor (`e`) opens.
+    #[serde(default = "default_body_editor_max_bytes")]
+    pub body_editor_max_bytes: u64,
+    /// How tasks in the DONE column are de-emphasized.
+    #[serde(default)]
+    pub done_style: DoneStyle,
+    /// Appends key actions and errors to `~/.rem-cli/rem.log`; also enabled by `--verbose`.
+    #[serde(default)]
+    pub debug_log: bool,
+    /// How task names longer than their column are fitted.
+    #[serde(default)]
+    pub wrap_mode: WrapMode,
+    /// Where each task's deadline is shown in the list.
+    #[serde(default)]
+    pub deadline_style: DeadlineStyle,
+    /// Statuses that `n` / `N` step through, in order.
+    #[serde(default = "default_status_flow")]
+    pub status_flow: Vec<TaskStatus>,
+    /// Archives DONE tasks completed more than this many days ago when the TUI starts.
+    #[serde(default)]
+    pub auto_archive_done_days: Option<u32>,
+    /// Key that answers yes in confirmation popups.
+    #[serde(default = "default_confirm_key")]
+    pub confirm_key: char,
+    /// Seconds after which an unanswered confirmation is cancelled; 0 waits forever.
+    #[serde(default)]
+    pub confirm_timeout_secs: u64,
+    /// Frontmatter format of newly created task files.
+    #[serde(default)]
+    pub frontmatter_format: FrontmatterFormat,
+    /// What `n` does to a DONE task at the end of `status_flow`.
+    #[serde(default)]
+    pub done_forward_action: DoneForwardAction,
+    /// Leaves no session state behind: view preferences, the onboarding flag, and the input
+    /// draft are neither restored nor written. Also enabled by `--no-persist` or `REM_CLI_EPHEMERAL=1`.
+    #[serde(default)]
+    pub ephemeral: bool,
 }
 
-pub fn tasks_dir() -> io::Result<PathBuf> {
+/// Text style applied to tasks in the DONE column.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DoneStyle {
+    /// Dimmed and crossed out.
+    #[default]
+    DimStrikethrough,
+    /// Dimmed only, for terminals that render strikethrough poorly.
+    Dim,
+    /// Rendered like any other column.
+    Plain,
+}
+
+/// What moving a DONE task forward with `n` does once there is no later status.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DoneForwardAction {
+    /// Nothing happens.
+    #[default]
+    None,
+    /// The task is moved to `archive/`, like `D`.
+    Archive,
+    /// The task file is deleted.
+    Delete,
+}
+
+/// How task names wider than their column are displayed.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WrapMode {
+    /// Wrapped at any character, so long URLs and ids never overflow.
+    #[default]
+    Char,
+    /// Wrapped between words; a word wider than the column is still broken mid-word.
+    Word,
+    /// Cut to one line ending in an ellipsis.
+    Truncate,
+}
+
+/// Where each task's deadline is shown in the list.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadlineStyle {
+    /// A `Deadline: 2026/06/15` line under the name.
+    #[default]
+    Line,
+    /// A relative label (`today`, `3d`, `-2d`) right-aligned on the name's first line.
+    Inline,
+}
+
+impl Config {
+    /// Creates a config with default settings for the provided task storage directory.
+    pub fn with_tasks_dir(tasks_dir: PathBuf) -> Self {
+        Self {
+            tasks_dir,
+            confirm_on_quit: false,
+            sequential_ids: false,
+            confirm_uncomplete: false,
+            body_editor_max_bytes: default_body_editor_max_bytes(),
+            done_style: DoneStyle::default(),
+            debug_log: false,
+            wrap_mode: WrapMode::default(),
+            deadline_style: DeadlineStyle::default(),
+            status_flow: default_status_flow(),
+            auto_archive_done_days: None,
+            confirm_key: default_confirm_key(),
+            confirm_timeout_secs: 0,
+            frontmatter_format: FrontmatterFormat::default(),
+            done_forward_action: DoneForwardAction::default(),
+            ephemeral: false,
+        }
+    }
+}
+
+fn default_status_flow() -> Vec<TaskStatus> {
+    TaskStatus::ALL.to_vec()
+}
+
+fn default_confirm_key() -> char {
+    'y'
+}
+
+fn default_body_editor_max_bytes() -> u64 {
+    256 * 1024
+}
+
+/// Commented config written by `rem config` when no config file exists yet.
+///
+/// Every optional key is listed with its default so the file doubles as documentation.
+fn default_template(default_tasks_dir: &Path) -> String {
+    format!(
+        "\
+# rem configuration. Lines starting with # are comments.
+
+# Directory containing the parking/todo/doing/done task directories (required).
+tasks_dir: '{tasks_dir}'
+
+# Require pressing q twice within two seconds to quit.
+# confirm_on_quit: false
+
+# Give new tasks a short #N id (shown with i, matched by /).
+# sequential_ids: false
+
+# Ask before N moves a DONE task back.
+# confirm_uncomplete: false
+
+# Largest task file, in bytes, that the built-in body editor (e) opens.
+# body_editor_max_bytes: {body_editor_max_bytes}
+
+# How DONE tasks are de-emphasized: dim_strikethrough, dim, or plain.
+# done_style: dim_strikethrough
+
+# Append key actions and errors to ~/.rem-cli/rem.log (same as --verbose).
+# debug_log: false
+
+# How long task names are fitted to their column: char, word, or truncate.
+# wrap_mode: char
+
+# Where deadlines are shown: line (under the name) or inline (right-aligned, relative).
+# deadline_style: line
+
+# Statuses that n / N step through, in order; leave one out to skip it.
+# status_flow: [parking, todo, doing, done]
+
+# Archive DONE tasks completed more than this many days ago when rem starts (unset: never).
+# auto_archive_done_days: 30
+
+# Key that answers yes in confirmation popups.
+# confirm_key: y
+
+# Cancel an unanswered confirmation after this many seconds (0: never).
+# confirm_timeout_secs: 0
+
+# Frontmatter format of new task files: yaml (---), toml (+++), or json ({{).
+# Existing files keep theirs.
+# frontmatter_format: yaml
+
+# What n does to a DONE task: none, archive (like D), or delete (removes the file after confirming).
+# done_forward_action: none
+
+# Keep no session state (view preferences, welcome seen, typed draft) in the task directory;
+# task files are still written. Same as --no-persist or REM_CLI_EPHEMERAL=1.
+# ephemeral: false
+",
+        // Single-quoted YAML takes backslashes literally, so Windows paths survive.
+        tasks_dir = default_tasks_dir.display().to_string().replace('\'', "''"),
+        body_editor_max_bytes = default_body_editor_max_bytes(),
+    )
+}
+
+/// Environment variable that replaces the platform data directory.
+pub const DATA_DIR_ENV: &str = "REM_CLI_DIR";
+
+/// Environment variable that turns on `ephemeral` when set to `1`.
+pub const EPHEMERAL_ENV: &str = "REM_CLI_EPHEMERAL";
+
+/// Returns whether `REM_CLI_EPHEMERAL=1` asks for a session without saved state.
+pub fn ephemeral_from_env() -> bool {
+    std::env::var_os(EPHEMERAL_ENV).is_some_and(|value| value == "1")
+}
+
+/// Returns the command that edits the config file: `$VISUAL`, then `$EDITOR`, then `nvim`.
+pub fn editor_command() -> Vec<String> {
+    editor_command_from(std::env::var("VISUAL").ok(), std::env::var("EDITOR").ok())
+}
+
+/// Splits the first non-blank editor variable into a program and its arguments, so values
+/// such as `code --wait` work.
+fn editor_command_from(visual: Option<String>, editor: Option<String>) -> Vec<String> {
+    [visual, editor]
+        .into_iter()
+        .flatten()
+        .map(|value| {
+            value
+                .split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .find(|words| !words.is_empty())
+        .unwrap_or_else(|| vec!["nvim".to_string()])
+}
+
+/// Returns the directory holding task data: `$REM_CLI_DIR` when set, otherwise the platform default.
+pub fn data_dir(home_dir: &Path) -> PathBuf {
+    match std::env::var_os(DATA_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => platform_data_dir(
+            home_dir,
+            std::env::var_os("XDG_DATA_HOME").map(PathBuf::from),
+        ),
+    }
+}
+
+/// Follows the XDG base directory spec: `$XDG_DATA_HOME/rem-cli`, or `~/.local/share/rem-cli`
+/// when the variable is unset or not an absolute path.
+#[cfg(target_os = "linux")]
+fn platform_data_dir(home_dir: &Path, xdg_data_home: Option<PathBuf>) -> PathBuf {
+    xdg_data_home
+        .filter(|dir| dir.is_absolute())
+        .unwrap_or_else(|| home_dir.join(".local/share"))
+        .join("rem-cli")
+}
+
+/// Keeps `~/.rem-cli` outside Linux, where the XDG spec is not the platform convention.
+#[cfg(not(target_os = "linux"))]
+fn platform_data_dir(home_dir: &Path, _xdg_data_home: Option<PathBuf>) -> PathBuf {
+    home_dir.join(".rem-cli")
+}
+
+/// Moves `~/.rem-cli/tasks` to `tasks_dir` if only the former exists, returning the old path.
+///
+/// Does nothing once the new directory exists, so it is safe to call on every startup.
+fn migrate_legacy_tasks_dir(home_dir: &Path, tasks_dir: &Path) -> io::Result<Option<PathBuf>> {
+    let legacy_dir = home_dir.join(".rem-cli/tasks");
+    if legacy_dir == tasks_dir || !legacy_dir.is_dir() || tasks_dir.exists() {
+        return Ok(None);
+    }
+    if let Some(parent) = tasks_dir.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(&legacy_dir, tasks_dir).map_err(|error| {
+        io::Error::new(
+            error.kind(),
+            format!(
+                "failed to move {} to {}: {error}",
+                legacy_dir.display(),
+                tasks_dir.display()
+            ),
+        )
+    })?;
+    Ok(Some(legacy_dir))
+}
+
+pub fn load() -> io::Result<Config> {
     let home_dir = dirs::home_dir()
         .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "home directory not found"))?;
-    tasks_dir_from(&home_dir)
+    load_from(&home_dir, &data_dir(&home_dir))
+}
+
+/// Returns the path of the config file, creating it from the commented template if absent.
+pub fn ensure_file() -> io::Result<PathBuf> {
+    let home_dir = dirs::home_dir()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "home directory not found"))?;
+    ensure_file_in(&home_dir, &data_dir(&home_dir))
+}
+
+/// Migrates the legacy `~/.rem-cli/tasks` before writing the template, since `load_from` no
+/// longer migrates once a config file names `tasks_dir`.
+fn ensure_file_in(home_dir: &Path, data_dir: &Path) -> io::Result<PathBuf> {
+    let config_path = home_dir.join(".rem-cli/config.yaml");
+    if !config_path.exists() {
+        let default_tasks_dir = data_dir.join("tasks");
+        migrate_legacy_tasks_dir(home_dir, &default_tasks_dir)?;
+        fs::create_dir_all(home_dir.join(".rem-cli"))?;
+        fs::write(&config_path, default_template(&default_tasks_dir))?;
+    }
+    Ok(config_path)
 }
 
-fn tasks_dir_from(home_dir: &Path) -> io::Result<PathBuf> {
-    let default_tasks_dir = home_dir.join(".rem-cli/tasks");
+/// Reads the config file, falling back to `<data_dir>/tasks` when there is none.
+///
+/// Without a config file the legacy `~/.rem-cli/tasks` is migrated to the default first; a
+/// configured `tasks_dir` is never moved.
+fn load_from(home_dir: &Path, data_dir: &Path) -> io::Result<Config> {
+    let default_tasks_dir = data_dir.join("tasks");
     let config_path = home_dir.join(".rem-cli/config.yaml");
     if !config_path.exists() {
-        return Ok(default_tasks_dir);
+        migrate_legacy_tasks_dir(home_dir, &default_tasks_dir)?;
+        return Ok(Config::with_tasks_dir(default_tasks_dir));
     }
     let content = fs::read_to_string(&config_path)?;
     let config = serde_yaml::from_str::<Config>(&content).map_err(|error| {
@@ -27,7 +334,21 @@ fn tasks_dir_from(home_dir: &Path) -> io::Result<PathBuf> {
             format!("failed to parse {}: {error}", config_path.display()),
         )
     })?;
-    Ok(config.tasks_dir)
+    let flow = &config.status_flow;
+    let has_duplicate = flow
+        .iter()
+        .enumerate()
+        .any(|(position, status)| flow[..position].contains(status));
+    if flow.len() < 2 || has_duplicate {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "invalid status_flow in {}: list at least two statuses, each at most once",
+                config_path.display()
+            ),
+        ));
+    }
+    Ok(config)
 }
 
 #[cfg(test)]
@@ -46,7 +367,9 @@ mod tests {
         let expected = home_dir.join(".rem-cli/tasks");
 
         // WHEN
-        let actual = tasks_dir_from(&home_dir).unwrap();
+        let actual = load_from(&home_dir, &home_dir.join(".rem-cli"))
+            .unwrap()
+            .tasks_dir;
 
         // THEN
         assert_eq!(actual, expected);
@@ -66,7 +389,9 @@ mod tests {
         .unwrap();
 
         // WHEN
-        let actual = tasks_dir_from(&home_dir).unwrap();
+        let actual = load_from(&home_dir, &home_dir.join(".rem-cli"))
+            .unwrap()
+            .tasks_dir;
 
         // THEN
         assert_eq!(actual, expected);
@@ -83,7 +408,7 @@ mod tests {
         fs::write(config_dir.join("config.yaml"), "theme: dark\n").unwrap();
 
         // WHEN
-        let result = tasks_dir_from(&home_dir);
+        let result = load_from(&home_dir, &home_dir.join(".rem-cli"));
 
         // THEN
         assert!(result.is_err());
@@ -100,11 +425,295 @@ mod tests {
         fs::write(config_dir.join("config.yaml"), "tasks_dir: [").unwrap();
 
         // WHEN
-        let result = tasks_dir_from(&home_dir);
+        let result = load_from(&home_dir, &home_dir.join(".rem-cli"));
 
         // THEN
         assert!(result.is_err());
 
         fs::remove_dir_all(home_dir).unwrap();
     }
+
+    #[test]
+    fn confirm_on_quit_defaults_to_disabled_and_can_be_enabled() {
+        // GIVEN
+        let home_dir = temporary_home_dir();
+        let config_dir = home_dir.join(".rem-cli");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.yaml"),
+            "tasks_dir: /tmp/rem-cli\nconfirm_on_quit: true\n",
+        )
+        .unwrap();
+
+        // WHEN
+        let default_config = Config::with_tasks_dir(PathBuf::from("/tmp/rem-cli"));
+        let actual = load_from(&home_dir, &home_dir.join(".rem-cli")).unwrap();
+
+        // THEN
+        assert!(!default_config.confirm_on_quit);
+        assert!(actual.confirm_on_quit);
+
+        fs::remove_dir_all(home_dir).unwrap();
+    }
+
+    #[test]
+    fn ensure_file_creates_template_that_loads_as_defaults() {
+        // GIVEN
+        let home_dir = temporary_home_dir();
+        let expected = Config::with_tasks_dir(home_dir.join(".rem-cli/tasks"));
+
+        // WHEN
+        let path = ensure_file_in(&home_dir, &home_dir.join(".rem-cli")).unwrap();
+        let actual = load_from(&home_dir, &home_dir.join(".rem-cli")).unwrap();
+
+        // THEN
+        assert_eq!(path, home_dir.join(".rem-cli/config.yaml"));
+        assert_eq!(actual, expected);
+
+        fs::remove_dir_all(home_dir).unwrap();
+    }
+
+    #[test]
+    fn template_tasks_dir_keeps_backslashes_and_quotes() {
+        // GIVEN
+        let home_dir = temporary_home_dir();
+        let config_dir = home_dir.join(".rem-cli");
+        fs::create_dir_all(&config_dir).unwrap();
+        let expected = PathBuf::from(r"C:\Users\o'brien\tasks");
+        fs::write(config_dir.join("config.yaml"), default_template(&expected)).unwrap();
+
+        // WHEN
+        let actual = load_from(&home_dir, &config_dir).unwrap().tasks_dir;
+
+        // THEN
+        assert_eq!(actual, expected);
+
+        fs::remove_dir_all(home_dir).unwrap();
+    }
+
+    #[test]
+    fn ensure_file_keeps_existing_config() {
+        // GIVEN
+        let home_dir = temporary_home_dir();
+        let config_dir = home_dir.join(".rem-cli");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(config_dir.join("config.yaml"), "tasks_dir: /tmp/rem-cli\n").unwrap();
+
+        // WHEN
+        let path = ensure_file_in(&home_dir, &home_dir.join(".rem-cli")).unwrap();
+
+        // THEN
+        assert_eq!(
+            fs::read_to_string(path).unwrap(),
+            "tasks_dir: /tmp/rem-cli\n"
+        );
+
+        fs::remove_dir_all(home_dir).unwrap();
+    }
+
+    #[test]
+    fn done_style_is_parsed_from_snake_case() {
+        // GIVEN
+        let home_dir = temporary_home_dir();
+        let config_dir = home_dir.join(".rem-cli");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.yaml"),
+            "tasks_dir: /tmp/rem-cli\ndone_style: dim\n",
+        )
+        .unwrap();
+
+        // WHEN
+        let actual = load_from(&home_dir, &home_dir.join(".rem-cli"))
+            .unwrap()
+            .done_style;
+
+        // THEN
+        assert_eq!(actual, DoneStyle::Dim);
+
+        fs::remove_dir_all(home_dir).unwrap();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn linux_data_dir_follows_xdg_data_home() {
+        // GIVEN
+        let home_dir = PathBuf::from("/home/rem");
+
+        // WHEN
+        let with_xdg = platform_data_dir(&home_dir, Some(PathBuf::from("/data")));
+        let relative_xdg = platform_data_dir(&home_dir, Some(PathBuf::from("data")));
+        let without_xdg = platform_data_dir(&home_dir, None);
+
+        // THEN
+        assert_eq!(with_xdg, PathBuf::from("/data/rem-cli"));
+        assert_eq!(
+            relative_xdg,
+            PathBuf::from("/home/rem/.local/share/rem-cli")
+        );
+        assert_eq!(without_xdg, PathBuf::from("/home/rem/.local/share/rem-cli"));
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn non_linux_data_dir_stays_in_home_directory() {
+        // GIVEN
+        let home_dir = PathBuf::from("/Users/rem");
+
+        // WHEN
+        let actual = platform_data_dir(&home_dir, Some(PathBuf::from("/data")));
+
+        // THEN
+        assert_eq!(actual, PathBuf::from("/Users/rem/.rem-cli"));
+    }
+
+    #[test]
+    fn legacy_tasks_dir_is_migrated_once() {
+        // GIVEN
+        let home_dir = temporary_home_dir();
+        let legacy_todo_dir = home_dir.join(".rem-cli/tasks/todo");
+        let tasks_dir = home_dir.join(".local/share/rem-cli/tasks");
+        fs::create_dir_all(&legacy_todo_dir).unwrap();
+        fs::write(legacy_todo_dir.join("task.md"), "legacy").unwrap();
+
+        // WHEN
+        let first = migrate_legacy_tasks_dir(&home_dir, &tasks_dir).unwrap();
+        fs::create_dir_all(&legacy_todo_dir).unwrap();
+        let second = migrate_legacy_tasks_dir(&home_dir, &tasks_dir).unwrap();
+
+        // THEN
+        assert_eq!(first, Some(home_dir.join(".rem-cli/tasks")));
+        assert_eq!(second, None);
+        assert_eq!(
+            fs::read_to_string(tasks_dir.join("todo/task.md")).unwrap(),
+            "legacy"
+        );
+        assert!(legacy_todo_dir.exists());
+
+        fs::remove_dir_all(home_dir).unwrap();
+    }
+
+    #[test]
+    fn missing_config_migrates_legacy_tasks_dir_to_data_dir() {
+        // GIVEN
+        let home_dir = temporary_home_dir();
+        let data_dir = home_dir.join(".local/share/rem-cli");
+        fs::create_dir_all(home_dir.join(".rem-cli/tasks/doing")).unwrap();
+
+        // WHEN
+        let actual = load_from(&home_dir, &data_dir).unwrap().tasks_dir;
+
+        // THEN
+        assert_eq!(actual, data_dir.join("tasks"));
+        assert!(actual.join("doing").is_dir());
+        assert!(!home_dir.join(".rem-cli/tasks").exists());
+
+        fs::remove_dir_all(home_dir).unwrap();
+    }
+
+    #[test]
+    fn ensure_file_migrates_legacy_tasks_dir_before_writing_template() {
+        // GIVEN
+        let home_dir = temporary_home_dir();
+        let data_dir = home_dir.join(".local/share/rem-cli");
+        let legacy_todo_dir = home_dir.join(".rem-cli/tasks/todo");
+        fs::create_dir_all(&legacy_todo_dir).unwrap();
+        fs::write(legacy_todo_dir.join("task.md"), "legacy").unwrap();
+
+        // WHEN
+        ensure_file_in(&home_dir, &data_dir).unwrap();
+        let actual = load_from(&home_dir, &data_dir).unwrap().tasks_dir;
+
+        // THEN
+        assert_eq!(actual, data_dir.join("tasks"));
+        assert_eq!(
+            fs::read_to_string(actual.join("todo/task.md")).unwrap(),
+            "legacy"
+        );
+        assert!(!home_dir.join(".rem-cli/tasks").exists());
+
+        fs::remove_dir_all(home_dir).unwrap();
+    }
+
+    #[test]
+    fn editor_command_prefers_visual_then_editor_then_nvim() {
+        // GIVEN
+        let some = |value: &str| Some(value.to_string());
+
+        // WHEN
+        let visual = editor_command_from(some("code --wait"), some("vi"));
+        let editor = editor_command_from(some("  "), some("vi"));
+        let fallback = editor_command_from(None, None);
+
+        // THEN
+        assert_eq!(visual, ["code", "--wait"]);
+        assert_eq!(editor, ["vi"]);
+        assert_eq!(fallback, ["nvim"]);
+    }
+
+    #[test]
+    fn done_forward_action_is_parsed_and_defaults_to_none() {
+        // GIVEN
+        let home_dir = temporary_home_dir();
+        let config_dir = home_dir.join(".rem-cli");
+        fs::create_dir_all(&config_dir).unwrap();
+        let load_action = |line: &str| {
+            fs::write(
+                config_dir.join("config.yaml"),
+                format!("tasks_dir: /tmp/rem-cli\n{line}"),
+            )
+            .unwrap();
+            load_from(&home_dir, &config_dir)
+                .unwrap()
+                .done_forward_action
+        };
+
+        // WHEN
+        let actual = [
+            "",
+            "done_forward_action: archive\n",
+            "done_forward_action: delete\n",
+        ]
+        .map(load_action);
+
+        // THEN
+        assert_eq!(
+            actual,
+            [
+                DoneForwardAction::None,
+                DoneForwardAction::Archive,
+                DoneForwardAction::Delete
+            ]
+        );
+
+        fs::remove_dir_all(home_dir).unwrap();
+    }
+
+    #[test]
+    fn status_flow_is_parsed_and_validated() {
+        // GIVEN
+        let home_dir = temporary_home_dir();
+        let config_dir = home_dir.join(".rem-cli");
+        fs::create_dir_all(&config_dir).unwrap();
+        let load_flow = |flow: &str| {
+            fs::write(
+                config_dir.join("config.yaml"),
+                format!("tasks_dir: /tmp/rem-cli\nstatus_flow: {flow}\n"),
+            )
+            .unwrap();
+            load_from(&home_dir, &config_dir).map(|config| config.status_flow)
+        };
+
+        // WHEN
+        let skipping_doing = load_flow("[todo, done]").unwrap();
+        let duplicated = load_flow("[todo, doing, todo]");
+        let single = load_flow("[todo]");
+
+        // THEN
+        assert_eq!(skipping_doing, [TaskStatus::Todo, TaskStatus::Done]);
+        assert_eq!(duplicated.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(single.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        fs::remove_dir_all(home_dir).unwrap();
+    }
 }
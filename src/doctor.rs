@@ -0,0 +1,284 @@
+use crate::task::{Task, TaskStatus};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Extensions of the temporary files written while a task file is replaced; one left
+/// behind means a write was interrupted.
+const TEMPORARY_EXTENSIONS: [&str; 4] = ["update", "rollback", "migrate", "tmp"];
+
+/// A problem found by `rem doctor`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Finding {
+    /// The file could not be read or its frontmatter could not be parsed.
+    Unparseable { path: PathBuf, error: String },
+    /// The file name is not `<id>.md` for the id in its frontmatter.
+    IdMismatch { path: PathBuf, id: Uuid },
+    /// Several files carry the same id.
+    DuplicateId { id: Uuid, paths: Vec<PathBuf> },
+    /// The last status recorded in `history` differs from the directory the file is in.
+    WrongStatusDir {
+        path: PathBuf,
+        directory: TaskStatus,
+        recorded: TaskStatus,
+    },
+    /// A temporary file left behind by an interrupted write.
+    TemporaryFile { path: PathBuf },
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Finding::Unparseable { path, error } => {
+                write!(f, "unparseable: {}: {error}", path.display())
+            }
+            Finding::IdMismatch { path, id } => {
+                write!(f, "id mismatch: {} has id {id}", path.display())
+            }
+            Finding::DuplicateId { id, paths } => {
+                let paths = paths
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "duplicate id: {id} in {paths}")
+            }
+            Finding::WrongStatusDir {
+                path,
+                directory,
+                recorded,
+            } => write!(
+                f,
+                "wrong status directory: {} is in {} but its history ends in {}",
+                path.display(),
+                directory.label(),
+                recorded.label()
+            ),
+            Finding::TemporaryFile { path } => {
+                write!(f, "leftover temporary file: {}", path.display())
+            }
+        }
+    }
+}
+
+/// Runs every check against the task directory without changing any file.
+pub fn check(base_dir: &Path) -> io::Result<Vec<Finding>> {
+    let mut tasks = Vec::new();
+    let mut findings = Vec::new();
+    for (path, result) in Task::inspect_from(base_dir)? {
+        match result {
+            Ok(task) => tasks.push((path, task)),
+            Err(error) => findings.push(Finding::Unparseable {
+                path,
+                error: error.to_string(),
+            }),
+        }
+    }
+    findings.extend(id_mismatches(&tasks));
+    findings.extend(duplicate_ids(&tasks));
+    findings.extend(wrong_status_dirs(&tasks));
+    findings.extend(temporary_files(base_dir)?);
+    Ok(findings)
+}
+
+/// Finds files whose name does not match the id in their frontmatter.
+pub fn id_mismatches(tasks: &[(PathBuf, Task)]) -> Vec<Finding> {
+    tasks
+        .iter()
+        .filter(|(path, task)| {
+            path.file_stem().and_then(|stem| stem.to_str()) != Some(&task.id.to_string())
+        })
+        .map(|(path, task)| Finding::IdMismatch {
+            path: path.clone(),
+            id: task.id,
+        })
+        .collect()
+}
+
+/// Finds ids carried by more than one file.
+pub fn duplicate_ids(tasks: &[(PathBuf, Task)]) -> Vec<Finding> {
+    let mut paths_by_id = BTreeMap::<Uuid, Vec<PathBuf>>::new();
+    for (path, task) in tasks {
+        paths_by_id.entry(task.id).or_default().push(path.clone());
+    }
+    paths_by_id
+        .into_iter()
+        .filter(|(_, paths)| paths.len() > 1)
+        .map(|(id, paths)| Finding::DuplicateId { id, paths })
+        .collect()
+}
+
+/// Finds files whose last recorded status change points to another status directory.
+///
+/// Tasks without history, created before it was recorded, are not checked.
+pub fn wrong_status_dirs(tasks: &[(PathBuf, Task)]) -> Vec<Finding> {
+    tasks
+        .iter()
+        .filter_map(|(path, task)| {
+            let recorded = task.history.last()?.status;
+            (recorded != task.status).then(|| Finding::WrongStatusDir {
+                path: path.clone(),
+                directory: task.status,
+                recorded,
+            })
+        })
+        .collect()
+}
+
+/// Finds temporary files in the status directories and their project subdirectories.
+pub fn temporary_files(base_dir: &Path) -> io::Result<Vec<Finding>> {
+    let mut paths = Vec::new();
+    for status in TaskStatus::ALL {
+        let mut dirs = vec![Task::status_dir(base_dir, status)];
+        while let Some(dir) = dirs.pop() {
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(error),
+            };
+            for entry in entries {
+                let path = entry?.path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else if path
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .is_some_and(|extension| TEMPORARY_EXTENSIONS.contains(&extension))
+                {
+                    paths.push(path);
+                }
+            }
+        }
+    }
+    paths.sort();
+    Ok(paths
+        .into_iter()
+        .map(|path| Finding::TemporaryFile { path })
+        .collect())
+}
+
+/// Renames the file of every `IdMismatch` finding to `<id>.md`, the same policy `Task::load`
+/// applies, and returns one report line per file.
+pub fn fix_id_mismatches(findings: &[Finding]) -> Vec<String> {
+    findings
+        .iter()
+        .filter_map(|finding| match finding {
+            Finding::IdMismatch { path, id } => Some((path, *id)),
+            _ => None,
+        })
+        .map(|(path, id)| match Task::rename_to_match_id(path, id) {
+            Ok(Some(renamed)) => format!("renamed {} to {}", path.display(), renamed.display()),
+            Ok(None) => format!("skipped {}: {id}.md already exists", path.display()),
+            Err(error) => format!("failed to rename {}: {error}", path.display()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temporary_tasks_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("rem-cli-doctor-test-{}", Uuid::new_v4()))
+    }
+
+    #[test]
+    fn renamed_file_is_reported_as_id_mismatch() {
+        // GIVEN: a task file renamed away from its id
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("renamed".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let renamed = task.file_path().with_file_name("renamed.md");
+        fs::rename(task.file_path(), &renamed).unwrap();
+
+        // WHEN
+        let findings = check(&tasks_dir).unwrap();
+
+        // THEN
+        assert_eq!(
+            findings,
+            vec![Finding::IdMismatch {
+                path: renamed,
+                id: task.id
+            }]
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn fix_renames_mismatched_file_to_its_id() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("renamed".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let renamed = task.file_path().with_file_name("renamed.md");
+        fs::rename(task.file_path(), &renamed).unwrap();
+        let findings = check(&tasks_dir).unwrap();
+
+        // WHEN
+        let report = fix_id_mismatches(&findings);
+
+        // THEN
+        assert_eq!(
+            report,
+            vec![format!(
+                "renamed {} to {}",
+                renamed.display(),
+                task.file_path().display()
+            )]
+        );
+        assert!(!renamed.exists());
+        assert!(task.file_path().exists());
+        assert!(check(&tasks_dir).unwrap().is_empty());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn broken_copied_misplaced_and_temporary_files_are_reported() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("original".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let doing_dir = Task::status_dir(&tasks_dir, TaskStatus::Doing);
+        fs::create_dir_all(&doing_dir).unwrap();
+        let copy = doing_dir.join(format!("{}.md", task.id));
+        fs::copy(task.file_path(), &copy).unwrap();
+        let broken = doing_dir.join("broken.md");
+        fs::write(&broken, "---\nname: [unclosed\n---\n").unwrap();
+        let leftover = task.file_path().with_extension("md.update");
+        fs::write(&leftover, "").unwrap();
+        let original = fs::read_to_string(task.file_path()).unwrap();
+
+        // WHEN
+        let findings = check(&tasks_dir).unwrap();
+
+        // THEN
+        assert!(matches!(
+            &findings[0],
+            Finding::Unparseable { path, .. } if *path == broken
+        ));
+        assert_eq!(
+            findings[1..],
+            [
+                Finding::DuplicateId {
+                    id: task.id,
+                    paths: vec![task.file_path(), copy.clone()]
+                },
+                Finding::WrongStatusDir {
+                    path: copy,
+                    directory: TaskStatus::Doing,
+                    recorded: TaskStatus::Todo
+                },
+                Finding::TemporaryFile { path: leftover },
+            ]
+        );
+        assert_eq!(fs::read_to_string(task.file_path()).unwrap(), original);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+}
@@ -0,0 +1,62 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const DRAFT_FILE_NAME: &str = ".rem-draft.txt";
+
+/// Returns the draft file path under the task storage directory.
+pub fn path(tasks_dir: &Path) -> PathBuf {
+    tasks_dir.join(DRAFT_FILE_NAME)
+}
+
+/// Loads the unfinished task name, returning `None` when there is no draft.
+pub fn load(tasks_dir: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path(tasks_dir)) {
+        Ok(text) if text.is_empty() => Ok(None),
+        Ok(text) => Ok(Some(text)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Writes the unfinished task name, creating the task storage directory if needed.
+pub fn save(tasks_dir: &Path, text: &str) -> io::Result<()> {
+    fs::create_dir_all(tasks_dir)?;
+    fs::write(path(tasks_dir), text)
+}
+
+/// Removes the draft file. A missing draft is not an error.
+pub fn clear(tasks_dir: &Path) -> io::Result<()> {
+    match fs::remove_file(path(tasks_dir)) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn temporary_tasks_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("rem-cli-draft-test-{}", Uuid::new_v4()))
+    }
+
+    #[test]
+    fn saved_draft_is_loaded_back_until_cleared() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        save(&tasks_dir, "half-typed task").unwrap();
+
+        // WHEN
+        let loaded = load(&tasks_dir).unwrap();
+        clear(&tasks_dir).unwrap();
+        let cleared = load(&tasks_dir).unwrap();
+
+        // THEN
+        assert_eq!(loaded.as_deref(), Some("half-typed task"));
+        assert_eq!(cleared, None);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+}
@@ -0,0 +1,167 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io;
+
+/// How the metadata at the top of a task file is written.
+///
+/// Loading detects the format from the first line, so files in any format can share a
+/// task directory; rewriting a task keeps the format of its file.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum FrontmatterFormat {
+    /// YAML between `---` lines.
+    #[default]
+    Yaml,
+    /// TOML between `+++` lines, as used by Hugo and Zola.
+    Toml,
+    /// A JSON object whose closing `}` is alone on its line, as used by Hugo.
+    Json,
+}
+
+impl FrontmatterFormat {
+    /// Returns the format of a task file's content: TOML when it starts with `+++`, JSON when
+    /// it starts with `{`, YAML otherwise.
+    pub fn detect(content: &str) -> Self {
+        if content.starts_with("+++") {
+            Self::Toml
+        } else if content.starts_with('{') {
+            Self::Json
+        } else {
+            Self::Yaml
+        }
+    }
+}
+
+/// Splits task file content into its frontmatter text and markdown body.
+fn split(content: &str) -> (&str, &str) {
+    match FrontmatterFormat::detect(content) {
+        FrontmatterFormat::Yaml => {
+            let frontmatter = content
+                .trim_start_matches("---\n")
+                .split("---")
+                .next()
+                .unwrap_or("");
+            let body = content
+                .strip_prefix("---\n")
+                .and_then(|s| s.find("\n---\n").map(|pos| &s[pos + 5..]))
+                .unwrap_or("");
+            (frontmatter, body)
+        }
+        FrontmatterFormat::Toml => {
+            let rest = content.strip_prefix("+++\n").unwrap_or("");
+            match rest.find("\n+++\n") {
+                Some(pos) => (&rest[..pos + 1], &rest[pos + 5..]),
+                None => (rest, ""),
+            }
+        }
+        FrontmatterFormat::Json => match content.find("\n}\n") {
+            Some(pos) => (&content[..pos + 2], &content[pos + 3..]),
+            None => (content, ""),
+        },
+    }
+}
+
+/// Deserializes the frontmatter of task file content with the parser of its format.
+pub fn parse<T: DeserializeOwned>(content: &str) -> io::Result<T> {
+    let frontmatter = split(content).0;
+    let invalid = |error: String| io::Error::new(io::ErrorKind::InvalidData, error);
+    match FrontmatterFormat::detect(content) {
+        FrontmatterFormat::Yaml => {
+            serde_yaml::from_str(frontmatter).map_err(|error| invalid(error.to_string()))
+        }
+        FrontmatterFormat::Toml => {
+            toml::from_str(frontmatter).map_err(|error| invalid(error.to_string()))
+        }
+        FrontmatterFormat::Json => {
+            serde_json::from_str(frontmatter).map_err(|error| invalid(error.to_string()))
+        }
+    }
+}
+
+/// Returns the markdown body that follows the frontmatter.
+pub fn body(content: &str) -> &str {
+    split(content).1
+}
+
+/// Builds task file content from frontmatter in the given format and a markdown body.
+pub fn render<T: Serialize>(
+    format: FrontmatterFormat,
+    frontmatter: &T,
+    body: &str,
+) -> io::Result<String> {
+    match format {
+        FrontmatterFormat::Yaml => {
+            let yaml = serde_yaml::to_string(frontmatter).map_err(io::Error::other)?;
+            Ok(format!("---\n{yaml}---\n{body}"))
+        }
+        FrontmatterFormat::Toml => {
+            let toml = toml::to_string(frontmatter).map_err(io::Error::other)?;
+            Ok(format!("+++\n{toml}+++\n{body}"))
+        }
+        FrontmatterFormat::Json => {
+            let json = serde_json::to_string_pretty(frontmatter).map_err(io::Error::other)?;
+            Ok(format!("{json}\n{body}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn json_frontmatter_round_trips() {
+        // GIVEN
+        let mut frontmatter = BTreeMap::new();
+        frontmatter.insert("name", "quote \" slash \\ tab \t end".to_string());
+        frontmatter.insert("empty", String::new());
+
+        // WHEN
+        let content = render(FrontmatterFormat::Json, &frontmatter, "## Notes\n").unwrap();
+        let parsed: BTreeMap<String, String> = parse(&content).unwrap();
+
+        // THEN
+        assert!(content.starts_with("{\n  \"empty\": \"\",\n"));
+        assert_eq!(FrontmatterFormat::detect(&content), FrontmatterFormat::Json);
+        assert_eq!(parsed["name"], frontmatter["name"]);
+        assert_eq!(body(&content), "## Notes\n");
+    }
+
+    #[test]
+    fn toml_frontmatter_round_trips() {
+        // GIVEN
+        let mut frontmatter = BTreeMap::new();
+        frontmatter.insert("name", "quote \" plus +++ end".to_string());
+        frontmatter.insert("empty", String::new());
+
+        // WHEN
+        let content = render(FrontmatterFormat::Toml, &frontmatter, "## Notes\n").unwrap();
+        let parsed: BTreeMap<String, String> = parse(&content).unwrap();
+
+        // THEN
+        assert!(content.starts_with("+++\nempty = \"\"\n"));
+        assert_eq!(FrontmatterFormat::detect(&content), FrontmatterFormat::Toml);
+        assert_eq!(
+            parsed,
+            frontmatter
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect()
+        );
+        assert_eq!(body(&content), "## Notes\n");
+    }
+
+    #[test]
+    fn hand_written_toml_frontmatter_is_read() {
+        // GIVEN
+        let content = "+++\nname = 'toml'\n+++\n## Notes\n";
+
+        // WHEN
+        let parsed: BTreeMap<String, String> = parse(content).unwrap();
+
+        // THEN
+        assert_eq!(parsed["name"], "toml");
+        assert_eq!(body(content), "## Notes\n");
+    }
+}
@@ -1,4 +1,34 @@
+//! Local-first task management used by the `rem` TUI.
+//!
+//! Tasks are markdown files with YAML (or JSON) frontmatter stored under
+//! `<tasks_dir>/{parking,todo,doing,done}/<id>.md`. The core types are
+//! re-exported at the crate root so that other tools can read and update
+//! the same files without the TUI:
+//!
+//! - [`TaskStore`]: every task file operation under one storage directory
+//! - [`Task`]: load (`Task::load`, `Task::all_from`, `Task::load_by_status`),
+//!   create (`Task::new_in` + `save`), and move (`update_status`) tasks
+//! - [`TaskStatus`]: the lifecycle status, which is also the directory name
+//! - [`Config`]: the settings the TUI reads from `~/.rem-cli/config.yaml`
+//!
+//! The `app`, `render`, `cli`, and `clipboard` modules make up the TUI binary and are not
+//! meant to be embedded.
+
 pub mod app;
+pub mod cli;
+pub mod clipboard;
 pub mod config;
+pub mod doctor;
+pub mod draft;
+pub mod frontmatter;
+pub mod lock;
+pub mod log;
 pub mod render;
+pub mod state;
+pub mod stats;
+pub mod store;
 pub mod task;
+
+pub use config::Config;
+pub use store::TaskStore;
+pub use task::{StatusChange, Task, TaskStatus};
@@ -0,0 +1,140 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+const LOCK_FILE_NAME: &str = ".rem.lock";
+
+/// Advisory lock marking that a TUI instance is using the task storage directory.
+///
+/// The lock file holds the owner's PID and is removed when the lock is dropped.
+#[derive(Debug)]
+pub struct InstanceLock {
+    path: PathBuf,
+    pid: u32,
+}
+
+/// Result of trying to take the lock.
+#[derive(Debug)]
+pub enum LockStatus {
+    Acquired(InstanceLock),
+    /// Another running process holds the lock.
+    HeldBy(u32),
+}
+
+/// Returns the lock file path under the task storage directory.
+pub fn path(tasks_dir: &Path) -> PathBuf {
+    tasks_dir.join(LOCK_FILE_NAME)
+}
+
+/// Takes the lock unless another live process holds it. Stale locks are reclaimed.
+pub fn acquire(tasks_dir: &Path) -> io::Result<LockStatus> {
+    acquire_as(tasks_dir, process::id(), is_process_alive)
+}
+
+/// Takes the lock even if another process holds it.
+pub fn force(tasks_dir: &Path) -> io::Result<InstanceLock> {
+    write_lock(tasks_dir, process::id())
+}
+
+fn acquire_as(
+    tasks_dir: &Path,
+    pid: u32,
+    is_alive: impl Fn(u32) -> bool,
+) -> io::Result<LockStatus> {
+    let holder = match fs::read_to_string(path(tasks_dir)) {
+        Ok(content) => content.trim().parse::<u32>().ok(),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(error) => return Err(error),
+    };
+    match holder {
+        Some(holder) if holder != pid && is_alive(holder) => Ok(LockStatus::HeldBy(holder)),
+        _ => write_lock(tasks_dir, pid).map(LockStatus::Acquired),
+    }
+}
+
+fn write_lock(tasks_dir: &Path, pid: u32) -> io::Result<InstanceLock> {
+    fs::create_dir_all(tasks_dir)?;
+    let path = path(tasks_dir);
+    fs::write(&path, format!("{pid}\n"))?;
+    Ok(InstanceLock { path, pid })
+}
+
+/// Checks whether a process exists by sending it signal 0.
+fn is_process_alive(pid: u32) -> bool {
+    Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+impl Drop for InstanceLock {
+    /// Removes the lock file, unless another instance has taken it over in the meantime.
+    fn drop(&mut self) {
+        let is_ours = fs::read_to_string(&self.path)
+            .is_ok_and(|content| content.trim() == self.pid.to_string());
+        if is_ours {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn temporary_tasks_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("rem-cli-lock-test-{}", Uuid::new_v4()))
+    }
+
+    #[test]
+    fn stale_lock_is_reclaimed() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        fs::create_dir_all(&tasks_dir).unwrap();
+        fs::write(path(&tasks_dir), "4242\n").unwrap();
+
+        // WHEN
+        let status = acquire_as(&tasks_dir, 7, |_| false).unwrap();
+
+        // THEN
+        assert!(matches!(status, LockStatus::Acquired(_)));
+        assert_eq!(fs::read_to_string(path(&tasks_dir)).unwrap(), "7\n");
+        drop(status);
+        assert!(!path(&tasks_dir).exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn live_lock_is_reported_and_left_in_place() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        fs::create_dir_all(&tasks_dir).unwrap();
+        fs::write(path(&tasks_dir), "4242\n").unwrap();
+
+        // WHEN
+        let status = acquire_as(&tasks_dir, 7, |pid| pid == 4242).unwrap();
+
+        // THEN
+        assert!(matches!(status, LockStatus::HeldBy(4242)));
+        assert_eq!(fs::read_to_string(path(&tasks_dir)).unwrap(), "4242\n");
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn own_process_is_reported_alive() {
+        // GIVEN
+        let pid = process::id();
+
+        // WHEN
+        let actual = is_process_alive(pid);
+
+        // THEN
+        assert!(actual);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::task::TASK_DATETIME_FORMAT;
+use chrono::Local;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Size at which the log file is rotated to `rem.log.1`, replacing any older rotation.
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+/// Append-only debug log of key actions, enabled with `--verbose` or `debug_log: true`.
+///
+/// Writing never fails loudly: a log that cannot be written must not break the TUI.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Log {
+    path: PathBuf,
+}
+
+impl Log {
+    /// Creates a log that appends to the given file.
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Returns the default log file path (`~/.rem-cli/rem.log`).
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home_dir| home_dir.join(".rem-cli/rem.log"))
+    }
+
+    /// Returns the log file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends a timestamped line, ignoring any error.
+    pub fn write(&self, message: &str) {
+        let _ = self.try_write(message);
+    }
+
+    fn try_write(&self, message: &str) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        if fs::metadata(&self.path).is_ok_and(|metadata| metadata.len() >= MAX_LOG_BYTES) {
+            fs::rename(&self.path, self.path.with_extension("log.1"))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(
+            file,
+            "{} {}",
+            Local::now().format(TASK_DATETIME_FORMAT),
+            message.replace('\n', " ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn temporary_log_path() -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("rem-cli-log-test-{}", Uuid::new_v4()))
+            .join("rem.log")
+    }
+
+    #[test]
+    fn write_appends_one_timestamped_line_per_message() {
+        // GIVEN
+        let path = temporary_log_path();
+        let log = Log::new(path.clone());
+
+        // WHEN
+        log.write("first");
+        log.write("second\nline");
+
+        // THEN
+        let content = fs::read_to_string(&path).unwrap();
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with(" first"));
+        assert!(lines[1].ends_with(" second line"));
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn write_rotates_file_over_size_limit() {
+        // GIVEN
+        let path = temporary_log_path();
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "x".repeat(MAX_LOG_BYTES as usize)).unwrap();
+        let log = Log::new(path.clone());
+
+        // WHEN
+        log.write("fresh");
+
+        // THEN
+        assert!(fs::read_to_string(&path).unwrap().ends_with(" fresh\n"));
+        assert_eq!(
+            fs::metadata(path.with_extension("log.1")).unwrap().len(),
+            MAX_LOG_BYTES
+        );
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
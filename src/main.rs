@@ -1,64 +1,335 @@
 use crossterm::{
     ExecutableCommand,
-    event::{self, Event, KeyEventKind},
+    event::{self, DisableBracketedPaste, EnableBracketedPaste, Event, KeyEventKind},
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::prelude::*;
 use rem_cli::app::App;
+use rem_cli::cli::{self, BodySource, Command as CliCommand};
+use rem_cli::clipboard;
 use rem_cli::config;
+use rem_cli::doctor;
+use rem_cli::lock::{self, LockStatus};
 use rem_cli::render;
+use rem_cli::store::TaskStore;
+use rem_cli::task::Task;
 use std::io;
-use std::process::{self, Command};
+use std::path::Path;
+use std::process::{self, Command, ExitStatus};
 
 /// Entry point for the rem TUI application.
 ///
-/// Handles `--version` / `-V` flags, sets up the terminal (raw mode, alternate screen),
+/// Handles `--version` / `--help` / `--dir` / `--verbose` / `--no-persist` flags and the `list` / `count` / `add` / `export-md` / `doctor` / `config` subcommands, sets up the terminal (raw mode, alternate screen),
 /// runs the event loop, and restores the terminal on exit.
-/// When `app.open_file` is set, temporarily exits the TUI to open the file in neovim.
+/// When `app.open_files` is not empty, temporarily exits the TUI to open the files in neovim tabs;
+/// after `app.edit_config` is set, it opens the config file in the editor and reloads it.
 fn main() -> io::Result<()> {
-    if std::env::args().any(|a| a == "--version" || a == "-V") {
-        println!("rem {}", env!("CARGO_PKG_VERSION"));
-        return Ok(());
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let (verbose, args) = cli::take_flag(&args, "--verbose");
+    let (no_persist, args) = cli::take_flag(&args, "--no-persist");
+    let parsed = cli::take_dir_option(&args)
+        .and_then(|(dir, rest)| cli::parse(&rest).map(|command| (dir, command)));
+    let (dir, command) = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("{error}\n");
+            eprint!("{}", cli::help_text());
+            process::exit(2);
+        }
+    };
+    match command {
+        CliCommand::Tui
+        | CliCommand::List { .. }
+        | CliCommand::Count(_)
+        | CliCommand::ExportMd { .. }
+        | CliCommand::Doctor { .. }
+        | CliCommand::Add { .. } => {}
+        CliCommand::Config => {
+            let path = match config::ensure_file() {
+                Ok(path) => path,
+                Err(error) => {
+                    eprintln!("Failed to create config file: {error}");
+                    process::exit(1);
+                }
+            };
+            if let Err(error) = open_in_editor(&path) {
+                eprintln!("Failed to open {} in an editor: {error}", path.display());
+                process::exit(1);
+            }
+            return Ok(());
+        }
+        CliCommand::Version => {
+            println!("rem {}", env!("CARGO_PKG_VERSION"));
+            return Ok(());
+        }
+        CliCommand::Help => {
+            print!("{}", cli::help_text());
+            return Ok(());
+        }
     }
 
-    let tasks_dir = match config::tasks_dir() {
-        Ok(tasks_dir) => tasks_dir,
+    let load_config = || {
+        config::load().map(|mut loaded| {
+            if let Some(dir) = &dir {
+                loaded.tasks_dir = dir.clone();
+            }
+            loaded.debug_log |= verbose;
+            loaded.ephemeral |= no_persist || config::ephemeral_from_env();
+            loaded
+        })
+    };
+    let config = match load_config() {
+        Ok(config) => config,
         Err(error) => {
             eprintln!("Failed to load config: {error}");
             process::exit(1);
         }
     };
+    let store =
+        TaskStore::new(config.tasks_dir.clone()).with_frontmatter_format(config.frontmatter_format);
+    if let CliCommand::Count(statuses) = &command {
+        let count = statuses
+            .iter()
+            .map(|status| store.count(*status))
+            .sum::<io::Result<usize>>();
+        match count {
+            Ok(count) => println!("{count}"),
+            Err(error) => {
+                eprintln!("Failed to count tasks: {error}");
+                process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+    if let CliCommand::Doctor { fix } = command {
+        let result = doctor::check(store.root()).and_then(|findings| {
+            if !fix {
+                return Ok(findings);
+            }
+            for line in doctor::fix_id_mismatches(&findings) {
+                println!("{line}");
+            }
+            doctor::check(store.root())
+        });
+        match result {
+            Ok(findings) if findings.is_empty() => {
+                println!("No problems found in {}", store.root().display());
+            }
+            Ok(findings) => {
+                for finding in &findings {
+                    println!("{finding}");
+                }
+                println!(
+                    "{} {} found in {}",
+                    findings.len(),
+                    if findings.len() == 1 {
+                        "problem"
+                    } else {
+                        "problems"
+                    },
+                    store.root().display()
+                );
+                process::exit(1);
+            }
+            Err(error) => {
+                eprintln!("Failed to check tasks: {error}");
+                process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+    if let CliCommand::List {
+        statuses,
+        since,
+        until,
+    } = command
+    {
+        match store.load_by_status(&statuses) {
+            Ok(tasks) => Task::sort(tasks)
+                .iter()
+                .filter(|task| task.created_between(since, until))
+                .for_each(|task| println!("{}", cli::list_line(task))),
+            Err(error) => {
+                eprintln!("Failed to load tasks: {error}");
+                process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+    if let CliCommand::ExportMd { output } = command {
+        let markdown = match store.load_all() {
+            Ok(tasks) => cli::markdown_export(tasks),
+            Err(error) => {
+                eprintln!("Failed to load tasks: {error}");
+                process::exit(1);
+            }
+        };
+        match output {
+            Some(path) => {
+                if let Err(error) = cli::write_output(&path, &markdown) {
+                    eprintln!("Failed to write {}: {error}", path.display());
+                    process::exit(1);
+                }
+            }
+            None => print!("{markdown}"),
+        }
+        return Ok(());
+    }
+    if let Err(error) = store.ensure_dirs() {
+        eprintln!("Failed to create task directories: {error}");
+        process::exit(1);
+    }
+    if let CliCommand::Add {
+        name,
+        body,
+        skip_duplicates,
+    } = command
+    {
+        match add_task(&store, config.sequential_ids, name, body, skip_duplicates) {
+            Ok(created) if skip_duplicates => {
+                println!(
+                    "{} created, {} skipped",
+                    u8::from(created),
+                    u8::from(!created)
+                );
+            }
+            Ok(_) => {}
+            Err(error) => {
+                eprintln!("Failed to add task: {error}");
+                process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    let _lock = match lock::acquire(store.root()) {
+        Ok(LockStatus::Acquired(lock)) => Some(lock),
+        Ok(LockStatus::HeldBy(pid)) => {
+            eprint!(
+                "Another rem (PID {pid}) is using {}. Changes may overwrite each other. Continue? [y/N] ",
+                store.root().display()
+            );
+            let mut answer = String::new();
+            io::stdin().read_line(&mut answer)?;
+            if !answer.trim().eq_ignore_ascii_case("y") {
+                process::exit(1);
+            }
+            Some(lock::force(store.root())?)
+        }
+        // A read-only directory cannot hold the lock; `App::startup` detects it and refuses
+        // every key that writes, so there is nothing to protect.
+        Err(error)
+            if matches!(
+                error.kind(),
+                io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem
+            ) =>
+        {
+            None
+        }
+        Err(error) => {
+            eprintln!("Failed to create lock file: {error}");
+            process::exit(1);
+        }
+    };
 
     enable_raw_mode()?;
     io::stdout().execute(EnterAlternateScreen)?;
+    io::stdout().execute(EnableBracketedPaste)?;
 
     let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
-    let mut app = App::with_tasks_dir(tasks_dir);
+    let mut app = App::with_config(config);
+    app.startup();
 
     // Polling events
     while !app.should_quit {
         terminal.draw(|frame| render::render(frame, &app))?;
         app.load_parking_after_first_render();
+        app.tick();
 
-        if event::poll(std::time::Duration::from_millis(100))?
-            && let Event::Key(key) = event::read()?
-            && key.kind == KeyEventKind::Press
-        {
-            app.handle_key_event(key.code);
+        if event::poll(std::time::Duration::from_millis(100))? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    app.handle_key(key.code, key.modifiers);
+                }
+                Event::Paste(text) => app.handle_paste(&text),
+                _ => {}
+            }
         }
 
-        if let Some(path) = app.open_file.take() {
+        if let Some(text) = app.clipboard.take() {
+            clipboard::copy(terminal.backend_mut(), &text)?;
+        }
+
+        if !app.open_files.is_empty() {
+            let paths = std::mem::take(&mut app.open_files);
             disable_raw_mode()?;
             io::stdout().execute(LeaveAlternateScreen)?;
-            let _ = Command::new("nvim").arg(&path).status();
+            let _ = Command::new("nvim").arg("-p").args(&paths).status();
             enable_raw_mode()?;
             io::stdout().execute(EnterAlternateScreen)?;
+            io::stdout().execute(EnableBracketedPaste)?;
             terminal.clear()?;
             app.after_edit();
         }
+
+        if std::mem::take(&mut app.edit_config) {
+            disable_raw_mode()?;
+            io::stdout().execute(LeaveAlternateScreen)?;
+            let edited = config::ensure_file().and_then(|path| open_in_editor(&path));
+            enable_raw_mode()?;
+            io::stdout().execute(EnterAlternateScreen)?;
+            io::stdout().execute(EnableBracketedPaste)?;
+            terminal.clear()?;
+            app.after_config_edit(edited.and_then(|_| load_config()));
+        }
     }
 
+    io::stdout().execute(DisableBracketedPaste)?;
     disable_raw_mode()?;
     io::stdout().execute(LeaveAlternateScreen)?;
     Ok(())
 }
+
+/// Opens a file in the editor from `$VISUAL` / `$EDITOR` (neovim by default) and waits for it.
+fn open_in_editor(path: &Path) -> io::Result<ExitStatus> {
+    let command = config::editor_command();
+    Command::new(&command[0])
+        .args(&command[1..])
+        .arg(path)
+        .status()
+}
+
+/// Creates a TODO task for `rem add`, reading its body from stdin or a file when requested,
+/// and returns false instead when `skip_duplicates` is set and a task with the same name
+/// already exists in any status.
+fn add_task(
+    store: &TaskStore,
+    sequential_ids: bool,
+    name: String,
+    body: Option<BodySource>,
+    skip_duplicates: bool,
+) -> io::Result<bool> {
+    let existing = if skip_duplicates || sequential_ids {
+        store.load_all()?
+    } else {
+        Vec::new()
+    };
+    if skip_duplicates && existing.iter().any(|task| task.name.trim() == name.trim()) {
+        return Ok(false);
+    }
+    let body = match body {
+        Some(BodySource::Stdin) => io::read_to_string(io::stdin())?,
+        Some(BodySource::File(path)) => std::fs::read_to_string(path)?,
+        None => String::new(),
+    };
+    let mut task = store.create(name);
+    if sequential_ids {
+        let known_max = existing.iter().filter_map(|task| task.seq).max();
+        task.seq = Some(store.take_next_seq(known_max)?);
+    }
+    store.save(&task)?;
+    if !body.is_empty() {
+        task.save_body(&body)?;
+    }
+    Ok(true)
+}
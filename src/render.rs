@@ -1,18 +1,67 @@
-use crate::app::{App, Mode};
-use crate::task::{DEADLINE_DATE_FORMAT, TASK_DATETIME_FORMAT, Task, TaskStatus};
-use chrono::{Days, Local, NaiveDate};
+use crate::app::{App, FORM_LABELS, HELP_PAGE_COUNT, LayoutMode, Mode, format_created_range};
+use crate::cli;
+use crate::config::{Config, DeadlineStyle, DoneStyle, WrapMode};
+use crate::stats::Stats;
+use crate::task::{
+    DEADLINE_DATE_FORMAT, Priority, REMINDER_FORMAT, TASK_DATETIME_FORMAT, Task, TaskStatus,
+};
+use chrono::{Datelike, Days, Local, NaiveDate, NaiveDateTime, TimeDelta, TimeZone};
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{
+        Block, Borders, Clear, HighlightSpacing, List, ListItem, ListState, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState,
+    },
 };
 
 const OVERDUE_DEADLINE_COLOR: Color = Color::Rgb(190, 180, 120);
+const SHORT_ID_LENGTH: usize = 8;
+/// Marks the selected task when colors are disabled (`NO_COLOR`).
+const SELECTION_MARKER: &str = "> ";
+/// Keybinding groups the help line rotates through; `?` shows all of them at once.
+const HELP_PAGES: [&str; HELP_PAGE_COUNT] = [
+    " j/k: up/down | h/l: left/right | G/gg: bottom/top ",
+    " a/O: add/below | Tab: next status | ?: help | q: quit ",
+    " n/N: status | J/K: reorder | .: repeat | /: search ",
+    " x/p: cut/paste | s/S: sort | D: done+archive ",
+    " e: edit body | M: edit form | R: reminder | Z: defer ",
+    " Enter: open in nvim | o: start+open | W: open DOING ",
+    " !: overdue | f: priority | #: tag | Space/t: mark/tag ",
+    " ^O/^N: back/forward | L: follow link | m: mail ",
+    " d: done | [/]: done week | A: archive | P: purge ",
+    " i/I: ids/new id | U: UTC | z: expand | v: compact ",
+    " c: calendar | T: stats | r: reload | C: config ",
+    " y: copy path | F: created range | ^Q: force quit ",
+];
+/// Width of a column while another one is expanded with `z`; enough for its title.
+const COLLAPSED_COLUMN_WIDTH: u16 = 11;
+/// Shown before the name of tasks marked with `Space` for a bulk action.
+const MARKED_MARKER: &str = "+ ";
+/// Shown before the name of tasks whose markdown body is not empty.
+const NOTES_MARKER: &str = "* ";
+/// Shown under the selected task when another program changed its file since it was loaded.
+const CHANGED_ON_DISK_NOTICE: &str = "● Changed on disk (r: reload)";
+/// Width of the deadline calendar (`c`): seven 3-cell days plus borders.
+const CALENDAR_WIDTH: u16 = 23;
+/// Height of the deadline calendar: weekday header, up to six weeks, and borders.
+const CALENDAR_HEIGHT: u16 = 9;
+/// Background shades for days with one, two, and three or more deadlines.
+const CALENDAR_SHADES: [Color; 3] = [Color::Indexed(22), Color::Indexed(28), Color::Indexed(34)];
 
-/// Wraps a task name to the available panel width.
-fn wrap_task_name(name: &str, width: usize) -> Text<'static> {
+/// Fits a task name to the available panel width according to the configured `WrapMode`.
+fn wrap_task_name(name: &str, width: usize, wrap_mode: WrapMode) -> Text<'static> {
     if width == 0 || Line::from(name).width() <= width {
         return Text::from(name.to_string());
     }
+    Text::from(match wrap_mode {
+        WrapMode::Char => char_wrap(name, width),
+        WrapMode::Word => word_wrap(name, width),
+        WrapMode::Truncate => vec![truncate_with_ellipsis(name, width)],
+    })
+}
+
+/// Breaks a name at any character boundary, counting wide characters as two columns.
+fn char_wrap(name: &str, width: usize) -> Vec<Line<'static>> {
     let (mut lines, current_line) = name.chars().fold(
         (Vec::new(), String::new()),
         |(mut lines, current_line), character| {
@@ -31,11 +80,131 @@ fn wrap_task_name(name: &str, width: usize) -> Text<'static> {
     if !current_line.is_empty() || lines.is_empty() {
         lines.push(Line::from(current_line));
     }
-    Text::from(lines)
+    lines
+}
+
+/// Breaks a name between words, falling back to `char_wrap` for words wider than the line.
+fn word_wrap(name: &str, width: usize) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    for paragraph in name.split('\n') {
+        let mut current_line = String::new();
+        for word in paragraph.split(' ').filter(|word| !word.is_empty()) {
+            let candidate = if current_line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{current_line} {word}")
+            };
+            if Line::from(candidate.as_str()).width() <= width {
+                current_line = candidate;
+                continue;
+            }
+            if !current_line.is_empty() {
+                lines.push(Line::from(std::mem::take(&mut current_line)));
+            }
+            let mut pieces = char_wrap(word, width);
+            current_line = pieces
+                .pop()
+                .map(|line| line.to_string())
+                .unwrap_or_default();
+            lines.extend(pieces);
+        }
+        lines.push(Line::from(current_line));
+    }
+    lines
+}
+
+/// Cuts a name to a single line ending in `…` that fits the width.
+fn truncate_with_ellipsis(name: &str, width: usize) -> Line<'static> {
+    let mut truncated = String::new();
+    for character in name.chars().map(|c| if c == '\n' { ' ' } else { c }) {
+        if Line::from(format!("{truncated}{character}…")).width() > width {
+            break;
+        }
+        truncated.push(character);
+    }
+    Line::from(format!("{truncated}…"))
+}
+
+/// Splits a line into spans, emphasizing every case-insensitive occurrence of `query`.
+///
+/// Matches that straddle a wrap boundary are not highlighted.
+fn highlight_query(text: &str, query: &str) -> Vec<Span<'static>> {
+    if query.is_empty() {
+        return vec![Span::raw(text.to_string())];
+    }
+    let query_chars = query.chars().collect::<Vec<_>>();
+    let match_length = |start: usize| {
+        let mut matched = text[start..].char_indices();
+        let mut length = 0;
+        for query_char in &query_chars {
+            let (offset, text_char) = matched.next()?;
+            if !text_char.to_lowercase().eq(query_char.to_lowercase()) {
+                return None;
+            }
+            length = offset + text_char.len_utf8();
+        }
+        Some(length)
+    };
+    let mut spans = Vec::new();
+    let mut plain_start = 0;
+    let mut position = 0;
+    while position < text.len() {
+        if let Some(length) = match_length(position) {
+            if plain_start < position {
+                spans.push(Span::raw(text[plain_start..position].to_string()));
+            }
+            spans.push(Span::styled(
+                text[position..position + length].to_string(),
+                Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            ));
+            position += length;
+            plain_start = position;
+        } else {
+            position += text[position..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    if plain_start < text.len() || spans.is_empty() {
+        spans.push(Span::raw(text[plain_start..].to_string()));
+    }
+    spans
+}
+
+/// Runtime display switches toggled from the keyboard (`i`, `U`, and `Space` for this task).
+#[derive(Clone, Copy, Debug, Default)]
+struct DisplayToggles {
+    show_ids: bool,
+    display_utc: bool,
+    marked: bool,
+}
+
+/// Formats a stored local date-time, converted to UTC and suffixed with `UTC` when
+/// `display_utc` is set.
+fn format_time(time: NaiveDateTime, format: &str, display_utc: bool) -> String {
+    if !display_utc {
+        return time.format(format).to_string();
+    }
+    let utc = Local
+        .from_local_datetime(&time)
+        .earliest()
+        .map_or(time, |local| local.naive_utc());
+    format!("{} UTC", utc.format(format))
 }
 
 /// Builds the task text with its deadline below the wrapped name.
-fn task_text(task: &Task, width: usize, today: NaiveDate, is_selected: bool) -> Text<'static> {
+///
+/// Tasks in a project subdirectory are prefixed with `<project>/`.
+/// When `toggles.show_ids` is set, the `#<seq>` id (or the first characters of the UUID) is prepended to the name
+/// and the name is wrapped to the remaining width.
+/// Occurrences of `query` are highlighted within each wrapped line.
+fn task_text(
+    task: &Task,
+    width: usize,
+    today: NaiveDate,
+    is_selected: bool,
+    toggles: DisplayToggles,
+    query: &str,
+    config: &Config,
+) -> Text<'static> {
     let is_overdue = task.deadline < today;
     let name_style = if is_overdue {
         Style::default().fg(Color::Yellow)
@@ -49,13 +218,13 @@ fn task_text(task: &Task, width: usize, today: NaiveDate, is_selected: bool) ->
     } else {
         Color::DarkGray
     };
-    let deadline = Line::styled(
-        format!("Deadline: {}", task.deadline.format(DEADLINE_DATE_FORMAT)),
-        Style::default().fg(deadline_color),
-    );
+    let deadline_style = Style::default().fg(deadline_color);
     let completed = task.completed_at.map(|completed_at| {
         Line::styled(
-            format!("Completed: {}", completed_at.format(TASK_DATETIME_FORMAT)),
+            format!(
+                "Completed: {}",
+                format_time(completed_at, TASK_DATETIME_FORMAT, toggles.display_utc)
+            ),
             Style::default().fg(if is_selected {
                 Color::Gray
             } else {
@@ -63,17 +232,201 @@ fn task_text(task: &Task, width: usize, today: NaiveDate, is_selected: bool) ->
             }),
         )
     });
+    let id_prefix = toggles.show_ids.then(|| {
+        Span::styled(
+            task.seq.map_or_else(
+                || format!("{} ", &task.id.to_string()[..SHORT_ID_LENGTH]),
+                |seq| format!("#{seq} "),
+            ),
+            Style::default().add_modifier(Modifier::DIM),
+        )
+    });
+    let mark = toggles
+        .marked
+        .then(|| Span::styled(MARKED_MARKER, Style::default().fg(Color::Green)));
+    let project = task
+        .project
+        .as_ref()
+        .map(|project| Span::styled(format!("{project}/ "), Style::default().fg(Color::Magenta)));
+    let tags = task
+        .tags
+        .iter()
+        .map(|tag| Span::styled(format!("#{tag} "), Style::default().fg(Color::Blue)));
+    let notes_marker = task
+        .has_notes()
+        .then(|| Span::styled(NOTES_MARKER, Style::default().fg(Color::Cyan)));
+    let checklist = task.checklist_progress().map(|(done, total)| {
+        let color = if done == total {
+            Color::Green
+        } else {
+            Color::Cyan
+        };
+        Span::styled(format!("({done}/{total}) "), Style::default().fg(color))
+    });
+    let prefix = mark
+        .into_iter()
+        .chain(id_prefix)
+        .chain(project)
+        .chain(tags)
+        .chain(notes_marker)
+        .chain(checklist)
+        .collect::<Vec<_>>();
+    let prefix_width = prefix.iter().map(Span::width).sum::<usize>();
+    let name_width = if prefix_width < width {
+        width - prefix_width
+    } else {
+        width
+    };
+    let inline_deadline = (config.deadline_style == DeadlineStyle::Inline)
+        .then(|| relative_deadline(task.deadline, today))
+        .map(|label| {
+            let label_width = Line::from(label.as_str()).width();
+            (label, label_width)
+        })
+        .filter(|(_, label_width)| label_width + 2 <= name_width);
+    let wrap_width = inline_deadline
+        .as_ref()
+        .map_or(name_width, |(_, label_width)| name_width - label_width - 1);
+    let deadline_line = inline_deadline.is_none().then(|| {
+        Line::styled(
+            format!("Deadline: {}", task.deadline.format(DEADLINE_DATE_FORMAT)),
+            deadline_style,
+        )
+    });
     Text::from(
-        wrap_task_name(task.name.as_str(), width)
+        wrap_task_name(task.name.as_str(), wrap_width, config.wrap_mode)
             .lines
             .into_iter()
-            .map(|line| line.patch_style(name_style))
-            .chain([deadline])
+            .enumerate()
+            .map(|(line_index, line)| {
+                let line =
+                    Line::from(highlight_query(&line.to_string(), query)).patch_style(name_style);
+                let mut spans = match (prefix.is_empty(), line_index) {
+                    (true, _) => Vec::new(),
+                    (false, 0) => prefix.clone(),
+                    (false, _) => vec![Span::raw(" ".repeat(prefix_width))],
+                };
+                let line_width = line.width();
+                spans.extend(line.spans);
+                if let (0, Some((label, label_width))) = (line_index, &inline_deadline) {
+                    let padding = name_width.saturating_sub(line_width + label_width);
+                    spans.push(Span::raw(" ".repeat(padding)));
+                    spans.push(Span::styled(label.clone(), deadline_style));
+                }
+                Line::from(spans).style(line.style)
+            })
+            .chain(deadline_line)
             .chain(completed)
             .collect::<Vec<_>>(),
     )
 }
 
+/// Formats a deadline relative to today for the inline deadline: `today`, `3d`, or `-2d` when overdue.
+fn relative_deadline(deadline: NaiveDate, today: NaiveDate) -> String {
+    match (deadline - today).num_days() {
+        0 => "today".to_string(),
+        days => format!("{days}d"),
+    }
+}
+
+/// Formats a duration with its two largest units, e.g. `2d 3h` or `45m`.
+///
+/// A negative duration comes from a timestamp in the future, such as a hand-edited
+/// `created_at` or clock skew: under a minute it reads `<1m`, otherwise `in the future`.
+fn format_duration(duration: TimeDelta) -> String {
+    if duration <= -TimeDelta::minutes(1) {
+        return "in the future".to_string();
+    }
+    let minutes = duration.num_minutes().max(0);
+    let (days, hours, minutes) = (minutes / (24 * 60), minutes / 60 % 24, minutes % 60);
+    match (days, hours) {
+        (0, 0) if minutes == 0 => "<1m".to_string(),
+        (0, 0) => format!("{minutes}m"),
+        (0, _) => format!("{hours}h {minutes}m"),
+        _ => format!("{days}d {hours}h"),
+    }
+}
+
+/// Builds the `TODO 2d 3h | DOING 4h 0m` summary shown under the selected task.
+///
+/// Returns `None` until the task has changed status at least once.
+fn time_in_status_line(task: &Task, now: NaiveDateTime) -> Option<Line<'static>> {
+    if task.history.len() < 2 {
+        return None;
+    }
+    let summary = task
+        .time_in_status(now)
+        .into_iter()
+        .map(|(status, duration)| format!("{} {}", status.label(), format_duration(duration)))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    Some(Line::styled(summary, Style::default().fg(Color::Gray)))
+}
+
+/// Builds the `Time spent: 3h 10m` line for tasks that have been in DOING.
+fn time_spent_line(task: &Task, now: NaiveDateTime) -> Option<Line<'static>> {
+    let seconds = task.time_spent_secs_at(now);
+    if seconds == 0 && task.started_at.is_none() {
+        return None;
+    }
+    let duration = i64::try_from(seconds)
+        .ok()
+        .and_then(TimeDelta::try_seconds)
+        .unwrap_or(TimeDelta::MAX);
+    Some(Line::styled(
+        format!("Time spent: {}", format_duration(duration)),
+        Style::default().fg(Color::Gray),
+    ))
+}
+
+/// Builds the `Reminders: ...` line, dimming past reminders and highlighting the next one.
+fn reminders_line(task: &Task, now: NaiveDateTime, display_utc: bool) -> Option<Line<'static>> {
+    if task.reminders.is_empty() {
+        return None;
+    }
+    let mut reminders = task.reminders.clone();
+    reminders.sort();
+    let next = reminders.iter().position(|reminder| *reminder > now);
+    let mut spans = vec![Span::styled(
+        "Reminders: ",
+        Style::default().fg(Color::Gray),
+    )];
+    for (position, reminder) in reminders.iter().enumerate() {
+        if position > 0 {
+            spans.push(Span::styled(", ", Style::default().fg(Color::Gray)));
+        }
+        let style = match next {
+            Some(next) if position == next => Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            Some(next) if position > next => Style::default().fg(Color::Gray),
+            _ => Style::default().fg(Color::DarkGray),
+        };
+        spans.push(Span::styled(
+            format_time(*reminder, REMINDER_FORMAT, display_utc),
+            style,
+        ));
+    }
+    Some(Line::from(spans))
+}
+
+/// Returns the item style for a DONE task.
+///
+/// The selected task is never dimmed so that it stays readable on the highlight background.
+fn done_task_style(done_style: DoneStyle, is_selected: bool) -> Style {
+    let modifier = match done_style {
+        DoneStyle::DimStrikethrough => Modifier::DIM | Modifier::CROSSED_OUT,
+        DoneStyle::Dim => Modifier::DIM,
+        DoneStyle::Plain => Modifier::empty(),
+    };
+    let modifier = if is_selected {
+        modifier - Modifier::DIM
+    } else {
+        modifier
+    };
+    Style::default().add_modifier(modifier)
+}
+
 fn status_title_style(status: TaskStatus) -> Style {
     let background = match status {
         TaskStatus::Parking | TaskStatus::Done => Color::DarkGray,
@@ -86,16 +439,31 @@ fn status_title_style(status: TaskStatus) -> Style {
 /// Renders the entire TUI layout.
 ///
 /// Layout structure:
-/// - Main area: PARKING, TODO, DOING, and optionally DONE columns
-/// - Bottom: Input field (Editing mode) or keybinding help (Normal mode)
+/// - Top: search query and last update time, then the tag bar when any loaded task has tags
+/// - Main area: PARKING, TODO, DOING, and optionally DONE columns,
+///   or a single stacked list in `LayoutMode::Compact`
+/// - Bottom: Input field (Editing / Search / Reminder / Tag / CreatedRange mode) or keybinding help (Normal / EditBody mode)
+/// - Overlay: body editor (EditBody mode), confirmation popup, or keybinding help (`?`)
+///
+/// With `use_color` off (`NO_COLOR`), colors are stripped and the selection is marked with `>`.
 pub fn render(frame: &mut Frame, app: &App) {
-    let outer = if app.input_mode == Mode::Editing {
+    let outer = if matches!(
+        app.input_mode,
+        Mode::Editing | Mode::Search | Mode::Reminder | Mode::Tag | Mode::CreatedRange
+    ) {
         Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(frame.area())
     } else {
         Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).split(frame.area())
     };
 
-    let main = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(outer[0]);
+    let tags = Task::collect_tags(&app.tasks);
+    let tag_bar_height = u16::from(!tags.is_empty());
+    let main = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(tag_bar_height),
+        Constraint::Min(0),
+    ])
+    .split(outer[0]);
     let statuses = if app.done_loaded {
         let done_week_end = app
             .done_week_start
@@ -107,11 +475,15 @@ pub fn render(frame: &mut Frame, app: &App) {
             (TaskStatus::Doing, " DOING ".to_string()),
             (
                 TaskStatus::Done,
-                format!(
-                    " DONE {}-{} ",
-                    app.done_week_start.format(DEADLINE_DATE_FORMAT),
-                    done_week_end.format(DEADLINE_DATE_FORMAT)
-                ),
+                if app.done_loaded_for_search {
+                    " DONE (all weeks) ".to_string()
+                } else {
+                    format!(
+                        " DONE {}-{} ",
+                        app.done_week_start.format(DEADLINE_DATE_FORMAT),
+                        done_week_end.format(DEADLINE_DATE_FORMAT)
+                    )
+                },
             ),
         ]
     } else {
@@ -121,35 +493,305 @@ pub fn render(frame: &mut Frame, app: &App) {
             (TaskStatus::Doing, " DOING ".to_string()),
         ]
     };
-    let constraints = vec![Constraint::Ratio(1, statuses.len() as u32); statuses.len()];
-    let columns = Layout::horizontal(constraints).split(main[1]);
-    let today = Local::now().date_naive();
+    let now = Local::now().naive_local();
     let last_updated = Paragraph::new(format!(
         " last updated: {}",
-        app.last_updated_at.format(TASK_DATETIME_FORMAT)
+        format_time(app.last_updated_at, TASK_DATETIME_FORMAT, app.display_utc)
     ))
     .alignment(Alignment::Right);
     frame.render_widget(last_updated, main[0]);
+    let mut filters = Vec::new();
+    if !app.search_query.is_empty() {
+        filters.push(format!("/{}", app.search_query));
+    }
+    if app.created_range != (None, None) {
+        let (since, until) = app.created_range;
+        filters.push(format!("created {}", format_created_range(since, until)));
+    }
+    if !filters.is_empty() {
+        let search = Paragraph::new(format!(" {}", filters.join("  ")))
+            .style(Style::default().add_modifier(Modifier::BOLD));
+        frame.render_widget(search, main[0]);
+    }
+    frame.render_widget(
+        Paragraph::new(tag_bar(&tags, app.active_tag_filter.as_deref())),
+        main[1],
+    );
+    let board = if app.show_calendar {
+        let [board, calendar] =
+            Layout::horizontal([Constraint::Min(0), Constraint::Length(CALENDAR_WIDTH)])
+                .areas(main[2]);
+        render_calendar(frame, calendar, &app.tasks, now.date());
+        board
+    } else {
+        main[2]
+    };
+    match app.layout_mode {
+        LayoutMode::Columns => render_columns(frame, app, board, &statuses, now),
+        LayoutMode::Compact => render_compact(frame, app, board, &statuses, now),
+    }
+
+    if app.input_mode == Mode::Editing {
+        let default_title = format!(
+            "New {} Task{} (Enter: confirm, Tab: status, Esc: cancel)",
+            app.add_status.label(),
+            if app.add_after.is_some() {
+                " below the selection"
+            } else {
+                ""
+            }
+        );
+        let input_title = app.error_message.as_deref().unwrap_or(&default_title);
+        let input_style = app
+            .error_message
+            .as_ref()
+            .map_or_else(Style::default, |_| Style::default().fg(Color::Red));
+        render_input(
+            frame,
+            outer[1],
+            &app.input_buffer,
+            app.input_cursor,
+            input_title,
+            input_style,
+        );
+    } else if app.input_mode == Mode::Search {
+        render_input(
+            frame,
+            outer[1],
+            &app.search_query,
+            app.search_query.chars().count(),
+            "Search (Enter: apply, Esc: clear)",
+            Style::default(),
+        );
+    } else if app.input_mode == Mode::Reminder {
+        let default_title = "Reminder YYYY-MM-DD HH:MM (an existing time removes it; Esc: cancel)";
+        let input_title = app.error_message.as_deref().unwrap_or(default_title);
+        let input_style = app
+            .error_message
+            .as_ref()
+            .map_or_else(Style::default, |_| Style::default().fg(Color::Red));
+        render_input(
+            frame,
+            outer[1],
+            &app.input_buffer,
+            app.input_cursor,
+            input_title,
+            input_style,
+        );
+    } else if app.input_mode == Mode::Tag {
+        let default_title = format!(
+            "Tag for {} task(s) (-tag removes; Esc: cancel)",
+            app.tag_targets().len()
+        );
+        let input_title = app.error_message.as_deref().unwrap_or(&default_title);
+        let input_style = app
+            .error_message
+            .as_ref()
+            .map_or_else(Style::default, |_| Style::default().fg(Color::Red));
+        render_input(
+            frame,
+            outer[1],
+            &app.input_buffer,
+            app.input_cursor,
+            input_title,
+            input_style,
+        );
+    } else if app.input_mode == Mode::CreatedRange {
+        let default_title = "Created YYYY-MM-DD..YYYY-MM-DD (either end may be empty; Esc: cancel)";
+        let input_title = app.error_message.as_deref().unwrap_or(default_title);
+        let input_style = app
+            .error_message
+            .as_ref()
+            .map_or_else(Style::default, |_| Style::default().fg(Color::Red));
+        render_input(
+            frame,
+            outer[1],
+            &app.input_buffer,
+            app.input_cursor,
+            input_title,
+            input_style,
+        );
+    } else if app.input_mode == Mode::EditBody {
+        let (message, style) = app.error_message.as_deref().map_or(
+            (
+                " Enter: newline | arrows: move | Ctrl-S: save | Esc: cancel ",
+                Style::default(),
+            ),
+            |error| (error, Style::default().fg(Color::Red)),
+        );
+        frame.render_widget(Paragraph::new(message).style(style), outer[1]);
+    } else {
+        let shown_statuses = statuses
+            .iter()
+            .map(|(status, _)| *status)
+            .collect::<Vec<_>>();
+        let priority = match app.priority_filter {
+            Some(Priority::High) => "prio: high | ",
+            Some(Priority::Medium) => "prio: medium+ | ",
+            Some(Priority::Low) | None => "",
+        };
+        // The position gets its own right-aligned chunk so a long help page cannot push it
+        // off narrow terminals.
+        let indicator = format!("| {priority}{} ", position_indicator(app, &shown_statuses));
+        let [message_area, indicator_area] = Layout::horizontal([
+            Constraint::Min(0),
+            Constraint::Length(Line::from(indicator.as_str()).width() as u16),
+        ])
+        .areas(outer[1]);
+        let help = HELP_PAGES[app.help_page % HELP_PAGE_COUNT];
+        let (message, style) = app.error_message.as_deref().map_or_else(
+            || {
+                app.notification
+                    .as_deref()
+                    .map_or((help, Style::default()), |notification| {
+                        (notification, Style::default().fg(Color::Cyan))
+                    })
+            },
+            |error| (error, Style::default().fg(Color::Red)),
+        );
+        let help = Paragraph::new(message).style(style);
+        frame.render_widget(help, message_area);
+        frame.render_widget(Paragraph::new(indicator), indicator_area);
+    }
+
+    if app.input_mode == Mode::EditBody {
+        let title = app
+            .selected_index
+            .and_then(|index| app.tasks.get(index))
+            .map_or_else(String::new, |task| task.name.clone());
+        render_body_editor(frame, main[1], &app.body_buffer, app.body_cursor, &title);
+    }
+
+    if app.input_mode == Mode::Form {
+        render_form(frame, &app.form_fields, app.form_focus);
+    }
+
+    if let Some(action) = &app.pending_confirm {
+        render_confirm(
+            frame,
+            &action.prompt(app.config.confirm_key, &app.confirm_input),
+        );
+    }
+    if app.first_run && app.input_mode == Mode::Normal {
+        render_welcome(frame);
+    }
+    if app.show_help {
+        render_help(frame, app.help_scroll);
+    }
+    if let Some(stats) = &app.stats {
+        render_stats(frame, stats);
+    }
+    if !app.use_color {
+        strip_colors(frame.buffer_mut());
+    }
+}
+
+/// Builds the tag bar: every tag of the loaded tasks with its count, the active filter reversed.
+fn tag_bar(tags: &[(String, usize)], active: Option<&str>) -> Line<'static> {
+    let mut spans = vec![Span::raw(" ")];
+    for (tag, count) in tags {
+        let style = if active == Some(tag.as_str()) {
+            Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(Color::Blue)
+        };
+        spans.push(Span::styled(format!("#{tag} {count}"), style));
+        spans.push(Span::raw("  "));
+    }
+    Line::from(spans)
+}
+
+/// Builds the list item for one task, adding the time summaries under the selected task.
+fn task_item(
+    app: &App,
+    task: &Task,
+    is_selected: bool,
+    width: usize,
+    now: NaiveDateTime,
+) -> ListItem<'static> {
+    let mut text = task_text(
+        task,
+        width,
+        now.date(),
+        is_selected,
+        DisplayToggles {
+            show_ids: app.show_ids,
+            display_utc: app.display_utc,
+            marked: app.marked.contains(&task.id),
+        },
+        &app.search_query,
+        &app.config,
+    );
+    if is_selected {
+        if app.selected_changed_on_disk {
+            text.lines.push(Line::styled(
+                CHANGED_ON_DISK_NOTICE,
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        text.lines.extend(time_in_status_line(task, now));
+        text.lines.extend(time_spent_line(task, now));
+        text.lines
+            .extend(reminders_line(task, now, app.display_utc));
+    }
+    let item = ListItem::new(text);
+    if task.status == TaskStatus::Done {
+        item.style(done_task_style(app.config.done_style, is_selected))
+    } else {
+        item
+    }
+}
+
+/// Applies the selection highlight, or the `>` marker when colors are disabled.
+fn with_selection_highlight(list: List<'_>, use_color: bool) -> List<'_> {
+    if use_color {
+        list.highlight_style(Style::default().bg(Color::DarkGray))
+    } else {
+        list.highlight_symbol(SELECTION_MARKER)
+            .highlight_spacing(HighlightSpacing::Always)
+    }
+}
+
+fn selection_marker_width(app: &App) -> u16 {
+    if app.use_color {
+        0
+    } else {
+        SELECTION_MARKER.len() as u16
+    }
+}
 
+/// Renders one bordered column per status, side by side.
+fn render_columns(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    statuses: &[(TaskStatus, String)],
+    now: NaiveDateTime,
+) {
+    let shown_statuses = statuses
+        .iter()
+        .map(|(status, _)| *status)
+        .collect::<Vec<_>>();
+    let constraints = column_constraints(&shown_statuses, app.expanded_panel);
+    let columns = Layout::horizontal(constraints).split(area);
+    let selection_marker_width = selection_marker_width(app);
     for (column, ((status, title), area)) in statuses.iter().zip(columns.iter()).enumerate() {
         let mut selected_in_group: Option<usize> = None;
         let items: Vec<ListItem> = app
             .tasks
             .iter()
             .enumerate()
-            .filter(|(_, t)| t.status == *status)
+            .filter(|(_, t)| t.status == *status && app.is_visible(t))
             .enumerate()
             .map(|(group_idx, (global_idx, t))| {
                 let is_selected = app.selected_index == Some(global_idx);
                 if is_selected {
                     selected_in_group = Some(group_idx);
                 }
-                ListItem::new(task_text(
-                    t,
-                    area.width.saturating_sub(2) as usize,
-                    today,
-                    is_selected,
-                ))
+                let width = area.width.saturating_sub(2 + selection_marker_width) as usize;
+                task_item(app, t, is_selected, width, now)
             })
             .collect();
         let is_empty_done_selected =
@@ -159,70 +801,481 @@ pub fn render(frame: &mut Frame, app: &App) {
         } else {
             Style::default()
         };
-        let list = List::new(items)
-            .block(
-                Block::default()
-                    .title(title.as_str())
-                    .title_style(status_title_style(*status))
-                    .borders(Borders::ALL)
-                    .border_style(border_style),
-            )
-            .highlight_style(Style::default().bg(Color::DarkGray));
+        let block = Block::default()
+            .title(title.as_str())
+            .title_style(status_title_style(*status))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        if *status == TaskStatus::Done && items.is_empty() {
+            let empty = Paragraph::new("No completed tasks")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block);
+            frame.render_widget(empty, columns[column]);
+            continue;
+        }
+        let item_heights = items.iter().map(ListItem::height).collect::<Vec<_>>();
+        let list = with_selection_highlight(List::new(items).block(block), app.use_color);
         let mut state = ListState::default();
         state.select(selected_in_group);
         frame.render_stateful_widget(list, columns[column], &mut state);
+        render_scrollbar(frame, columns[column], &item_heights, state.offset());
     }
+}
 
-    if app.input_mode == Mode::Editing {
-        let cursor_prefix = app
-            .input_buffer
-            .chars()
-            .take(app.input_cursor)
-            .collect::<String>();
-        let cursor_width = Line::from(cursor_prefix.as_str()).width() as u16;
-        let input_width = outer[1].width.saturating_sub(2).max(1);
-        let horizontal_offset = cursor_width.saturating_sub(input_width.saturating_sub(1));
-        let input_title = app
-            .error_message
-            .as_deref()
-            .unwrap_or("New Task (Enter: confirm, Esc: cancel)");
-        let input_style = app
-            .error_message
-            .as_ref()
-            .map_or_else(Style::default, |_| Style::default().fg(Color::Red));
-        let input = Paragraph::new(app.input_buffer.as_str())
-            .block(
-                Block::default()
-                    .title(format!(" {input_title} "))
-                    .borders(Borders::ALL),
-            )
-            .style(input_style)
-            .scroll((0, horizontal_offset));
-        frame.render_widget(input, outer[1]);
-        frame.set_cursor_position((
-            outer[1].x + 1 + cursor_width.saturating_sub(horizontal_offset),
-            outer[1].y + 1,
-        ));
+/// Renders every status as one full-width list, each group introduced by its status title.
+///
+/// Navigation is unchanged; only the layout differs, giving task names the whole width.
+fn render_compact(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    statuses: &[(TaskStatus, String)],
+    now: NaiveDateTime,
+) {
+    let width = area.width.saturating_sub(2 + selection_marker_width(app)) as usize;
+    let mut items = Vec::new();
+    let mut selected = None;
+    for (status, title) in statuses {
+        items.push(ListItem::new(Line::styled(
+            title.clone(),
+            status_title_style(*status),
+        )));
+        let group_start = items.len();
+        for (index, task) in app
+            .tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, task)| task.status == *status && app.is_visible(task))
+        {
+            let is_selected = app.selected_index == Some(index);
+            if is_selected {
+                selected = Some(items.len());
+            }
+            items.push(task_item(app, task, is_selected, width, now));
+        }
+        if *status == TaskStatus::Done && items.len() == group_start {
+            items.push(ListItem::new(Line::styled(
+                "No completed tasks",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+    let item_heights = items.iter().map(ListItem::height).collect::<Vec<_>>();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green));
+    let list = with_selection_highlight(List::new(items).block(block), app.use_color);
+    let mut state = ListState::default();
+    state.select(selected);
+    frame.render_stateful_widget(list, area, &mut state);
+    render_scrollbar(frame, area, &item_heights, state.offset());
+}
+
+/// Draws a scrollbar over the right border of a panel whose items do not fit its height.
+///
+/// `offset` is the index of the first visible item, as left in the `ListState` by rendering.
+fn render_scrollbar(frame: &mut Frame, area: Rect, item_heights: &[usize], offset: usize) {
+    let inner_height = usize::from(area.height.saturating_sub(2));
+    let content_height = item_heights.iter().sum::<usize>();
+    if content_height <= inner_height {
+        return;
+    }
+    let scrolled_lines = item_heights.iter().take(offset).sum::<usize>();
+    let mut scrollbar_state = ScrollbarState::new(content_height - inner_height + 1)
+        .position(scrolled_lines)
+        .viewport_content_length(inner_height);
+    frame.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None),
+        area.inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        }),
+        &mut scrollbar_state,
+    );
+}
+
+/// Renders a single-line input field with the cursor kept in view by horizontal scrolling.
+fn render_input(
+    frame: &mut Frame,
+    area: Rect,
+    text: &str,
+    cursor: usize,
+    title: &str,
+    style: Style,
+) {
+    let cursor_prefix = text.chars().take(cursor).collect::<String>();
+    let cursor_width = Line::from(cursor_prefix.as_str()).width() as u16;
+    let input_width = area.width.saturating_sub(2).max(1);
+    let horizontal_offset = cursor_width.saturating_sub(input_width.saturating_sub(1));
+    let input = Paragraph::new(text)
+        .block(
+            Block::default()
+                .title(format!(" {title} "))
+                .borders(Borders::ALL),
+        )
+        .style(style)
+        .scroll((0, horizontal_offset));
+    frame.render_widget(input, area);
+    frame.set_cursor_position((
+        area.x + 1 + cursor_width.saturating_sub(horizontal_offset),
+        area.y + 1,
+    ));
+}
+
+/// Renders the multiline body editor over the task columns, scrolling to keep the cursor in view.
+fn render_body_editor(frame: &mut Frame, area: Rect, text: &str, cursor: usize, title: &str) {
+    let before_cursor = text.chars().take(cursor).collect::<String>();
+    let cursor_row = before_cursor.matches('\n').count() as u16;
+    let cursor_line = before_cursor.rsplit('\n').next().unwrap_or("");
+    let cursor_width = Line::from(cursor_line).width() as u16;
+    let inner_height = area.height.saturating_sub(2).max(1);
+    let inner_width = area.width.saturating_sub(2).max(1);
+    let vertical_offset = cursor_row.saturating_sub(inner_height - 1);
+    let horizontal_offset = cursor_width.saturating_sub(inner_width - 1);
+    let editor = Paragraph::new(text)
+        .block(
+            Block::default()
+                .title(format!(" {title} "))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Green)),
+        )
+        .scroll((vertical_offset, horizontal_offset));
+    frame.render_widget(Clear, area);
+    frame.render_widget(editor, area);
+    frame.set_cursor_position((
+        area.x + 1 + cursor_width - horizontal_offset,
+        area.y + 1 + cursor_row - vertical_offset,
+    ));
+}
+
+/// Counts the unfinished tasks due on each day of `today`'s month, indexed by day - 1.
+fn deadline_counts(tasks: &[Task], today: NaiveDate) -> Vec<usize> {
+    let first = today
+        .with_day(1)
+        .expect("day 1 should exist in every month");
+    let days = first
+        .checked_add_months(chrono::Months::new(1))
+        .map_or(31, |next| (next - first).num_days() as usize);
+    let mut counts = vec![0; days];
+    for task in tasks {
+        if task.status != TaskStatus::Done
+            && task.deadline.year() == first.year()
+            && task.deadline.month() == first.month()
+        {
+            counts[task.deadline.day0() as usize] += 1;
+        }
+    }
+    counts
+}
+
+/// Renders this month as a Monday-first grid, shading each day by how many tasks are due.
+fn render_calendar(frame: &mut Frame, area: Rect, tasks: &[Task], today: NaiveDate) {
+    let counts = deadline_counts(tasks, today);
+    let leading_blanks = today
+        .with_day(1)
+        .expect("day 1 should exist in every month")
+        .weekday()
+        .num_days_from_monday() as usize;
+    let cells = std::iter::repeat_n(None, leading_blanks)
+        .chain(counts.iter().enumerate().map(Some))
+        .collect::<Vec<_>>();
+    let mut lines = vec![Line::styled(
+        "Mo Tu We Th Fr Sa Su",
+        Style::default().fg(Color::Gray),
+    )];
+    lines.extend(cells.chunks(7).map(|week| {
+        Line::from(
+            week.iter()
+                .flat_map(|cell| {
+                    let Some((day0, count)) = cell else {
+                        return [Span::raw("  "), Span::raw(" ")];
+                    };
+                    let mut style = match count {
+                        0 => Style::default().fg(Color::DarkGray),
+                        count => Style::default()
+                            .fg(Color::White)
+                            .bg(CALENDAR_SHADES[(**count - 1).min(CALENDAR_SHADES.len() - 1)]),
+                    };
+                    if *day0 == today.day0() as usize {
+                        style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+                    }
+                    [
+                        Span::styled(format!("{:>2}", day0 + 1), style),
+                        Span::raw(" "),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        )
+    }));
+    let [area] = Layout::vertical([Constraint::Length(CALENDAR_HEIGHT)]).areas(area);
+    let calendar = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" Due {} ", today.format("%Y/%m")))
+            .borders(Borders::ALL),
+    );
+    frame.render_widget(calendar, area);
+}
+
+/// Formats the selection as `position/total` over the tasks shown in `statuses`, in board
+/// order; a search narrows the total. Without a selection the position is `-`.
+fn position_indicator(app: &App, statuses: &[TaskStatus]) -> String {
+    let mut total = 0;
+    let mut position = None;
+    for status in statuses {
+        for (index, task) in app.tasks.iter().enumerate() {
+            if task.status != *status || !app.is_visible(task) {
+                continue;
+            }
+            total += 1;
+            if app.selected_index == Some(index) {
+                position = Some(total);
+            }
+        }
+    }
+    match position {
+        Some(position) => format!("{position}/{total}"),
+        None => format!("-/{total}"),
+    }
+}
+
+/// Splits the width evenly, or gives the expanded column everything the collapsed ones leave.
+///
+/// An expanded status that is not shown (e.g. DONE after it was hidden) falls back to even widths.
+fn column_constraints(statuses: &[TaskStatus], expanded: Option<TaskStatus>) -> Vec<Constraint> {
+    match expanded.filter(|expanded| statuses.contains(expanded)) {
+        Some(expanded) => statuses
+            .iter()
+            .map(|status| {
+                if *status == expanded {
+                    Constraint::Min(0)
+                } else {
+                    Constraint::Length(COLLAPSED_COLUMN_WIDTH)
+                }
+            })
+            .collect(),
+        None => vec![Constraint::Ratio(1, statuses.len() as u32); statuses.len()],
+    }
+}
+
+/// Resets every foreground and background color so that only text and modifiers remain.
+fn strip_colors(buffer: &mut Buffer) {
+    buffer.content.iter_mut().for_each(|cell| {
+        cell.set_fg(Color::Reset).set_bg(Color::Reset);
+    });
+}
+
+/// Basics shown to a new user until the first key press or the first task.
+const WELCOME_TEXT: &str = "\
+  a        Add your first task
+  j / k    Move down / up within a status
+  h / l    Move between statuses
+  n / N    Move the task to the next / previous status
+  ?        Show every keybinding
+
+  Press any key to start";
+
+/// Renders the first-run welcome overlay as a centered popup.
+fn render_welcome(frame: &mut Frame) {
+    let lines = WELCOME_TEXT.lines().collect::<Vec<_>>();
+    let width = lines
+        .iter()
+        .map(|line| Line::from(*line).width() as u16 + 4)
+        .max()
+        .unwrap_or(0)
+        .min(frame.area().width);
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(layout::Flex::Center)
+        .areas(frame.area());
+    let [area] = Layout::vertical([Constraint::Length(lines.len() as u16 + 2)])
+        .flex(layout::Flex::Center)
+        .areas(area);
+    let popup = Paragraph::new(WELCOME_TEXT).block(
+        Block::default()
+            .title(" Welcome to rem ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+/// Renders the full keybinding reference as a centered popup.
+fn render_help(frame: &mut Frame, scroll: usize) {
+    let lines = cli::KEYBINDINGS.lines().collect::<Vec<_>>();
+    let width = lines
+        .iter()
+        .map(|line| Line::from(*line).width() as u16 + 4)
+        .max()
+        .unwrap_or(0)
+        .min(frame.area().width);
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(layout::Flex::Center)
+        .areas(frame.area());
+    let [area] = Layout::vertical([Constraint::Length(lines.len() as u16 + 2)])
+        .flex(layout::Flex::Center)
+        .areas(area);
+    // Scrolling stops once the last line is at the bottom of the popup.
+    let visible = usize::from(area.height.saturating_sub(2));
+    let scroll = scroll.min(lines.len().saturating_sub(visible));
+    let title = if visible < lines.len() {
+        format!(
+            " Keybindings {}-{}/{} (j/k: scroll, other keys close) ",
+            scroll + 1,
+            (scroll + visible).min(lines.len()),
+            lines.len()
+        )
     } else {
-        let (message, style) = app.error_message.as_deref().map_or_else(
-            || {
-                (
-                    " a: add | j/k: up/down | G/gg: bottom/top | h/l: left/right | n/N: status | r: reload | d: done | [/]: done week | q: quit ",
-                    Style::default(),
-                )
-            },
-            |error| (error, Style::default().fg(Color::Red)),
+        " Keybindings (any key to close) ".to_string()
+    };
+    let popup = Paragraph::new(cli::KEYBINDINGS)
+        .scroll((scroll as u16, 0))
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
         );
-        let help = Paragraph::new(message).style(style);
-        frame.render_widget(help, outer[1]);
-    }
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+/// Formats board statistics as the lines of the `T` overlay.
+fn stats_lines(stats: &Stats) -> Vec<String> {
+    let mut lines = TaskStatus::ALL
+        .iter()
+        .zip(stats.counts)
+        .map(|(status, count)| format!("{:<22}{count}", status.label()))
+        .collect::<Vec<_>>();
+    lines.extend([
+        String::new(),
+        format!("{:<22}{}", "Overdue", stats.overdue),
+        format!("{:<22}{}", "Due this week", stats.due_this_week),
+        format!(
+            "{:<22}{}",
+            "Average TODO age",
+            stats
+                .average_todo_age
+                .map_or_else(|| "-".to_string(), format_duration)
+        ),
+        format!("{:<22}{}", "Done in last 7 days", stats.done_last_7_days),
+        format!("{:<22}{}", "Done in last 30 days", stats.done_last_30_days),
+    ]);
+    lines
+}
+
+/// Renders the board statistics as a centered popup.
+fn render_stats(frame: &mut Frame, stats: &Stats) {
+    let lines = stats_lines(stats);
+    let title = " Statistics (any key to close) ";
+    let width = lines
+        .iter()
+        .map(|line| Line::from(line.as_str()).width())
+        .chain([title.len()])
+        .max()
+        .unwrap_or(0) as u16
+        + 4;
+    let [area] = Layout::horizontal([Constraint::Length(width.min(frame.area().width))])
+        .flex(layout::Flex::Center)
+        .areas(frame.area());
+    let [area] = Layout::vertical([Constraint::Length(lines.len() as u16 + 2)])
+        .flex(layout::Flex::Center)
+        .areas(area);
+    let popup = Paragraph::new(lines.join("\n")).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+}
+
+/// Width of the metadata form popup, wide enough for its title.
+const FORM_WIDTH: u16 = 60;
+
+/// Renders the metadata form as a centered popup with one labeled field per line,
+/// placing the cursor at the end of the focused field.
+fn render_form(frame: &mut Frame, fields: &[String], focus: usize) {
+    let label_width = FORM_LABELS
+        .iter()
+        .map(|label| label.len())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    let [area] = Layout::horizontal([Constraint::Length(FORM_WIDTH.min(frame.area().width))])
+        .flex(layout::Flex::Center)
+        .areas(frame.area());
+    let [area] = Layout::vertical([Constraint::Length(fields.len() as u16 + 2)])
+        .flex(layout::Flex::Center)
+        .areas(area);
+    let lines = FORM_LABELS
+        .iter()
+        .zip(fields)
+        .enumerate()
+        .map(|(index, (label, value))| {
+            let label_style = if index == focus {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            Line::from(vec![
+                Span::styled(format!("{label:<label_width$}"), label_style),
+                Span::raw(value.clone()),
+            ])
+        })
+        .collect::<Vec<_>>();
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Edit task (Tab: next field, Enter: save, Esc: cancel) ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Green)),
+    );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
+    let value_width = fields
+        .get(focus)
+        .map_or(0, |value| Line::from(value.as_str()).width());
+    frame.set_cursor_position((
+        (area.x + 1 + (label_width + value_width) as u16).min(area.right().saturating_sub(2)),
+        area.y + 1 + focus as u16,
+    ));
+}
+
+/// Renders a centered confirmation popup above the task columns.
+fn render_confirm(frame: &mut Frame, message: &str) {
+    let text = format!(" {message} ");
+    let width = (Line::from(text.as_str()).width() as u16 + 2).min(frame.area().width);
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(layout::Flex::Center)
+        .areas(frame.area());
+    let [area] = Layout::vertical([Constraint::Length(3)])
+        .flex(layout::Flex::Center)
+        .areas(area);
+    let popup = Paragraph::new(text).block(
+        Block::default()
+            .title(" Confirm ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+    frame.render_widget(Clear, area);
+    frame.render_widget(popup, area);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::task::Task;
+    use crate::store::TaskStore;
+    use crate::task::{StatusChange, Task};
     use ratatui::backend::TestBackend;
+    use ratatui::buffer::Buffer;
+
+    fn test_config() -> Config {
+        Config::with_tasks_dir(std::path::PathBuf::from("/tmp/rem-cli"))
+    }
 
     fn create_app(done_loaded: bool) -> App {
         App {
@@ -230,20 +1283,66 @@ mod tests {
             input_mode: Mode::Normal,
             input_buffer: String::new(),
             input_cursor: 0,
+            add_status: TaskStatus::Todo,
+            add_after: None,
             tasks: Vec::new(),
             selected_index: None,
             parking_loaded: false,
             done_loaded,
             done_week_start: Task::week_start(Local::now().date_naive()),
+            done_loaded_for_search: false,
+            done_week_before_search: false,
             last_updated_at: NaiveDate::from_ymd_opt(2026, 6, 15)
                 .unwrap()
                 .and_hms_opt(10, 30, 45)
                 .unwrap(),
-            open_file: None,
+            open_files: Vec::new(),
+            edit_config: false,
+            clipboard: None,
             error_message: None,
-            tasks_dir: Task::default_base_dir(),
+            notification: None,
+            pending_confirm: None,
+            confirm_input: String::new(),
+            show_ids: false,
+            display_utc: false,
+            expanded_panel: None,
+            layout_mode: LayoutMode::Columns,
+            use_color: true,
+            search_query: String::new(),
+            help_page: 0,
+            show_help: false,
+            help_scroll: 0,
+            stats: None,
+            first_run: false,
+            show_calendar: false,
+            body_buffer: String::new(),
+            body_cursor: 0,
+            form_fields: Default::default(),
+            form_focus: 0,
+            store: TaskStore::new(Task::default_base_dir()),
             persistent_error: None,
+            read_only: false,
             pending_g_at: None,
+            pending_count: None,
+            cut_task: None,
+            priority_filter: None,
+            active_tag_filter: None,
+            created_range: (None, None),
+            marked: Vec::new(),
+            jump_list: Vec::new(),
+            jump_cursor: 0,
+            last_action: None,
+            config: Config::with_tasks_dir(Task::default_base_dir()),
+            quit_armed: None,
+            pending_confirm_at: None,
+            saved_draft: String::new(),
+            draft_saved_at: None,
+            help_page_shown_at: std::time::Instant::now(),
+            reminders_checked_at: Local::now().naive_local(),
+            log: None,
+            pre_edit: None,
+            watched_file: None,
+            selected_changed_on_disk: false,
         }
     }
 
@@ -252,7 +1351,10 @@ mod tests {
         let mut terminal = Terminal::new(backend).unwrap();
         let app = create_app(done_loaded);
         terminal.draw(|frame| render(frame, &app)).unwrap();
-        let buffer = terminal.backend().buffer();
+        buffer_text(terminal.backend().buffer())
+    }
+
+    fn buffer_text(buffer: &Buffer) -> String {
         (0..buffer.area.height)
             .map(|y| {
                 (0..buffer.area.width)
@@ -265,12 +1367,136 @@ mod tests {
     }
 
     #[test]
-    fn renders_three_columns_without_done() {
-        // GIVEN
-        let expected_titles = ["PARKING", "TODO", "DOING"];
+    fn position_indicator_counts_visible_tasks_in_board_order() {
+        // GIVEN: a DOING task listed before a TODO task
+        let mut app = create_app(false);
+        let mut doing = Task::new("doing report".to_string());
+        doing.status = TaskStatus::Doing;
+        app.tasks = vec![
+            doing,
+            Task::new("todo report".to_string()),
+            Task::new("todo other".to_string()),
+        ];
+        app.selected_index = Some(0);
+        let statuses = [TaskStatus::Parking, TaskStatus::Todo, TaskStatus::Doing];
 
         // WHEN
-        let actual = rendered_text(false);
+        let selected = position_indicator(&app, &statuses);
+        app.search_query = "report".to_string();
+        let filtered = position_indicator(&app, &statuses);
+        app.selected_index = None;
+        let unselected = position_indicator(&app, &statuses);
+        app.tasks.clear();
+        let empty = position_indicator(&app, &statuses);
+
+        // THEN
+        assert_eq!(selected, "3/3");
+        assert_eq!(filtered, "2/2");
+        assert_eq!(unselected, "-/2");
+        assert_eq!(empty, "-/0");
+    }
+
+    #[test]
+    fn help_pages_and_keybindings_fit_80_columns() {
+        // GIVEN: the widest indicator next to the help line and the overlay's border and padding
+        let indicator = "| prio: medium+ | 99/99 ";
+        let overlay_frame = 4;
+
+        // WHEN
+        let too_wide = HELP_PAGES
+            .into_iter()
+            .filter(|page| Line::from(*page).width() + indicator.len() > 80)
+            .chain(
+                cli::KEYBINDINGS
+                    .lines()
+                    .filter(|line| Line::from(*line).width() + overlay_frame > 80),
+            )
+            .collect::<Vec<_>>();
+
+        // THEN
+        assert!(too_wide.is_empty(), "{too_wide:?}");
+    }
+
+    #[test]
+    fn help_overlay_scrolls_to_the_last_keybinding() {
+        // GIVEN: a terminal shorter than the keybinding list
+        let mut app = create_app(false);
+        app.show_help = true;
+        app.help_scroll = usize::MAX;
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+
+        // WHEN
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        // THEN
+        let text = buffer_text(terminal.backend().buffer());
+        let last = cli::KEYBINDINGS.lines().last().unwrap().trim();
+        let first = cli::KEYBINDINGS.lines().next().unwrap().trim();
+        assert!(text.contains(last), "{text}");
+        assert!(!text.contains(first));
+        assert!(text.contains("j/k: scroll"));
+    }
+
+    #[test]
+    fn position_indicator_stays_visible_at_80_columns() {
+        // GIVEN: the widest help page and a priority filter on an 80-column terminal
+        let mut app = create_app(false);
+        app.tasks = vec![
+            Task::new("first".to_string()),
+            Task::new("second".to_string()),
+        ];
+        app.selected_index = Some(1);
+        app.priority_filter = Some(Priority::Medium);
+        app.help_page = (0..HELP_PAGE_COUNT)
+            .max_by_key(|page| HELP_PAGES[*page].len())
+            .unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(80, 10)).unwrap();
+
+        // WHEN
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        // THEN
+        let text = buffer_text(terminal.backend().buffer());
+        let footer = text.lines().last().unwrap();
+        assert!(footer.ends_with("| prio: medium+ | 2/2 "), "{footer}");
+        assert!(footer.starts_with(&HELP_PAGES[app.help_page][..20]));
+    }
+
+    #[test]
+    fn tag_bar_appears_only_when_tasks_have_tags() {
+        // GIVEN
+        let render_tags = |tags: &[&[&str]]| {
+            let mut app = create_app(false);
+            app.tasks = tags
+                .iter()
+                .map(|tags| {
+                    let mut task = Task::new("tagged".to_string());
+                    task.tags = tags.iter().map(|tag| tag.to_string()).collect();
+                    task
+                })
+                .collect();
+            let mut terminal = Terminal::new(TestBackend::new(60, 10)).unwrap();
+            terminal.draw(|frame| render(frame, &app)).unwrap();
+            buffer_text(terminal.backend().buffer())
+        };
+
+        // WHEN
+        let tagged = render_tags(&[&["work", "home"], &["work"]]);
+        let untagged = render_tags(&[&[]]);
+
+        // THEN
+        let second_line = |text: &str| text.lines().nth(1).unwrap().to_string();
+        assert_eq!(second_line(&tagged).trim_end(), " #home 1  #work 2");
+        assert!(second_line(&untagged).contains("PARKING"));
+    }
+
+    #[test]
+    fn renders_three_columns_without_done() {
+        // GIVEN
+        let expected_titles = ["PARKING", "TODO", "DOING"];
+
+        // WHEN
+        let actual = rendered_text(false);
 
         // THEN
         assert!(expected_titles.iter().all(|title| actual.contains(title)));
@@ -336,6 +1562,90 @@ mod tests {
         assert_eq!(done_border.fg, Color::Green);
     }
 
+    #[test]
+    fn toggling_done_without_done_files_shows_empty_message() {
+        // GIVEN
+        let tasks_dir =
+            std::env::temp_dir().join(format!("rem-cli-render-test-{}", uuid::Uuid::new_v4()));
+        let mut app = App::with_tasks_dir(tasks_dir);
+        let backend = TestBackend::new(160, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        // WHEN
+        app.handle_key_event(crossterm::event::KeyCode::Char('d'));
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        // THEN
+        assert!(app.done_loaded);
+        let buffer = terminal.backend().buffer();
+        let actual = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)))
+                    .map(|cell| cell.symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(actual.contains("No completed tasks"));
+    }
+
+    /// Counts the rows in which every cell between `x_start` and `x_end` has the selection background.
+    fn highlighted_rows(buffer: &Buffer, x_start: u16, x_end: u16) -> usize {
+        (0..buffer.area.height)
+            .filter(|y| {
+                (x_start..x_end).all(|x| buffer.cell((x, *y)).unwrap().bg == Color::DarkGray)
+            })
+            .count()
+    }
+
+    #[test]
+    fn selection_highlight_covers_every_wrapped_line() {
+        // GIVEN: a selected task whose name wraps over three lines of a narrow TODO column
+        let mut app = create_app(false);
+        app.tasks = vec![
+            Task::new("a long task name that wraps over several lines".to_string()),
+            Task::new("next".to_string()),
+        ];
+        app.selected_index = Some(0);
+        let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+
+        // WHEN
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        // THEN: three name lines and the deadline line are highlighted, the next task is not
+        let buffer = terminal.backend().buffer();
+        assert_eq!(highlighted_rows(buffer, 21, 39), 4);
+        let text = buffer_text(buffer);
+        assert!(text.contains("eral lines"));
+        assert!(text.contains("next"));
+    }
+
+    #[test]
+    fn wrapped_selection_at_the_bottom_scrolls_fully_into_view() {
+        // GIVEN: the selected wrapped task is the last of a column taller than the panel
+        let mut app = create_app(false);
+        app.tasks = (0..6)
+            .map(|index| Task::new(format!("short {index}")))
+            .collect();
+        app.tasks.push(Task::new(
+            "a long task name that wraps over several lines".to_string(),
+        ));
+        app.selected_index = Some(6);
+        let mut terminal = Terminal::new(TestBackend::new(60, 12)).unwrap();
+
+        // WHEN
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        // THEN: every line of the selected task is visible and highlighted
+        let buffer = terminal.backend().buffer();
+        assert_eq!(highlighted_rows(buffer, 21, 39), 4);
+        let text = buffer_text(buffer);
+        assert!(text.contains("a long task name "));
+        assert!(text.contains("eral lines"));
+        assert!(!text.contains("short 0"));
+    }
+
     #[test]
     fn renders_navigation_help() {
         // GIVEN
@@ -348,6 +1658,131 @@ mod tests {
         assert!(actual.contains(expected));
     }
 
+    #[test]
+    fn no_color_renders_without_color_styles_and_marks_selection() {
+        // GIVEN
+        let mut app = create_app(true);
+        app.use_color = false;
+        app.tasks = vec![Task::new("selected task".to_string())];
+        app.selected_index = Some(0);
+        app.error_message = Some("shown in red with colors".to_string());
+        let mut terminal = Terminal::new(TestBackend::new(120, 10)).unwrap();
+
+        // WHEN
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        // THEN
+        let buffer = terminal.backend().buffer();
+        assert!(
+            buffer
+                .content
+                .iter()
+                .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset)
+        );
+        let text = buffer
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>();
+        assert!(text.contains("> selected task"));
+    }
+
+    #[test]
+    fn display_utc_converts_last_updated_header() {
+        // GIVEN
+        let mut app = create_app(false);
+        app.last_updated_at = NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let utc = Local
+            .from_local_datetime(&app.last_updated_at)
+            .earliest()
+            .unwrap()
+            .naive_utc();
+        let local_header = format!(
+            "last updated: {}",
+            app.last_updated_at.format(TASK_DATETIME_FORMAT)
+        );
+        let utc_header = format!("last updated: {} UTC", utc.format(TASK_DATETIME_FORMAT));
+        let mut terminal = Terminal::new(TestBackend::new(160, 10)).unwrap();
+        let header = |terminal: &Terminal<TestBackend>| {
+            terminal.backend().buffer().content[..160]
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+
+        // WHEN
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+        let before = header(&terminal);
+        app.display_utc = true;
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+        let after = header(&terminal);
+
+        // THEN
+        assert!(before.trim_end().ends_with(&local_header));
+        assert!(after.trim_end().ends_with(&utc_header));
+    }
+
+    #[test]
+    fn compact_layout_stacks_statuses_in_one_full_width_list() {
+        // GIVEN
+        let mut app = create_app(false);
+        app.layout_mode = LayoutMode::Compact;
+        let name = "a task name long enough to wrap inside one of three columns";
+        app.tasks = vec![Task::new(name.to_string())];
+        let mut terminal = Terminal::new(TestBackend::new(80, 14)).unwrap();
+
+        // WHEN
+        terminal.draw(|frame| render(frame, &app)).unwrap();
+
+        // THEN
+        let buffer = terminal.backend().buffer();
+        let rows = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)))
+                    .map(|cell| cell.symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        let row_of = |text: &str| rows.iter().position(|row| row.contains(text));
+        assert!(row_of(" PARKING ") < row_of(" TODO "));
+        assert!(row_of(" TODO ") < row_of(name));
+        assert!(row_of(name) < row_of(" DOING "));
+        assert!(rows.iter().all(|row| !row.contains(" DONE ")));
+    }
+
+    #[test]
+    fn scrollbar_appears_only_when_a_panel_overflows() {
+        // GIVEN
+        let rendered_with = |task_count: usize| {
+            let mut app = create_app(false);
+            app.tasks = (0..task_count)
+                .map(|index| Task::new(format!("task {index}")))
+                .collect();
+            app.selected_index = Some(0);
+            let mut terminal = Terminal::new(TestBackend::new(90, 12)).unwrap();
+            terminal.draw(|frame| render(frame, &app)).unwrap();
+            terminal
+                .backend()
+                .buffer()
+                .content
+                .iter()
+                .map(|cell| cell.symbol())
+                .collect::<String>()
+        };
+
+        // WHEN
+        let fitting = rendered_with(2);
+        let overflowing = rendered_with(8);
+
+        // THEN
+        assert!(!fitting.contains('█'));
+        assert!(overflowing.contains('█'));
+    }
+
     #[test]
     fn renders_last_updated_at_right_top() {
         // GIVEN
@@ -373,12 +1808,229 @@ mod tests {
         ]);
 
         // WHEN
-        let actual = wrap_task_name(task_name, width);
+        let actual = wrap_task_name(task_name, width, WrapMode::Char);
 
         // THEN
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn char_wrap_mode_breaks_words_at_width() {
+        // GIVEN
+        let width = 8;
+
+        // WHEN
+        let unbroken = wrap_task_name("https://example.com/x", width, WrapMode::Char);
+        let words = wrap_task_name("buy some milk", width, WrapMode::Char);
+
+        // THEN
+        assert_eq!(
+            unbroken,
+            Text::from(vec![
+                Line::from("https://"),
+                Line::from("example."),
+                Line::from("com/x"),
+            ])
+        );
+        assert_eq!(
+            words,
+            Text::from(vec![Line::from("buy some"), Line::from(" milk")])
+        );
+    }
+
+    #[test]
+    fn word_wrap_mode_breaks_between_words_and_splits_long_words() {
+        // GIVEN
+        let width = 8;
+
+        // WHEN
+        let unbroken = wrap_task_name("see https://example.com/x", width, WrapMode::Word);
+        let words = wrap_task_name("buy fresh milk", width, WrapMode::Word);
+        let wide = wrap_task_name("長いタスク 全文", width, WrapMode::Word);
+
+        // THEN
+        assert_eq!(
+            unbroken,
+            Text::from(vec![
+                Line::from("see"),
+                Line::from("https://"),
+                Line::from("example."),
+                Line::from("com/x"),
+            ])
+        );
+        assert_eq!(
+            words,
+            Text::from(vec![
+                Line::from("buy"),
+                Line::from("fresh"),
+                Line::from("milk")
+            ])
+        );
+        assert_eq!(
+            wide,
+            Text::from(vec![Line::from("長いタス"), Line::from("ク 全文"),])
+        );
+    }
+
+    #[test]
+    fn truncate_mode_keeps_one_line_ending_in_ellipsis() {
+        // GIVEN
+        let width = 8;
+
+        // WHEN
+        let unbroken = wrap_task_name("https://example.com/x", width, WrapMode::Truncate);
+        let words = wrap_task_name("buy some milk", width, WrapMode::Truncate);
+        let wide = wrap_task_name("長いタスクタイトル", width, WrapMode::Truncate);
+
+        // THEN
+        assert_eq!(unbroken, Text::from(Line::from("https:/…")));
+        assert_eq!(words, Text::from(Line::from("buy som…")));
+        assert_eq!(wide, Text::from(Line::from("長いタ…")));
+    }
+
+    #[test]
+    fn task_text_prefixes_project_name() {
+        // GIVEN
+        let mut task = Task::new("Fix bug".to_string());
+        task.project = Some("work".to_string());
+        let today = task.deadline;
+
+        // WHEN
+        let actual = task_text(
+            &task,
+            20,
+            today,
+            false,
+            DisplayToggles::default(),
+            "",
+            &test_config(),
+        );
+
+        // THEN
+        assert_eq!(actual.lines[0].to_string(), "work/ Fix bug");
+    }
+
+    #[test]
+    fn deadline_counts_tally_unfinished_tasks_per_day_of_month() {
+        // GIVEN
+        let today = NaiveDate::from_ymd_opt(2024, 2, 10).unwrap();
+        let due = |name: &str, day: u32, status: TaskStatus| {
+            let mut task = Task::new_with_status(
+                name.to_string(),
+                status,
+                std::path::PathBuf::from("/tmp/rem-cli"),
+            );
+            task.deadline = NaiveDate::from_ymd_opt(2024, 2, day).unwrap();
+            task
+        };
+        let mut next_month = due("march", 1, TaskStatus::Todo);
+        next_month.deadline = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let tasks = vec![
+            due("a", 1, TaskStatus::Todo),
+            due("b", 1, TaskStatus::Doing),
+            due("c", 29, TaskStatus::Parking),
+            due("finished", 1, TaskStatus::Done),
+            next_month,
+        ];
+
+        // WHEN
+        let counts = deadline_counts(&tasks, today);
+
+        // THEN
+        assert_eq!(counts.len(), 29);
+        assert_eq!(counts[0], 2);
+        assert_eq!(counts[28], 1);
+        assert_eq!(counts.iter().sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn inline_deadline_is_right_aligned_after_short_name() {
+        // GIVEN
+        let mut task = Task::new("Fix bug".to_string());
+        let today = task.deadline;
+        task.deadline = today.checked_add_days(Days::new(2)).unwrap();
+        let mut config = test_config();
+        config.deadline_style = DeadlineStyle::Inline;
+
+        // WHEN
+        let actual = task_text(
+            &task,
+            14,
+            today,
+            false,
+            DisplayToggles::default(),
+            "",
+            &config,
+        );
+
+        // THEN
+        let lines = actual
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, ["Fix bug     2d"]);
+    }
+
+    #[test]
+    fn inline_deadline_wraps_long_name_before_date_column() {
+        // GIVEN
+        let mut task = Task::new("長いタスク名前です".to_string());
+        let today = task.deadline;
+        task.deadline = today.pred_opt().unwrap();
+        let mut config = test_config();
+        config.deadline_style = DeadlineStyle::Inline;
+
+        // WHEN
+        let actual = task_text(
+            &task,
+            12,
+            today,
+            false,
+            DisplayToggles::default(),
+            "",
+            &config,
+        );
+
+        // THEN
+        let lines = actual
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, ["長いタス -1d", "ク名前で", "す"]);
+        assert!(actual.lines.iter().all(|line| line.width() <= 12));
+    }
+
+    #[test]
+    fn inline_deadline_falls_back_to_line_when_panel_is_too_narrow() {
+        // GIVEN
+        let task = Task::new("narrow".to_string());
+        let today = task.deadline;
+        let mut config = test_config();
+        config.deadline_style = DeadlineStyle::Inline;
+
+        // WHEN
+        let actual = task_text(
+            &task,
+            6,
+            today,
+            false,
+            DisplayToggles::default(),
+            "",
+            &config,
+        );
+
+        // THEN
+        assert_eq!(
+            actual.lines.last().map(ToString::to_string),
+            Some(format!(
+                "Deadline: {}",
+                task.deadline.format(DEADLINE_DATE_FORMAT)
+            ))
+        );
+    }
+
     #[test]
     fn task_text_displays_unselected_deadline_in_dark_gray() {
         // GIVEN
@@ -393,7 +2045,15 @@ mod tests {
         ]);
 
         // WHEN
-        let actual = task_text(&task, 20, today, false);
+        let actual = task_text(
+            &task,
+            20,
+            today,
+            false,
+            DisplayToggles::default(),
+            "",
+            &test_config(),
+        );
 
         // THEN
         assert_eq!(actual, expected);
@@ -413,7 +2073,15 @@ mod tests {
         ]);
 
         // WHEN
-        let actual = task_text(&task, 20, today, true);
+        let actual = task_text(
+            &task,
+            20,
+            today,
+            true,
+            DisplayToggles::default(),
+            "",
+            &test_config(),
+        );
 
         // THEN
         assert_eq!(actual, expected);
@@ -434,7 +2102,15 @@ mod tests {
         ]);
 
         // WHEN
-        let actual = task_text(&task, 20, today, false);
+        let actual = task_text(
+            &task,
+            20,
+            today,
+            false,
+            DisplayToggles::default(),
+            "",
+            &test_config(),
+        );
 
         // THEN
         assert_eq!(actual, expected);
@@ -454,12 +2130,322 @@ mod tests {
         let expected = format!("Completed: {}", completed_at.format(TASK_DATETIME_FORMAT));
 
         // WHEN
-        let actual = task_text(&task, 30, today, false);
+        let actual = task_text(
+            &task,
+            30,
+            today,
+            false,
+            DisplayToggles::default(),
+            "",
+            &test_config(),
+        );
 
         // THEN
         assert_eq!(actual.lines.last().unwrap().to_string(), expected);
     }
 
+    #[test]
+    fn task_text_prefixes_short_id_and_wraps_remaining_width() {
+        // GIVEN
+        let task = Task::new("abcdef".to_string());
+        let today = task.deadline;
+        let short_id = task.id.to_string()[..8].to_string();
+        let expected = [
+            format!("{short_id} abc"),
+            "         def".to_string(),
+            format!("Deadline: {}", task.deadline.format(DEADLINE_DATE_FORMAT)),
+        ];
+
+        // WHEN
+        let actual = task_text(
+            &task,
+            12,
+            today,
+            false,
+            DisplayToggles {
+                show_ids: true,
+                ..DisplayToggles::default()
+            },
+            "",
+            &test_config(),
+        );
+
+        // THEN
+        let actual = actual
+            .lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn format_duration_uses_two_largest_units() {
+        // GIVEN
+        let cases = [
+            (TimeDelta::seconds(30), "<1m"),
+            (TimeDelta::minutes(45), "45m"),
+            (TimeDelta::minutes(4 * 60 + 5), "4h 5m"),
+            (TimeDelta::hours(2 * 24 + 3), "2d 3h"),
+        ];
+
+        // WHEN
+        let actual = cases.map(|(duration, _)| format_duration(duration));
+
+        // THEN
+        let expected = cases.map(|(_, text)| text.to_string());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn format_duration_handles_durations_from_future_timestamps() {
+        // GIVEN
+        let cases = [
+            (-TimeDelta::seconds(30), "<1m"),
+            (-TimeDelta::minutes(1), "in the future"),
+            (-TimeDelta::days(400), "in the future"),
+        ];
+
+        // WHEN
+        let actual = cases.map(|(duration, _)| format_duration(duration));
+
+        // THEN
+        let expected = cases.map(|(_, text)| text.to_string());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn time_in_status_line_summarizes_each_status() {
+        // GIVEN
+        let at = |hour: u32| {
+            NaiveDate::from_ymd_opt(2026, 6, 15)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+        let mut task = Task::new("tracked".to_string());
+        task.history = vec![
+            StatusChange {
+                status: TaskStatus::Todo,
+                at: at(8),
+            },
+            StatusChange {
+                status: TaskStatus::Doing,
+                at: at(10),
+            },
+        ];
+
+        // WHEN
+        let actual = time_in_status_line(&task, at(13)).map(|line| line.to_string());
+
+        // THEN
+        assert_eq!(actual, Some("TODO 2h 0m | DOING 3h 0m".to_string()));
+    }
+
+    #[test]
+    fn time_in_status_line_is_hidden_before_first_transition() {
+        // GIVEN
+        let task = Task::new("new".to_string());
+
+        // WHEN
+        let actual = time_in_status_line(&task, task.created_at);
+
+        // THEN
+        assert_eq!(actual, None);
+    }
+
+    #[test]
+    fn reminders_line_sorts_reminders_and_highlights_next_one() {
+        // GIVEN
+        let mut task = Task::new("reminded".to_string());
+        let at = |day, hour| {
+            NaiveDate::from_ymd_opt(2026, 6, day)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+        task.reminders = vec![at(20, 9), at(10, 9), at(16, 18)];
+        let now = at(15, 12);
+
+        // WHEN
+        let line = reminders_line(&task, now, false).unwrap();
+
+        // THEN
+        let reminder_spans = line
+            .spans
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .map(|span| (span.content.to_string(), span.style.fg))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reminder_spans,
+            [
+                ("2026-06-10 09:00".to_string(), Some(Color::DarkGray)),
+                ("2026-06-16 18:00".to_string(), Some(Color::Yellow)),
+                ("2026-06-20 09:00".to_string(), Some(Color::Gray)),
+            ]
+        );
+        assert!(reminders_line(&Task::new("plain".to_string()), now, false).is_none());
+    }
+
+    #[test]
+    fn time_spent_line_adds_running_doing_visit() {
+        // GIVEN
+        let mut task = Task::new("tracked".to_string());
+        task.time_spent_secs = 3600;
+        task.started_at = Some(task.created_at);
+        let now = task.created_at + TimeDelta::minutes(10);
+
+        // WHEN
+        let actual = time_spent_line(&task, now).map(|line| line.to_string());
+
+        // THEN
+        assert_eq!(actual.as_deref(), Some("Time spent: 1h 10m"));
+    }
+
+    #[test]
+    fn task_text_marks_tasks_with_notes_and_wraps_remaining_width() {
+        // GIVEN
+        let tasks_dir =
+            std::env::temp_dir().join(format!("rem-cli-render-test-{}", uuid::Uuid::new_v4()));
+        let mut task = Task::new_in("abcdef".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        task.save_body("notes\n").unwrap();
+        let today = task.deadline;
+
+        // WHEN
+        let actual = task_text(
+            &task,
+            5,
+            today,
+            false,
+            DisplayToggles::default(),
+            "",
+            &test_config(),
+        );
+
+        // THEN
+        let actual = actual
+            .lines
+            .iter()
+            .take(2)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, ["* abc", "  def"]);
+
+        std::fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn task_text_shows_checklist_progress_before_name() {
+        // GIVEN
+        let tasks_dir =
+            std::env::temp_dir().join(format!("rem-cli-render-test-{}", uuid::Uuid::new_v4()));
+        let mut task = Task::new_in("ship".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        task.save_body("- [x] build\n- [ ] test\n- [x] tag\n")
+            .unwrap();
+        let today = task.deadline;
+
+        // WHEN
+        let actual = task_text(
+            &task,
+            20,
+            today,
+            false,
+            DisplayToggles::default(),
+            "",
+            &test_config(),
+        );
+
+        // THEN
+        assert_eq!(actual.lines[0].to_string(), "* (2/3) ship");
+
+        std::fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn highlight_query_emphasizes_single_match() {
+        // GIVEN
+        let emphasized = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        let expected = vec![
+            Span::raw("write "),
+            Span::styled("Report", emphasized),
+            Span::raw(" today"),
+        ];
+
+        // WHEN
+        let actual = highlight_query("write Report today", "report");
+
+        // THEN
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn highlight_query_without_match_keeps_plain_text() {
+        // GIVEN
+        let expected = vec![Span::raw("buy milk")];
+
+        // WHEN
+        let actual = highlight_query("buy milk", "report");
+
+        // THEN
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn done_task_style_keeps_selected_task_undimmed() {
+        // GIVEN
+        let cases = [
+            (DoneStyle::DimStrikethrough, false),
+            (DoneStyle::DimStrikethrough, true),
+            (DoneStyle::Dim, false),
+            (DoneStyle::Plain, false),
+        ];
+
+        // WHEN
+        let actual = cases
+            .map(|(done_style, is_selected)| done_task_style(done_style, is_selected).add_modifier);
+
+        // THEN
+        let expected = [
+            Modifier::DIM | Modifier::CROSSED_OUT,
+            Modifier::CROSSED_OUT,
+            Modifier::DIM,
+            Modifier::empty(),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn column_constraints_give_expanded_column_the_remaining_width() {
+        // GIVEN
+        let statuses = [TaskStatus::Parking, TaskStatus::Todo, TaskStatus::Doing];
+        let collapsed = Constraint::Length(COLLAPSED_COLUMN_WIDTH);
+        let even = Constraint::Ratio(1, 3);
+
+        // WHEN
+        let actual = [
+            None,
+            Some(TaskStatus::Parking),
+            Some(TaskStatus::Todo),
+            Some(TaskStatus::Doing),
+            Some(TaskStatus::Done),
+        ]
+        .map(|expanded| column_constraints(&statuses, expanded));
+
+        // THEN
+        let expected = [
+            vec![even, even, even],
+            vec![Constraint::Min(0), collapsed, collapsed],
+            vec![collapsed, Constraint::Min(0), collapsed],
+            vec![collapsed, collapsed, Constraint::Min(0)],
+            vec![even, even, even],
+        ];
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn status_titles_have_expected_background_colors() {
         // GIVEN
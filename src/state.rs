@@ -0,0 +1,147 @@
+use crate::app::LayoutMode;
+use crate::task::{Priority, TaskStatus};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const STATE_FILE_NAME: &str = ".rem-state.yaml";
+const BACKUP_EXTENSION: &str = "yaml.bak";
+
+/// Values that rem keeps between sessions, stored next to the status directories.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct State {
+    /// The sequence number assigned to the next task when `sequential_ids` is enabled.
+    #[serde(default)]
+    pub next_seq: u64,
+    /// Set once the first-run welcome overlay has been dismissed.
+    #[serde(default)]
+    pub onboarded: bool,
+    /// View preferences restored when the TUI starts; saved when it quits.
+    #[serde(default)]
+    pub view: ViewPreferences,
+}
+
+/// Display toggles that stick across sessions. The search query is deliberately not among them.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct ViewPreferences {
+    pub show_ids: bool,
+    pub display_utc: bool,
+    pub layout_mode: LayoutMode,
+    pub expanded_panel: Option<TaskStatus>,
+    pub show_calendar: bool,
+    /// Whether this week's DONE tasks are shown (`d`).
+    pub show_done: bool,
+    pub priority_filter: Option<Priority>,
+}
+
+impl State {
+    /// Returns the state file path under the task storage directory.
+    pub fn path(tasks_dir: &Path) -> PathBuf {
+        tasks_dir.join(STATE_FILE_NAME)
+    }
+
+    /// Loads the state file, returning the default state when it is missing or unusable.
+    pub fn load(tasks_dir: &Path) -> Self {
+        Self::load_or_recover(tasks_dir).0
+    }
+
+    /// Loads the state file like [`State::load`], also returning where a corrupt file was moved.
+    ///
+    /// A file that cannot be parsed is renamed to `.rem-state.yaml.bak` before starting over with
+    /// the defaults, so that a partial write never blocks startup and nothing is silently lost.
+    pub fn load_or_recover(tasks_dir: &Path) -> (Self, Option<PathBuf>) {
+        let path = Self::path(tasks_dir);
+        let Ok(content) = fs::read_to_string(&path) else {
+            return (Self::default(), None);
+        };
+        match serde_yaml::from_str(&content) {
+            Ok(state) => (state, None),
+            Err(_) => {
+                let backup_path = path.with_extension(BACKUP_EXTENSION);
+                let backup = fs::rename(&path, &backup_path).ok().map(|()| backup_path);
+                (Self::default(), backup)
+            }
+        }
+    }
+
+    /// Writes the state file, creating the task storage directory if needed.
+    pub fn save(&self, tasks_dir: &Path) -> io::Result<()> {
+        fs::create_dir_all(tasks_dir)?;
+        let yaml = serde_yaml::to_string(self).map_err(io::Error::other)?;
+        fs::write(Self::path(tasks_dir), yaml)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn temporary_tasks_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("rem-cli-state-test-{}", Uuid::new_v4()))
+    }
+
+    #[test]
+    fn missing_state_file_loads_default_state() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+
+        // WHEN
+        let actual = State::load(&tasks_dir);
+
+        // THEN
+        assert_eq!(actual, State::default());
+        assert!(!tasks_dir.exists());
+    }
+
+    #[test]
+    fn saved_state_is_loaded_back() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let expected = State {
+            next_seq: 42,
+            onboarded: true,
+            view: ViewPreferences {
+                show_ids: true,
+                layout_mode: LayoutMode::Compact,
+                expanded_panel: Some(TaskStatus::Doing),
+                priority_filter: Some(Priority::High),
+                ..ViewPreferences::default()
+            },
+        };
+
+        // WHEN
+        expected.save(&tasks_dir).unwrap();
+        let actual = State::load(&tasks_dir);
+
+        // THEN
+        assert_eq!(actual, expected);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn malformed_state_file_is_backed_up_and_replaced_by_defaults() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        fs::create_dir_all(&tasks_dir).unwrap();
+        fs::write(State::path(&tasks_dir), "next_seq: [unterminated").unwrap();
+        let expected_backup = tasks_dir.join(".rem-state.yaml.bak");
+
+        // WHEN
+        let (actual, backup) = State::load_or_recover(&tasks_dir);
+
+        // THEN
+        assert_eq!(actual, State::default());
+        assert_eq!(backup.as_deref(), Some(expected_backup.as_path()));
+        assert_eq!(
+            fs::read_to_string(&expected_backup).unwrap(),
+            "next_seq: [unterminated"
+        );
+        assert!(!State::path(&tasks_dir).exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+}
@@ -0,0 +1,147 @@
+use crate::task::{Task, TaskStatus};
+use chrono::{Days, NaiveDateTime, TimeDelta};
+
+/// Board summary shown in the statistics overlay (`T`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    /// Number of tasks in each status, in the order of [`TaskStatus::ALL`].
+    pub counts: [usize; 4],
+    /// Unfinished tasks whose deadline has passed.
+    pub overdue: usize,
+    /// Unfinished tasks due from today through the end of this week (Sunday).
+    pub due_this_week: usize,
+    /// Mean time since TODO tasks were created; `None` without TODO tasks. Tasks created
+    /// in the future count as created just now.
+    pub average_todo_age: Option<TimeDelta>,
+    /// DONE tasks completed within the last 7 days.
+    pub done_last_7_days: usize,
+    /// DONE tasks completed within the last 30 days.
+    pub done_last_30_days: usize,
+}
+
+/// Summarizes `tasks` as of `now`.
+///
+/// DONE tasks count as completed at `completed_at`, or at `updated_at` for files written
+/// before completion times were recorded.
+pub fn compute_stats(tasks: &[Task], now: NaiveDateTime) -> Stats {
+    let today = now.date();
+    let week_end = Task::week_start(today)
+        .checked_add_days(Days::new(6))
+        .unwrap_or(today);
+    let unfinished = || tasks.iter().filter(|task| task.status != TaskStatus::Done);
+    let completed_since = |days| {
+        let since = now - TimeDelta::days(days);
+        tasks
+            .iter()
+            .filter(|task| task.status == TaskStatus::Done)
+            .filter(|task| task.completed_at.unwrap_or(task.updated_at) >= since)
+            .count()
+    };
+    let todo_ages = tasks
+        .iter()
+        .filter(|task| task.status == TaskStatus::Todo)
+        .map(|task| (now - task.created_at).max(TimeDelta::zero()))
+        .collect::<Vec<_>>();
+    Stats {
+        counts: TaskStatus::ALL
+            .map(|status| tasks.iter().filter(|task| task.status == status).count()),
+        overdue: unfinished().filter(|task| task.deadline < today).count(),
+        due_this_week: unfinished()
+            .filter(|task| (today..=week_end).contains(&task.deadline))
+            .count(),
+        average_todo_age: (!todo_ages.is_empty())
+            .then(|| todo_ages.iter().sum::<TimeDelta>() / todo_ages.len() as i32),
+        done_last_7_days: completed_since(7),
+        done_last_30_days: completed_since(30),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use std::path::PathBuf;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 6, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn task(status: TaskStatus, created_day: u32, deadline_day: u32) -> Task {
+        let mut task = Task::new_in("task".to_string(), PathBuf::from("/tmp/rem-cli-stats"));
+        task.status = status;
+        task.created_at = at(created_day, 12);
+        task.updated_at = task.created_at;
+        task.deadline = NaiveDate::from_ymd_opt(2026, 6, deadline_day).unwrap();
+        task
+    }
+
+    fn done(completed_at: NaiveDateTime) -> Task {
+        let mut task = task(TaskStatus::Done, 1, 1);
+        task.completed_at = Some(completed_at);
+        task
+    }
+
+    #[test]
+    fn stats_summarize_counts_deadlines_ages_and_completions() {
+        // GIVEN: Wednesday 2026-06-17 noon; the week ends on Sunday the 21st
+        let now = at(17, 12);
+        let tasks = vec![
+            task(TaskStatus::Parking, 17, 30),
+            task(TaskStatus::Todo, 15, 16),
+            task(TaskStatus::Todo, 13, 21),
+            task(TaskStatus::Doing, 10, 17),
+            task(TaskStatus::Doing, 10, 22),
+            done(at(16, 12)),
+            done(at(5, 12)),
+            done(at(1, 12) - TimeDelta::days(20)),
+        ];
+
+        // WHEN
+        let actual = compute_stats(&tasks, now);
+
+        // THEN
+        assert_eq!(
+            actual,
+            Stats {
+                counts: [1, 2, 2, 3],
+                overdue: 1,
+                due_this_week: 2,
+                average_todo_age: Some(TimeDelta::days(3)),
+                done_last_7_days: 1,
+                done_last_30_days: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn overdue_done_tasks_and_missing_todo_are_not_counted() {
+        // GIVEN
+        let tasks = vec![task(TaskStatus::Done, 1, 2)];
+
+        // WHEN
+        let actual = compute_stats(&tasks, at(17, 12));
+
+        // THEN
+        assert_eq!(actual.overdue, 0);
+        assert_eq!(actual.average_todo_age, None);
+        assert_eq!(actual.done_last_30_days, 1);
+    }
+
+    #[test]
+    fn todo_tasks_created_in_the_future_count_as_fresh() {
+        // GIVEN: one task created a minute ahead and one far ahead of now
+        let now = at(17, 12);
+        let mut slightly = task(TaskStatus::Todo, 17, 20);
+        slightly.created_at = now + TimeDelta::minutes(1);
+        let far = task(TaskStatus::Todo, 30, 30);
+
+        // WHEN
+        let actual = compute_stats(&[slightly, far], now);
+
+        // THEN
+        assert_eq!(actual.average_todo_age, Some(TimeDelta::zero()));
+    }
+}
@@ -0,0 +1,261 @@
+use crate::frontmatter::FrontmatterFormat;
+use crate::state::State;
+use crate::task::{Task, TaskStatus};
+use chrono::{NaiveDate, NaiveDateTime};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The task files under one storage directory.
+///
+/// Every filesystem operation the TUI performs on tasks goes through a store, so pointing the
+/// store at another root (a `--dir` board or a temporary directory in tests) isolates it completely.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskStore {
+    root: PathBuf,
+    frontmatter_format: FrontmatterFormat,
+}
+
+impl TaskStore {
+    /// Creates a store rooted at the given task storage directory.
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            frontmatter_format: FrontmatterFormat::default(),
+        }
+    }
+
+    /// Sets the frontmatter format of tasks created by this store; existing files keep theirs.
+    pub fn with_frontmatter_format(mut self, format: FrontmatterFormat) -> Self {
+        self.frontmatter_format = format;
+        self
+    }
+
+    /// Returns the task storage directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Creates the status directories if they do not exist.
+    pub fn ensure_dirs(&self) -> io::Result<()> {
+        Task::ensure_dirs(&self.root)
+    }
+
+    /// Checks that files can be created in the task directory by writing and removing a
+    /// probe file.
+    ///
+    /// A missing directory passes, since it is created by the first save.
+    pub fn check_writable(&self) -> io::Result<()> {
+        if !self.root.is_dir() {
+            return Ok(());
+        }
+        let probe = self
+            .root
+            .join(format!(".rem-write-test.{}", std::process::id()));
+        fs::write(&probe, "")?;
+        fs::remove_file(probe)
+    }
+
+    /// Creates an unsaved TODO task that belongs to this store.
+    pub fn create(&self, name: String) -> Task {
+        self.create_with_status(name, TaskStatus::Todo)
+    }
+
+    /// Creates an unsaved task in the given status that belongs to this store.
+    pub fn create_with_status(&self, name: String, status: TaskStatus) -> Task {
+        let mut task = Task::new_with_status(name, status, self.root.clone());
+        task.frontmatter_format = self.frontmatter_format;
+        task
+    }
+
+    /// Writes the task file into the status directory of this store.
+    pub fn save(&self, task: &Task) -> io::Result<()> {
+        self.check_owner(task)?;
+        task.save()
+    }
+
+    /// Loads tasks in every status, sorted for display.
+    pub fn load_all(&self) -> io::Result<Vec<Task>> {
+        Task::all_from(&self.root)
+    }
+
+    /// Loads tasks in the given statuses.
+    pub fn load_by_status(&self, statuses: &[TaskStatus]) -> io::Result<Vec<Task>> {
+        Task::load_by_status(&self.root, statuses)
+    }
+
+    /// Loads DONE tasks completed in the week starting at `week_start`.
+    pub fn load_done_for_week(&self, week_start: NaiveDate) -> io::Result<Vec<Task>> {
+        Task::load_done_for_week_from(&self.root, week_start)
+    }
+
+    /// Counts task files in the given status without parsing them.
+    pub fn count(&self, status: TaskStatus) -> io::Result<usize> {
+        Task::count_by_status(&self.root, status)
+    }
+
+    /// Lists task files that are symlinks pointing outside this store.
+    pub fn external_links(&self) -> io::Result<Vec<PathBuf>> {
+        Task::external_links_from(&self.root)
+    }
+
+    /// Moves the task file to the directory of the new status.
+    pub fn move_status(&self, task: &mut Task, status: TaskStatus) -> io::Result<()> {
+        self.check_owner(task)?;
+        task.update_status(status)
+    }
+
+    /// Gives the task a fresh id and renames its file accordingly.
+    pub fn reassign_id(&self, task: &mut Task) -> io::Result<()> {
+        self.check_owner(task)?;
+        task.reassign_id()
+    }
+
+    /// Returns the next sequential id and advances the counter in the state file.
+    ///
+    /// The counter skips past `known_max`, the largest id among loaded tasks, so that
+    /// hand-edited files cannot cause collisions.
+    pub fn take_next_seq(&self, known_max: Option<u64>) -> io::Result<u64> {
+        let mut state = State::load(&self.root);
+        let seq = state
+            .next_seq
+            .max(known_max.map_or(1, |max| max + 1))
+            .max(1);
+        state.next_seq = seq + 1;
+        state.save(&self.root)?;
+        Ok(seq)
+    }
+
+    /// Moves every DONE task into `archive/`, returning how many were archived.
+    pub fn archive_done(&self) -> io::Result<usize> {
+        Task::archive_done_from(&self.root)
+    }
+
+    /// Moves DONE tasks completed before `cutoff` into `archive/`, returning how many were archived.
+    pub fn archive_done_before(&self, cutoff: NaiveDateTime) -> io::Result<usize> {
+        Task::archive_done_before(&self.root, cutoff)
+    }
+
+    /// Permanently deletes every DONE task, returning how many were deleted.
+    pub fn purge_done(&self) -> io::Result<usize> {
+        Task::purge_done_from(&self.root)
+    }
+
+    /// Deletes the task file.
+    pub fn delete(&self, task: &Task) -> io::Result<()> {
+        self.check_owner(task)?;
+        fs::remove_file(task.file_path())
+    }
+
+    fn check_owner(&self, task: &Task) -> io::Result<()> {
+        if task.base_dir() == self.root {
+            return Ok(());
+        }
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "task {} belongs to {}, not {}",
+                task.id,
+                task.base_dir().display(),
+                self.root.display()
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn temporary_store() -> TaskStore {
+        TaskStore::new(std::env::temp_dir().join(format!("rem-cli-store-test-{}", Uuid::new_v4())))
+    }
+
+    #[test]
+    fn saved_task_moves_between_statuses_and_is_deleted() {
+        // GIVEN
+        let store = temporary_store();
+        let mut task = store.create("stored".to_string());
+        store.save(&task).unwrap();
+
+        // WHEN
+        store.move_status(&mut task, TaskStatus::Doing).unwrap();
+        let moved = store.load_all().unwrap();
+        store.delete(&task).unwrap();
+        let deleted = store.load_all().unwrap();
+
+        // THEN
+        assert_eq!(moved.len(), 1);
+        assert_eq!(moved[0].status, TaskStatus::Doing);
+        assert!(deleted.is_empty());
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn check_writable_fails_for_read_only_directory() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // GIVEN
+        let store = temporary_store();
+        store.ensure_dirs().unwrap();
+        assert!(store.check_writable().is_ok());
+        fs::set_permissions(store.root(), fs::Permissions::from_mode(0o555)).unwrap();
+
+        // WHEN
+        let result = store.check_writable();
+
+        // THEN: unless permissions are bypassed, as for root
+        let bypassed = fs::write(store.root().join("bypass"), "").is_ok();
+        assert!(bypassed || result.is_err());
+        fs::set_permissions(store.root(), fs::Permissions::from_mode(0o755)).unwrap();
+        let leftovers: Vec<_> = fs::read_dir(store.root())
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(".rem-write-test")
+            })
+            .collect();
+        assert!(leftovers.is_empty());
+
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn stores_with_different_roots_are_independent() {
+        // GIVEN
+        let first = temporary_store();
+        let second = temporary_store();
+        first.save(&first.create("first".to_string())).unwrap();
+
+        // WHEN
+        let actual = second.load_by_status(&TaskStatus::ALL).unwrap();
+
+        // THEN
+        assert!(actual.is_empty());
+        assert_eq!(first.count(TaskStatus::Todo).unwrap(), 1);
+
+        fs::remove_dir_all(first.root()).unwrap();
+    }
+
+    #[test]
+    fn task_from_another_store_is_rejected() {
+        // GIVEN
+        let first = temporary_store();
+        let second = temporary_store();
+        let task = first.create("foreign".to_string());
+
+        // WHEN
+        let result = second.save(&task);
+
+        // THEN
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        assert!(!first.root().exists());
+        assert!(!second.root().exists());
+    }
+}
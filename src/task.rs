@@ -1,16 +1,25 @@
-use chrono::{DateTime, Datelike, Days, Local, NaiveDate, NaiveDateTime};
+use crate::frontmatter::{self, FrontmatterFormat};
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate, NaiveDateTime, TimeDelta};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::io;
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
+use std::thread;
 use uuid::Uuid;
 
 pub const DEADLINE_DATE_FORMAT: &str = "%Y/%m/%d";
 pub const TASK_DATETIME_FORMAT: &str = "%Y/%m/%d %H:%M:%S";
+/// Format in which reminders are typed and displayed.
+pub const REMINDER_FORMAT: &str = "%Y-%m-%d %H:%M";
+/// Number of task files from which `Task::load_by_status` parses on several threads.
+const PARALLEL_LOAD_THRESHOLD: usize = 256;
 const LEGACY_DEADLINE_DATE_FORMAT: &str = "%Y-%m-%d";
+const ARCHIVE_DIR_NAME: &str = "archive";
 
 /// Represents the lifecycle status of a task.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum TaskStatus {
     Parking,
     Todo,
@@ -19,8 +28,69 @@ pub enum TaskStatus {
 }
 
 impl TaskStatus {
+    /// All statuses in lifecycle order.
+    pub const ALL: [TaskStatus; 4] = [
+        TaskStatus::Parking,
+        TaskStatus::Todo,
+        TaskStatus::Doing,
+        TaskStatus::Done,
+    ];
+
+    /// Returns the position of this status in lifecycle order (`PARKING` is 0, `DONE` is 3).
+    pub fn status_rank(&self) -> usize {
+        match self {
+            TaskStatus::Parking => 0,
+            TaskStatus::Todo => 1,
+            TaskStatus::Doing => 2,
+            TaskStatus::Done => 3,
+        }
+    }
+
+    /// Returns the status after this one in `flow`, the ordered statuses that `n` walks through.
+    ///
+    /// A status missing from `flow` moves to the first listed status later in lifecycle order.
+    /// Returns `None` at the end of the flow.
+    pub fn next_in(self, flow: &[TaskStatus]) -> Option<TaskStatus> {
+        match flow.iter().position(|status| *status == self) {
+            Some(position) => flow.get(position + 1).copied(),
+            None => flow
+                .iter()
+                .find(|status| status.status_rank() > self.status_rank())
+                .copied(),
+        }
+    }
+
+    /// Returns the status before this one in `flow`; the mirror image of [`TaskStatus::next_in`].
+    pub fn previous_in(self, flow: &[TaskStatus]) -> Option<TaskStatus> {
+        match flow.iter().position(|status| *status == self) {
+            Some(position) => position.checked_sub(1).map(|previous| flow[previous]),
+            None => flow
+                .iter()
+                .rev()
+                .find(|status| status.status_rank() < self.status_rank())
+                .copied(),
+        }
+    }
+
+    /// Returns the uppercase label shown in column titles (e.g. `"TODO"`).
+    pub fn label(&self) -> &'static str {
+        match self {
+            TaskStatus::Parking => "PARKING",
+            TaskStatus::Todo => "TODO",
+            TaskStatus::Doing => "DOING",
+            TaskStatus::Done => "DONE",
+        }
+    }
+
+    /// Parses a status from its directory name, ignoring case (e.g. `"todo"` or `"TODO"`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.dir_name().eq_ignore_ascii_case(name))
+    }
+
     /// Returns the directory name corresponding to this status (e.g. `"todo"`, `"doing"`, `"done"`).
-    fn dir_name(&self) -> &str {
+    pub fn dir_name(&self) -> &'static str {
         match self {
             TaskStatus::Parking => "parking",
             TaskStatus::Todo => "todo",
@@ -30,6 +100,39 @@ impl TaskStatus {
     }
 }
 
+/// How urgent a task is, set by editing `priority` in the frontmatter.
+///
+/// Ordered from `Low` to `High`; a task without a priority counts as [`Priority::Medium`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    /// Returns the lowercase label used in the frontmatter (e.g. `"high"`).
+    pub fn label(&self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+        }
+    }
+}
+
+/// A status the task entered and when it entered it.
+///
+/// Stored as `entered` so that the frontmatter never carries a `status` key;
+/// the current status is always the directory the file lives in.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StatusChange {
+    #[serde(rename = "entered")]
+    pub status: TaskStatus,
+    pub at: NaiveDateTime,
+}
+
 /// Internal representation of the YAML frontmatter stored in each task's markdown file.
 ///
 /// Does not include `status`, which is determined by the directory the file resides in.
@@ -43,6 +146,29 @@ struct TaskFrontmatter {
     completed_at: Option<NaiveDateTime>,
     #[serde(default)]
     deadline: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    order: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seq: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    history: Vec<StatusChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    started_at: Option<NaiveDateTime>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    time_spent_secs: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    reminders: Vec<NaiveDateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    priority: Option<Priority>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    /// Fields this version does not know, written back unchanged.
+    #[serde(flatten)]
+    extra: serde_yaml::Mapping,
+}
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
 }
 
 #[derive(Deserialize)]
@@ -55,6 +181,24 @@ struct StoredTaskFrontmatter {
     completed_at: Option<String>,
     #[serde(default)]
     deadline: Option<String>,
+    #[serde(default)]
+    order: Option<u32>,
+    #[serde(default)]
+    seq: Option<u64>,
+    #[serde(default)]
+    history: Vec<StatusChange>,
+    #[serde(default)]
+    started_at: Option<NaiveDateTime>,
+    #[serde(default)]
+    time_spent_secs: u64,
+    #[serde(default)]
+    reminders: Vec<NaiveDateTime>,
+    #[serde(default)]
+    priority: Option<Priority>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(flatten)]
+    extra: serde_yaml::Mapping,
 }
 
 /// A TODO task with metadata and lifecycle status.
@@ -67,7 +211,36 @@ pub struct Task {
     pub updated_at: NaiveDateTime,
     pub completed_at: Option<NaiveDateTime>,
     pub deadline: NaiveDate,
+    /// Manual position within the status column. Ordered tasks come before unordered ones.
+    pub order: Option<u32>,
+    /// Short sequential id shown as `#<seq>` when `sequential_ids` is enabled.
+    pub seq: Option<u64>,
+    /// Status transitions, starting with the status the task was created in.
+    /// Empty for tasks created before history was recorded.
+    pub history: Vec<StatusChange>,
+    /// When the task last entered DOING; `None` while it is in another status.
+    pub started_at: Option<NaiveDateTime>,
+    /// Seconds spent in DOING over all finished visits, excluding the one in progress.
+    pub time_spent_secs: u64,
+    /// Local times at which the TUI reminds of this task, kept sorted.
+    pub reminders: Vec<NaiveDateTime>,
+    /// Urgency from the frontmatter; `None` when unset, which filters as medium.
+    pub priority: Option<Priority>,
+    /// Tags without their leading `#`, in the order they were added.
+    pub tags: Vec<String>,
+    /// Name of the project subdirectory within the status directory (`todo/<project>/<id>.md`);
+    /// `None` for files directly in the status directory.
+    pub project: Option<String>,
+    /// Format the frontmatter is written in: detected when loading, chosen by the store for new tasks.
+    pub frontmatter_format: FrontmatterFormat,
+    has_notes: bool,
+    checklist: Option<(usize, usize)>,
+    /// Frontmatter fields unknown to this version, kept so that saving does not drop them.
+    extra: serde_yaml::Mapping,
     base_dir: PathBuf,
+    /// File the task was loaded from when its name is not `<id>.md`, as for a copy whose
+    /// rename on load was skipped; `None` when the file is at `file_path()`.
+    source_path: Option<PathBuf>,
 }
 
 impl Task {
@@ -108,32 +281,89 @@ impl Task {
 
     /// Creates a new task under the provided task storage directory.
     pub fn new_in(name: String, base_dir: PathBuf) -> Self {
+        Self::new_with_status(name, TaskStatus::Todo, base_dir)
+    }
+
+    /// Creates a new task that starts in the given status, e.g. for logging work already done.
+    ///
+    /// Tasks created in DONE are completed at creation time.
+    pub fn new_with_status(name: String, status: TaskStatus, base_dir: PathBuf) -> Self {
         let now = Local::now().naive_local();
         Self {
             id: Uuid::new_v4(),
-            name,
-            status: TaskStatus::Todo,
+            name: Self::sanitize_name(&name),
+            status,
             created_at: now,
             updated_at: now,
-            completed_at: None,
+            completed_at: (status == TaskStatus::Done).then_some(now),
             deadline: Self::tomorrow_deadline(),
+            order: None,
+            seq: None,
+            history: vec![StatusChange { status, at: now }],
+            started_at: (status == TaskStatus::Doing).then_some(now),
+            time_spent_secs: 0,
+            reminders: Vec::new(),
+            priority: None,
+            tags: Vec::new(),
+            project: None,
+            frontmatter_format: FrontmatterFormat::Yaml,
+            has_notes: false,
+            checklist: None,
+            extra: serde_yaml::Mapping::new(),
             base_dir,
+            source_path: None,
         }
     }
 
-    /// Returns the base directory for all task files (`~/.rem-cli/tasks/`).
+    /// Turns a name into a single line by replacing newlines and other control characters
+    /// with spaces and trimming the ends, so hand-edited frontmatter cannot break the list.
+    pub fn sanitize_name(name: &str) -> String {
+        name.chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect::<String>()
+            .trim()
+            .to_string()
+    }
+
+    /// Returns the default base directory for all task files (`<data dir>/tasks/`).
+    ///
+    /// See [`crate::config::data_dir`] for how the data directory is chosen per platform.
     pub fn default_base_dir() -> PathBuf {
-        dirs::home_dir().unwrap().join(".rem-cli/tasks")
+        crate::config::data_dir(&dirs::home_dir().unwrap()).join("tasks")
+    }
+
+    /// Creates the directory for every status under the base directory.
+    ///
+    /// Existing directories are left untouched, so this is safe to call on every startup.
+    pub fn ensure_dirs(base_dir: &Path) -> io::Result<()> {
+        TaskStatus::ALL
+            .iter()
+            .try_for_each(|status| fs::create_dir_all(Self::status_dir(base_dir, *status)))
     }
 
     /// Returns the directory path for a given status (e.g. `~/.rem-cli/tasks/todo/`).
-    fn status_dir(base_dir: &Path, status: TaskStatus) -> PathBuf {
+    pub fn status_dir(base_dir: &Path, status: TaskStatus) -> PathBuf {
         base_dir.join(status.dir_name())
     }
 
+    /// Returns the task storage directory this task belongs to.
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
     /// Returns the full file path for this task's markdown file.
     pub fn file_path(&self) -> PathBuf {
-        Self::status_dir(&self.base_dir, self.status).join(format!("{}.md", self.id))
+        self.path_in(self.status)
+    }
+
+    /// Returns where this task's file lives in the given status, keeping its project subdirectory.
+    fn path_in(&self, status: TaskStatus) -> PathBuf {
+        let dir = Self::status_dir(&self.base_dir, status);
+        match &self.project {
+            Some(project) => dir.join(project),
+            None => dir,
+        }
+        .join(format!("{}.md", self.id))
     }
 
     /// Converts this task into a `TaskFrontmatter` for serialization.
@@ -145,28 +375,178 @@ impl Task {
             updated_at: self.updated_at,
             completed_at: self.completed_at,
             deadline: Some(self.deadline.format(DEADLINE_DATE_FORMAT).to_string()),
+            order: self.order,
+            seq: self.seq,
+            history: self.history.clone(),
+            started_at: self.started_at,
+            time_spent_secs: self.time_spent_secs,
+            reminders: self.reminders.clone(),
+            priority: self.priority,
+            tags: self.tags.clone(),
+            extra: self.extra.clone(),
         }
     }
 
-    /// Saves this task as a markdown file with YAML frontmatter to the appropriate status directory.
+    /// Saves this task as a markdown file with frontmatter to the appropriate status directory.
     pub fn save(&self) -> io::Result<()> {
         let path = self.file_path();
         fs::create_dir_all(path.parent().unwrap())?;
-        let yaml = serde_yaml::to_string(&self.frontmatter()).map_err(io::Error::other)?;
-        let content = format!("---\n{}---\n", yaml);
+        let content = frontmatter::render(self.frontmatter_format, &self.frontmatter(), "")?;
         fs::write(path, content)
     }
 
+    /// Rewrites this task's frontmatter in place while preserving the markdown body.
+    pub fn save_metadata(&self) -> io::Result<()> {
+        let path = self.file_path();
+        let existing = fs::read_to_string(&path)?;
+        let content = self.content_with_frontmatter(&existing, self.frontmatter())?;
+        Self::replace_file_content(&path, &content, "md.update")
+    }
+
+    /// Reads the markdown body of this task's file.
+    pub fn load_body(&self) -> io::Result<String> {
+        let content = fs::read_to_string(self.file_path())?;
+        Ok(Self::body_of(&content).to_string())
+    }
+
+    /// Reads the markdown body like `load_body`, but refuses files larger than `max_bytes`.
+    ///
+    /// At most `max_bytes + 1` bytes are read, so a huge file is never loaded into memory.
+    pub fn load_body_up_to(&self, max_bytes: u64) -> io::Result<String> {
+        // Check the length on bytes: the cap can split a multi-byte character.
+        let mut bytes = Vec::new();
+        fs::File::open(self.file_path())?
+            .take(max_bytes + 1)
+            .read_to_end(&mut bytes)?;
+        if bytes.len() as u64 > max_bytes {
+            return Err(io::Error::new(
+                io::ErrorKind::FileTooLarge,
+                format!("task file is larger than {max_bytes} bytes"),
+            ));
+        }
+        let content = String::from_utf8(bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        Ok(Self::body_of(&content).to_string())
+    }
+
+    /// Replaces the markdown body of this task's file, keeping the frontmatter as it is in memory.
+    pub fn save_body(&mut self, body: &str) -> io::Result<()> {
+        let path = self.file_path();
+        let content = frontmatter::render(self.frontmatter_format, &self.frontmatter(), body)?;
+        Self::replace_file_content(&path, &content, "md.update")?;
+        self.has_notes = !body.trim().is_empty();
+        self.checklist = Self::checklist_of(body);
+        Ok(())
+    }
+
+    /// Returns whether the markdown body had any non-whitespace text when the task was last read or written.
+    pub fn has_notes(&self) -> bool {
+        self.has_notes
+    }
+
+    /// Returns `(done, total)` for the `- [ ]` / `- [x]` checklist items in the markdown body,
+    /// or `None` when the body has no checklist. Read together with the body, like `has_notes`.
+    pub fn checklist_progress(&self) -> Option<(usize, usize)> {
+        self.checklist
+    }
+
+    /// Counts checklist items in a markdown body.
+    ///
+    /// Items may be indented and use `-`, `*`, or `+` bullets; `[x]` and `[X]` count as done.
+    fn checklist_of(body: &str) -> Option<(usize, usize)> {
+        let (done, total) = body
+            .lines()
+            .filter_map(|line| {
+                let item = line.trim_start();
+                let item = item
+                    .strip_prefix("- ")
+                    .or_else(|| item.strip_prefix("* "))
+                    .or_else(|| item.strip_prefix("+ "))?;
+                match item.get(..3)? {
+                    "[ ]" => Some(false),
+                    "[x]" | "[X]" => Some(true),
+                    _ => None,
+                }
+            })
+            .fold((0, 0), |(done, total), checked| {
+                (done + usize::from(checked), total + 1)
+            });
+        (total > 0).then_some((done, total))
+    }
+
+    /// Returns the task ids mentioned in a markdown body, in order of first appearance.
+    ///
+    /// Only the hyphenated form is recognized, so `see #<uuid>` links a task while a
+    /// bare 32-digit hex string does not.
+    pub fn referenced_ids(body: &str) -> Vec<Uuid> {
+        let mut ids = Vec::new();
+        for token in body.split(|c: char| !c.is_ascii_hexdigit() && c != '-') {
+            if token.len() == 36
+                && let Ok(id) = Uuid::parse_str(token)
+                && !ids.contains(&id)
+            {
+                ids.push(id);
+            }
+        }
+        ids
+    }
+
     /// Loads a task from a markdown file, assigning the given status based on its directory.
-    fn load(path: &PathBuf, status: TaskStatus) -> io::Result<Self> {
+    ///
+    /// The file must live in a status directory (`<base_dir>/<status>/<id>.md`);
+    /// outdated timestamp or deadline formats are migrated in place.
+    ///
+    /// The frontmatter `id` is authoritative: a file whose name does not match it, such as
+    /// a hand-made copy, is renamed to `<id>.md` like any other migration. The rename is best
+    /// effort: when a file with that name already exists or the directory cannot be written,
+    /// the file is left as it is and still loads, and `rem doctor` reports it.
+    pub fn load(path: &Path, status: TaskStatus) -> io::Result<Self> {
+        Self::load_in_project(path, status, None)
+    }
+
+    /// Loads a task from a file in the given project subdirectory
+    /// (`<base_dir>/<status>/<project>/<id>.md`), or directly in the status directory for `None`.
+    pub fn load_in_project(
+        path: &Path,
+        status: TaskStatus,
+        project: Option<String>,
+    ) -> io::Result<Self> {
         let content = fs::read_to_string(path)?;
-        let yaml = content
-            .trim_start_matches("---\n")
-            .split("---")
-            .next()
-            .unwrap_or("");
-        let fm: StoredTaskFrontmatter = serde_yaml::from_str(yaml)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let (mut task, needs_migration) = Self::parse(path, &content, status, project)?;
+        if needs_migration {
+            let migrated = task.content_with_frontmatter(&content, task.frontmatter())?;
+            Self::replace_file_content(path, &migrated, "md.migrate")?;
+        }
+        let renamed = Self::rename_to_match_id(path, task.id);
+        let id_file_name = format!("{}.md", task.id);
+        if !matches!(renamed, Ok(Some(_))) && path.file_name() != Some(id_file_name.as_ref()) {
+            task.source_path = Some(path.to_path_buf());
+        }
+        Ok(task)
+    }
+
+    /// Renames a task file to `<id>.md` in the same directory if it is named otherwise.
+    ///
+    /// Returns the new path, or `None` when the name already matches or another file
+    /// already has the matching name.
+    pub fn rename_to_match_id(path: &Path, id: Uuid) -> io::Result<Option<PathBuf>> {
+        let target = path.with_file_name(format!("{id}.md"));
+        if target == path || fs::symlink_metadata(&target).is_ok() {
+            return Ok(None);
+        }
+        fs::rename(path, &target)?;
+        Ok(Some(target))
+    }
+
+    /// Builds a task from the content of its file, also returning whether the file uses
+    /// outdated formats that `load_in_project` migrates.
+    fn parse(
+        path: &Path,
+        content: &str,
+        status: TaskStatus,
+        project: Option<String>,
+    ) -> io::Result<(Self, bool)> {
+        let fm: StoredTaskFrontmatter = frontmatter::parse(content)?;
         let (created_at, created_at_needs_migration) = Self::parse_datetime(&fm.created_at)?;
         let (updated_at, updated_at_needs_migration) = Self::parse_datetime(&fm.updated_at)?;
         let parsed_completed_at = fm
@@ -193,28 +573,59 @@ impl Task {
             || (status == TaskStatus::Done && fm.completed_at.is_none());
         let task = Self {
             id: fm.id,
-            name: fm.name,
+            name: Self::sanitize_name(&fm.name),
             status,
             created_at,
             updated_at,
             completed_at,
             deadline,
+            order: fm.order,
+            seq: fm.seq,
+            history: fm.history,
+            started_at: fm.started_at,
+            time_spent_secs: fm.time_spent_secs,
+            reminders: {
+                let mut reminders = fm.reminders;
+                reminders.sort();
+                reminders
+            },
+            priority: fm.priority,
+            tags: fm.tags,
+            has_notes: !Self::body_of(content).trim().is_empty(),
+            checklist: Self::checklist_of(Self::body_of(content)),
+            extra: fm.extra,
             base_dir: path
-                .parent()
-                .and_then(Path::parent)
+                .ancestors()
+                .nth(if project.is_some() { 3 } else { 2 })
                 .map(Path::to_path_buf)
                 .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid task path"))?,
+            project,
+            frontmatter_format: FrontmatterFormat::detect(content),
+            source_path: None,
         };
-        if needs_migration {
-            let migrated = task.content_with_frontmatter(&content, task.frontmatter())?;
-            Self::replace_file_content(path, &migrated, "md.migrate")?;
+        Ok((task, needs_migration))
+    }
+
+    /// Reads every task file in the status directories without migrating or otherwise
+    /// writing anything, pairing each path with its parse result.
+    ///
+    /// Used by `rem doctor`, which reports broken files instead of failing on the first one.
+    pub fn inspect_from(base_dir: &Path) -> io::Result<Vec<(PathBuf, io::Result<Self>)>> {
+        let mut results = Vec::new();
+        for status in TaskStatus::ALL {
+            for (path, project) in Self::task_files(&Self::status_dir(base_dir, status))? {
+                let task = fs::read_to_string(&path)
+                    .and_then(|content| Self::parse(&path, &content, status, project))
+                    .map(|(task, _)| task);
+                results.push((path, task));
+            }
         }
-        Ok(task)
+        Ok(results)
     }
 
     /// Reloads this task's metadata from its markdown file on disk.
     pub fn reload(&self) -> io::Result<Self> {
-        Self::load(&self.file_path(), self.status)
+        Self::load_in_project(&self.file_path(), self.status, self.project.clone())
     }
 
     /// Loads all tasks from the `parking/` directory.
@@ -247,6 +658,16 @@ impl Task {
         Self::load_by_status(base_dir, &[TaskStatus::Doing])
     }
 
+    /// Loads tasks in every status from the default base directory, sorted for display.
+    pub fn all() -> io::Result<Vec<Self>> {
+        Self::all_from(&Self::default_base_dir())
+    }
+
+    /// Loads tasks in every status under the provided base directory, sorted for display.
+    pub fn all_from(base_dir: &Path) -> io::Result<Vec<Self>> {
+        Self::load_by_status(base_dir, &TaskStatus::ALL).map(Self::sort)
+    }
+
     /// Loads all tasks from the `done/` directory.
     pub fn load_done() -> io::Result<Vec<Self>> {
         Self::load_done_from(&Self::default_base_dir())
@@ -277,48 +698,247 @@ impl Task {
         })
     }
 
-    /// Loads tasks from the directories corresponding to the given statuses, sorted by `created_at`.
-    fn load_by_status(base_dir: &Path, statuses: &[TaskStatus]) -> io::Result<Vec<Self>> {
-        let mut tasks = Vec::new();
-        for status in statuses {
-            let dir = Self::status_dir(base_dir, *status);
-            if !dir.exists() {
+    /// Counts the task files in the directory for the given status without parsing them.
+    pub fn count_by_status(base_dir: &Path, status: TaskStatus) -> io::Result<usize> {
+        Ok(Self::task_files(&Self::status_dir(base_dir, status))?.len())
+    }
+
+    /// Lists the `.md` files in a status directory and in its project subdirectories, one
+    /// level deep, each with the name of the project it was found in.
+    ///
+    /// Hidden subdirectories are ignored. A missing directory has no files, and entries removed
+    /// by another process while listing are skipped.
+    fn task_files(dir: &Path) -> io::Result<Vec<(PathBuf, Option<String>)>> {
+        let mut files = Vec::new();
+        for path in Self::read_dir_paths(dir)? {
+            if path.extension().is_some_and(|e| e == "md") {
+                files.push((path, None));
                 continue;
             }
-            for entry in fs::read_dir(&dir)? {
-                let path = entry?.path();
-                if path.extension().is_some_and(|e| e == "md") {
-                    tasks.push(Self::load(&path, *status).map_err(|error| {
-                        io::Error::new(
-                            error.kind(),
-                            format!("failed to load {}: {error}", path.display()),
-                        )
-                    })?);
+            let Some(project) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .filter(|name| !name.starts_with('.') && path.is_dir())
+                .map(str::to_string)
+            else {
+                continue;
+            };
+            files.extend(
+                Self::read_dir_paths(&path)?
+                    .into_iter()
+                    .filter(|path| path.extension().is_some_and(|e| e == "md"))
+                    .map(|path| (path, Some(project.clone()))),
+            );
+        }
+        Ok(files)
+    }
+
+    /// Lists the entries of a directory, treating a directory or entry that disappears
+    /// while reading as absent.
+    fn read_dir_paths(dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let read_dir = match fs::read_dir(dir) {
+            Ok(read_dir) => read_dir,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error),
+        };
+        let mut paths = Vec::new();
+        for entry in read_dir {
+            match entry {
+                Ok(entry) => paths.push(entry.path()),
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(paths)
+    }
+
+    /// Moves every task file in `done/` to `archive/`, returning the number of archived tasks.
+    ///
+    /// Archived tasks are kept on disk but are never loaded into the TUI.
+    /// Files in a project subdirectory go to the same subdirectory of `archive/`.
+    pub fn archive_done_from(base_dir: &Path) -> io::Result<usize> {
+        let files = Self::task_files(&Self::status_dir(base_dir, TaskStatus::Done))?;
+        let archive_dir = base_dir.join(ARCHIVE_DIR_NAME);
+        let mut archived = 0;
+        for (path, project) in files {
+            if let Some(stem) = path.file_stem() {
+                let dir = match project {
+                    Some(project) => archive_dir.join(project),
+                    None => archive_dir.clone(),
+                };
+                fs::create_dir_all(&dir)?;
+                fs::rename(
+                    &path,
+                    Self::free_archive_path(&dir, &stem.to_string_lossy()),
+                )?;
+                archived += 1;
+            }
+        }
+        Ok(archived)
+    }
+
+    /// Returns `<dir>/<stem>.md`, or `<dir>/<stem>-<n>.md` with the first free `n` when that
+    /// name is taken, so archiving a task again never overwrites the earlier archived copy.
+    fn free_archive_path(dir: &Path, stem: &str) -> PathBuf {
+        let mut path = dir.join(format!("{stem}.md"));
+        let mut suffix = 1;
+        while fs::symlink_metadata(&path).is_ok() {
+            path = dir.join(format!("{stem}-{suffix}.md"));
+            suffix += 1;
+        }
+        path
+    }
+
+    /// Moves DONE tasks completed before `cutoff` into `archive/`, returning how many were
+    /// archived. Tasks without `completed_at`, such as hand-made files, go by `updated_at`.
+    pub fn archive_done_before(base_dir: &Path, cutoff: NaiveDateTime) -> io::Result<usize> {
+        let mut archived = 0;
+        for mut task in Self::load_done_from(base_dir)? {
+            if task.completed_at.unwrap_or(task.updated_at) < cutoff {
+                task.archive()?;
+                archived += 1;
+            }
+        }
+        Ok(archived)
+    }
+
+    /// Returns task files in the status directories that are symlinks resolving outside `base_dir`.
+    ///
+    /// Such files are still loaded and updated through the link, but edits change files
+    /// that live elsewhere, which is worth pointing out.
+    pub fn external_links_from(base_dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let base_dir = fs::canonicalize(base_dir)?;
+        let mut links = Vec::new();
+        for status in TaskStatus::ALL {
+            for (path, _) in Self::task_files(&Self::status_dir(&base_dir, status))? {
+                if fs::symlink_metadata(&path).is_ok_and(|metadata| metadata.is_symlink())
+                    && fs::canonicalize(&path).is_ok_and(|target| !target.starts_with(&base_dir))
+                {
+                    links.push(path);
                 }
             }
         }
+        Ok(links)
+    }
+
+    /// Permanently deletes every task file in `done/`, returning the number of deleted tasks.
+    pub fn purge_done_from(base_dir: &Path) -> io::Result<usize> {
+        let files = Self::task_files(&Self::status_dir(base_dir, TaskStatus::Done))?;
+        for (path, _) in &files {
+            fs::remove_file(path)?;
+        }
+        Ok(files.len())
+    }
+
+    /// Loads tasks from the directories corresponding to the given statuses, sorted by `created_at`.
+    ///
+    /// Files in project subdirectories (`todo/<project>/<id>.md`) are loaded with that project.
+    /// Directories with at least `PARALLEL_LOAD_THRESHOLD` files are parsed on several threads.
+    /// Fails with the first unreadable file in directory order, as the serial path does.
+    /// Files and directories removed by another process while loading are skipped.
+    pub fn load_by_status(base_dir: &Path, statuses: &[TaskStatus]) -> io::Result<Vec<Self>> {
+        let mut entries = Vec::new();
+        for status in statuses {
+            entries.extend(
+                Self::task_files(&Self::status_dir(base_dir, *status))?
+                    .into_iter()
+                    .map(|(path, project)| (path, *status, project)),
+            );
+        }
+        let results = if entries.len() < PARALLEL_LOAD_THRESHOLD {
+            entries.iter().map(Self::load_entry).collect::<Vec<_>>()
+        } else {
+            Self::load_entries_in_parallel(&entries)
+        };
+        let mut tasks = results
+            .into_iter()
+            .filter_map(Result::transpose)
+            .collect::<io::Result<Vec<_>>>()?;
         tasks.sort_by_key(|task| task.created_at);
         Ok(tasks)
     }
 
+    /// Loads one listed file, returning `None` if it was removed after the directory was read.
+    ///
+    /// A symlink whose target is missing still exists itself, so it is reported as an error.
+    fn load_entry(
+        (path, status, project): &(PathBuf, TaskStatus, Option<String>),
+    ) -> io::Result<Option<Self>> {
+        match Self::load_in_project(path, *status, project.clone()) {
+            Ok(task) => Ok(Some(task)),
+            Err(error)
+                if error.kind() == io::ErrorKind::NotFound
+                    && fs::symlink_metadata(path).is_err() =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(io::Error::new(
+                error.kind(),
+                format!("failed to load {}: {error}", path.display()),
+            )),
+        }
+    }
+
+    /// Loads the entries on one scoped thread per available core, keeping the input order.
+    fn load_entries_in_parallel(
+        entries: &[(PathBuf, TaskStatus, Option<String>)],
+    ) -> Vec<io::Result<Option<Self>>> {
+        let threads = thread::available_parallelism().map_or(1, |count| count.get());
+        let chunk_size = entries.len().div_ceil(threads);
+        thread::scope(|scope| {
+            entries
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || chunk.iter().map(Self::load_entry).collect::<Vec<_>>())
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("task loading thread panicked"))
+                .collect()
+        })
+    }
+
     /// Changes this task's status and moves the file to the corresponding directory.
+    ///
+    /// The manual order is cleared because it only applies within the previous column.
+    /// Entering DOING starts the time tracking clock; leaving it adds the elapsed time
+    /// to `time_spent_secs`.
     pub fn update_status(&mut self, new_status: TaskStatus) -> io::Result<()> {
         let old_path = self.file_path();
-        let new_path = Self::status_dir(&self.base_dir, new_status).join(format!("{}.md", self.id));
+        let new_path = self.path_in(new_status);
         let updated_at = Local::now().naive_local();
         let completed_at = match (self.status, new_status) {
-            (TaskStatus::Doing, TaskStatus::Done) => Some(updated_at),
-            (TaskStatus::Done, TaskStatus::Doing) => None,
+            (TaskStatus::Done, TaskStatus::Done) => self.completed_at,
+            (_, TaskStatus::Done) => Some(updated_at),
+            (TaskStatus::Done, _) => None,
             _ => self.completed_at,
         };
+        let (started_at, time_spent_secs) = match (self.status, new_status) {
+            (TaskStatus::Doing, TaskStatus::Doing) => (self.started_at, self.time_spent_secs),
+            (TaskStatus::Doing, _) => (None, self.time_spent_secs_at(updated_at)),
+            (_, TaskStatus::Doing) => (Some(updated_at), self.time_spent_secs),
+            _ => (self.started_at, self.time_spent_secs),
+        };
+        let mut history = self.history.clone();
+        history.push(StatusChange {
+            status: new_status,
+            at: updated_at,
+        });
         let existing = fs::read_to_string(&old_path)?;
-        let content = self.content_with_updated_frontmatter(&existing, updated_at, completed_at)?;
+        let frontmatter = TaskFrontmatter {
+            updated_at,
+            completed_at,
+            order: None,
+            history: history.clone(),
+            started_at,
+            time_spent_secs,
+            ..self.frontmatter()
+        };
+        let content = self.content_with_frontmatter(&existing, frontmatter)?;
         fs::create_dir_all(new_path.parent().unwrap())?;
         Self::replace_file_content(&old_path, &content, "md.update")?;
         if let Err(move_error) = fs::rename(&old_path, &new_path) {
-            let rollback_path = old_path.with_extension("md.rollback");
-            let rollback_result = fs::write(&rollback_path, existing)
-                .and_then(|()| fs::rename(&rollback_path, &old_path));
+            let rollback_result = Self::replace_file_content(&old_path, &existing, "md.rollback");
             return match rollback_result {
                 Ok(()) => Err(move_error),
                 Err(rollback_error) => Err(io::Error::new(
@@ -330,22 +950,153 @@ impl Task {
         self.status = new_status;
         self.updated_at = updated_at;
         self.completed_at = completed_at;
+        self.order = None;
+        self.history = history;
+        self.started_at = started_at;
+        self.time_spent_secs = time_spent_secs;
         Ok(())
     }
 
-    /// Builds updated file content while preserving the markdown body.
-    fn content_with_updated_frontmatter(
-        &self,
-        existing: &str,
-        updated_at: NaiveDateTime,
-        completed_at: Option<NaiveDateTime>,
-    ) -> io::Result<String> {
+    /// Gives this task a fresh random id and renames its file to match.
+    ///
+    /// The frontmatter and body carry over unchanged apart from `id`; a symlinked file
+    /// is renamed as a link, like in `update_status`.
+    pub fn reassign_id(&mut self) -> io::Result<()> {
+        let old_path = self.source_path.clone().unwrap_or_else(|| self.file_path());
+        let existing = fs::read_to_string(&old_path)?;
+        let new_id = Uuid::new_v4();
         let frontmatter = TaskFrontmatter {
-            updated_at,
-            completed_at,
+            id: new_id,
             ..self.frontmatter()
         };
-        self.content_with_frontmatter(existing, frontmatter)
+        let content = self.content_with_frontmatter(&existing, frontmatter)?;
+        let new_path = old_path.with_file_name(format!("{new_id}.md"));
+        Self::replace_file_content(&old_path, &content, "md.update")?;
+        if let Err(move_error) = fs::rename(&old_path, &new_path) {
+            let rollback_result = Self::replace_file_content(&old_path, &existing, "md.rollback");
+            return match rollback_result {
+                Ok(()) => Err(move_error),
+                Err(rollback_error) => Err(io::Error::new(
+                    move_error.kind(),
+                    format!("{move_error}; failed to restore original file: {rollback_error}"),
+                )),
+            };
+        }
+        self.id = new_id;
+        self.source_path = None;
+        Ok(())
+    }
+
+    /// Adds a reminder at `at`, or removes it if one is already set then.
+    ///
+    /// Returns whether the reminder was added. Reminders stay sorted.
+    pub fn toggle_reminder(&mut self, at: NaiveDateTime) -> bool {
+        match self.reminders.binary_search(&at) {
+            Ok(position) => {
+                self.reminders.remove(position);
+                false
+            }
+            Err(position) => {
+                self.reminders.insert(position, at);
+                true
+            }
+        }
+    }
+
+    /// Normalizes a typed tag: surrounding whitespace and one leading `#` are removed.
+    ///
+    /// Returns `None` for an empty tag or one containing whitespace.
+    pub fn normalize_tag(tag: &str) -> Option<String> {
+        let tag = tag.trim();
+        let tag = tag.strip_prefix('#').unwrap_or(tag);
+        (!tag.is_empty() && !tag.contains(char::is_whitespace)).then(|| tag.to_string())
+    }
+
+    /// Adds a normalized tag unless the task already has it. Returns whether it was added.
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        if self.tags.iter().any(|existing| existing == tag) {
+            return false;
+        }
+        self.tags.push(tag.to_string());
+        true
+    }
+
+    /// Removes a normalized tag. Returns whether the task had it.
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let before = self.tags.len();
+        self.tags.retain(|existing| existing != tag);
+        self.tags.len() != before
+    }
+
+    /// Returns every distinct tag of `tasks` with the number of tasks carrying it, sorted by tag.
+    pub fn collect_tags(tasks: &[Task]) -> Vec<(String, usize)> {
+        let mut counts = BTreeMap::<&str, usize>::new();
+        for tag in tasks.iter().flat_map(|task| &task.tags) {
+            *counts.entry(tag).or_default() += 1;
+        }
+        counts
+            .into_iter()
+            .map(|(tag, count)| (tag.to_string(), count))
+            .collect()
+    }
+
+    /// Returns whether a reminder falls after `since` and no later than `now`.
+    pub fn has_reminder_between(&self, since: NaiveDateTime, now: NaiveDateTime) -> bool {
+        self.reminders
+            .iter()
+            .any(|reminder| since < *reminder && *reminder <= now)
+    }
+
+    /// Returns the seconds spent in DOING up to `now`, including the visit in progress.
+    pub fn time_spent_secs_at(&self, now: NaiveDateTime) -> u64 {
+        let running = self.started_at.map_or(0, |started_at| {
+            u64::try_from((now - started_at).num_seconds()).unwrap_or(0)
+        });
+        self.time_spent_secs + running
+    }
+
+    /// Returns the total time spent in each status, in the order the statuses were first entered.
+    ///
+    /// The last recorded status is counted up to `now`. Negative spans from clock skew count as zero.
+    pub fn time_in_status(&self, now: NaiveDateTime) -> Vec<(TaskStatus, TimeDelta)> {
+        let ends = self
+            .history
+            .iter()
+            .skip(1)
+            .map(|change| change.at)
+            .chain([now]);
+        self.history
+            .iter()
+            .zip(ends)
+            .fold(Vec::new(), |mut totals, (change, end)| {
+                let span = (end - change.at).max(TimeDelta::zero());
+                match totals
+                    .iter_mut()
+                    .find(|(status, _)| *status == change.status)
+                {
+                    Some((_, total)) => *total += span,
+                    None => totals.push((change.status, span)),
+                }
+                totals
+            })
+    }
+
+    /// Marks this task as DONE and moves its file into `archive/`.
+    ///
+    /// If the status update succeeds but the move fails, the task stays in `done/`.
+    pub fn archive(&mut self) -> io::Result<()> {
+        if self.status != TaskStatus::Done {
+            self.update_status(TaskStatus::Done)?;
+        }
+        let mut archive_dir = self.base_dir.join(ARCHIVE_DIR_NAME);
+        if let Some(project) = &self.project {
+            archive_dir.push(project);
+        }
+        fs::create_dir_all(&archive_dir)?;
+        fs::rename(
+            self.file_path(),
+            Self::free_archive_path(&archive_dir, &self.id.to_string()),
+        )
     }
 
     /// Builds file content with the provided frontmatter while preserving the markdown body.
@@ -354,23 +1105,34 @@ impl Task {
         existing: &str,
         frontmatter: TaskFrontmatter,
     ) -> io::Result<String> {
-        let yaml = serde_yaml::to_string(&frontmatter).map_err(io::Error::other)?;
-        let body = existing
-            .strip_prefix("---\n")
-            .and_then(|s| s.find("\n---\n").map(|pos| &s[pos + 5..]))
-            .unwrap_or("");
-        Ok(format!("---\n{}---\n{}", yaml, body))
+        frontmatter::render(
+            self.frontmatter_format,
+            &frontmatter,
+            Self::body_of(existing),
+        )
+    }
+
+    /// Returns the markdown body that follows the frontmatter.
+    fn body_of(content: &str) -> &str {
+        frontmatter::body(content)
     }
 
     /// Replaces a task file through a temporary file to avoid partial writes.
+    ///
+    /// A symlinked task file is written through to its target, so the link itself is kept.
     fn replace_file_content(
         path: &Path,
         content: &str,
         temporary_extension: &str,
     ) -> io::Result<()> {
+        let path = if fs::symlink_metadata(path)?.file_type().is_symlink() {
+            fs::canonicalize(path)?
+        } else {
+            path.to_path_buf()
+        };
         let temporary_path = path.with_extension(temporary_extension);
         fs::write(&temporary_path, content)?;
-        if let Err(error) = fs::rename(&temporary_path, path) {
+        if let Err(error) = fs::rename(&temporary_path, &path) {
             let cleanup_result = fs::remove_file(&temporary_path);
             return match cleanup_result {
                 Ok(()) => Err(error),
@@ -383,21 +1145,51 @@ impl Task {
         Ok(())
     }
 
-    /// Sorts tasks by status group and by `created_at` within each group.
-    pub fn sort(tasks: Vec<Task>) -> Vec<Task> {
-        let mut parking = Self::filter_by_status(&tasks, TaskStatus::Parking);
-        let mut todos = Self::filter_by_status(&tasks, TaskStatus::Todo);
-        let mut doings = Self::filter_by_status(&tasks, TaskStatus::Doing);
-        let mut dones = Self::filter_by_status(&tasks, TaskStatus::Done);
-        parking.sort_by_key(|task| task.created_at);
-        todos.sort_by_key(|task| task.created_at);
-        doings.sort_by_key(|task| task.created_at);
-        dones.sort_by_key(|task| task.created_at);
-        [parking, todos, doings, dones].concat()
+    /// Returns whether the task name, its project, its `#<seq>` id, or one of its `#<tag>`s
+    /// contains the query, ignoring case.
+    ///
+    /// An empty query matches every task.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.name.to_lowercase().contains(&query)
+            || self
+                .project
+                .as_ref()
+                .is_some_and(|project| project.to_lowercase().contains(&query))
+            || self
+                .seq
+                .is_some_and(|seq| format!("#{seq}").contains(&query))
+            || self
+                .tags
+                .iter()
+                .any(|tag| format!("#{}", tag.to_lowercase()).contains(&query))
+    }
+
+    /// Returns whether the task was created within the inclusive date range.
+    ///
+    /// A missing bound leaves that side of the range open.
+    pub fn created_between(&self, since: Option<NaiveDate>, until: Option<NaiveDate>) -> bool {
+        let created = self.created_at.date();
+        since.is_none_or(|since| created >= since) && until.is_none_or(|until| created <= until)
+    }
+
+    /// Sorts tasks by status group, then by manual `order`, then by `created_at` within each group.
+    pub fn sort(mut tasks: Vec<Task>) -> Vec<Task> {
+        Self::sort_in_place(&mut tasks);
+        tasks
+    }
+
+    /// Same ordering as [`Task::sort`], but sorts the vector in place without cloning any task.
+    pub fn sort_in_place(tasks: &mut [Task]) {
+        tasks.sort_by_key(|task| (task.status.status_rank(), Self::column_sort_key(task)));
+    }
+
+    fn column_sort_key(task: &Task) -> (bool, Option<u32>, NaiveDateTime) {
+        (task.order.is_none(), task.order, task.created_at)
     }
 
     /// Filters tasks by the given status, returning cloned copies.
-    fn filter_by_status(tasks: &[Task], status: TaskStatus) -> Vec<Task> {
+    pub fn filter_by_status(tasks: &[Task], status: TaskStatus) -> Vec<Task> {
         tasks
             .iter()
             .filter(|t| t.status == status)
@@ -522,48 +1314,714 @@ mod tests {
     }
 
     #[test]
-    fn parking_file_path_contains_parking_directory() {
+    fn ensure_dirs_creates_every_status_directory_idempotently() {
         // GIVEN
-        let mut task = Task::new("parking path test".to_string());
-        task.status = TaskStatus::Parking;
+        let tasks_dir = temporary_tasks_dir();
+        let expected = ["parking", "todo", "doing", "done"];
 
         // WHEN
-        let path = task.file_path();
+        Task::ensure_dirs(&tasks_dir).unwrap();
+        Task::ensure_dirs(&tasks_dir).unwrap();
 
         // THEN
-        assert!(path.to_str().unwrap().contains("/parking/"));
-        assert!(path.to_str().unwrap().ends_with(&format!("{}.md", task.id)));
+        assert!(expected.iter().all(|name| tasks_dir.join(name).is_dir()));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
     }
 
     #[test]
-    fn filter_by_status_returns_matching_tasks() {
-        // GIVEN: tasks with mixed statuses
-        let todo = Task::new("todo".to_string());
-        let mut doing = Task::new("doing".to_string());
-        doing.status = TaskStatus::Doing;
-        let tasks = vec![todo, doing];
+    fn sort_places_ordered_tasks_before_unordered_ones() {
+        // GIVEN
+        let first_created = Task::new("first created".to_string());
+        thread::sleep(Duration::from_millis(10));
+        let mut second_created = Task::new("second created".to_string());
+        second_created.order = Some(0);
 
-        // WHEN: filter_by_status is called with Todo
-        let filtered = Task::filter_by_status(&tasks, TaskStatus::Todo);
+        // WHEN
+        let sorted = Task::sort(vec![first_created, second_created]);
 
-        // THEN: only TODO tasks are returned
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].name, "todo");
+        // THEN
+        let actual = sorted
+            .iter()
+            .map(|task| task.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, ["second created", "first created"]);
     }
 
     #[test]
-    fn save_and_load_roundtrip() {
-        // GIVEN: a task saved to disk
+    fn todo_doing_done_cycle_accumulates_time_spent_in_doing() {
+        // GIVEN
         let tasks_dir = temporary_tasks_dir();
-        let task = Task::new_in("roundtrip test".to_string(), tasks_dir.clone());
+        let mut task = Task::new_in("tracked".to_string(), tasks_dir.clone());
         task.save().unwrap();
 
-        // WHEN: Task::load is called with the file path
-        let loaded = Task::load(&task.file_path(), TaskStatus::Todo).unwrap();
+        // WHEN
+        task.update_status(TaskStatus::Doing).unwrap();
+        let started_at = task.started_at;
+        task.started_at = started_at.map(|started_at| started_at - TimeDelta::seconds(90));
+        task.update_status(TaskStatus::Done).unwrap();
 
-        // THEN: the loaded task matches the original
-        assert_eq!(loaded.id, task.id);
-        assert_eq!(loaded.name, "roundtrip test");
+        // THEN
+        assert!(started_at.is_some());
+        assert!((90..=92).contains(&task.time_spent_secs));
+        assert_eq!(task.started_at, None);
+        let reloaded = task.reload().unwrap();
+        assert_eq!(reloaded.time_spent_secs, task.time_spent_secs);
+        assert_eq!(reloaded.started_at, None);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn external_links_detects_symlinked_task_outside_base_dir() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let outside_dir = temporary_tasks_dir();
+        let linked = Task::new_in("linked".to_string(), outside_dir.clone());
+        linked.save().unwrap();
+        let local = Task::new_in("local".to_string(), tasks_dir.clone());
+        local.save().unwrap();
+        let link_path =
+            Task::status_dir(&tasks_dir, TaskStatus::Todo).join(format!("{}.md", linked.id));
+        std::os::unix::fs::symlink(linked.file_path(), &link_path).unwrap();
+
+        // WHEN
+        let actual = Task::external_links_from(&tasks_dir).unwrap();
+
+        // THEN
+        assert_eq!(actual, [link_path]);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+        fs::remove_dir_all(outside_dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn update_status_moves_symlink_and_writes_through_to_target() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let outside_dir = temporary_tasks_dir();
+        let target = Task::new_in("linked".to_string(), outside_dir.clone());
+        target.save().unwrap();
+        let link_path =
+            Task::status_dir(&tasks_dir, TaskStatus::Todo).join(format!("{}.md", target.id));
+        fs::create_dir_all(link_path.parent().unwrap()).unwrap();
+        std::os::unix::fs::symlink(target.file_path(), &link_path).unwrap();
+        let mut task = Task::load(&link_path, TaskStatus::Todo).unwrap();
+
+        // WHEN
+        task.update_status(TaskStatus::Doing).unwrap();
+
+        // THEN
+        let moved = fs::symlink_metadata(task.file_path()).unwrap();
+        assert!(moved.file_type().is_symlink());
+        assert!(!link_path.exists());
+        assert!(
+            fs::read_to_string(target.file_path())
+                .unwrap()
+                .contains("entered: doing")
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+        fs::remove_dir_all(outside_dir).unwrap();
+    }
+
+    #[test]
+    fn load_by_status_reads_project_subdirectories() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let flat = Task::new_in("flat".to_string(), tasks_dir.clone());
+        flat.save().unwrap();
+        let mut nested = Task::new_in("nested".to_string(), tasks_dir.clone());
+        nested.project = Some("projectA".to_string());
+        nested.save().unwrap();
+        let expected_path = Task::status_dir(&tasks_dir, TaskStatus::Todo)
+            .join("projectA")
+            .join(format!("{}.md", nested.id));
+
+        // WHEN
+        let tasks = Task::load_by_status(&tasks_dir, &[TaskStatus::Todo]).unwrap();
+
+        // THEN
+        let projects = tasks
+            .iter()
+            .map(|task| (task.name.as_str(), task.project.as_deref()))
+            .collect::<Vec<_>>();
+        assert_eq!(projects, [("flat", None), ("nested", Some("projectA"))]);
+        assert_eq!(tasks[1].file_path(), expected_path);
+        assert_eq!(tasks[1].base_dir(), tasks_dir.as_path());
+        assert_eq!(
+            Task::count_by_status(&tasks_dir, TaskStatus::Todo).unwrap(),
+            2
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn status_change_and_archive_keep_project_subdirectory() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("nested".to_string(), tasks_dir.clone());
+        task.project = Some("projectA".to_string());
+        task.save().unwrap();
+
+        // WHEN
+        task.update_status(TaskStatus::Done).unwrap();
+        let done_path = task.file_path();
+        let archived = Task::archive_done_from(&tasks_dir).unwrap();
+
+        // THEN
+        assert_eq!(
+            done_path,
+            Task::status_dir(&tasks_dir, TaskStatus::Done)
+                .join("projectA")
+                .join(format!("{}.md", task.id))
+        );
+        assert_eq!(archived, 1);
+        assert!(
+            tasks_dir
+                .join(ARCHIVE_DIR_NAME)
+                .join("projectA")
+                .join(format!("{}.md", task.id))
+                .exists()
+        );
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn archive_done_before_falls_back_to_updated_at() {
+        // GIVEN: a hand-made DONE file without completed_at
+        let tasks_dir = temporary_tasks_dir();
+        let now = Local::now().naive_local();
+        let mut task =
+            Task::new_with_status("by hand".to_string(), TaskStatus::Done, tasks_dir.clone());
+        task.completed_at = None;
+        task.updated_at = now - TimeDelta::days(10);
+        task.save().unwrap();
+
+        // WHEN
+        let archived = Task::archive_done_before(&tasks_dir, now - TimeDelta::days(7)).unwrap();
+
+        // THEN
+        assert_eq!(archived, 1);
+        assert!(!task.file_path().exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn archiving_keeps_earlier_archived_copy_with_the_same_id() {
+        // GIVEN: a task archived once, then restored by hand and completed again
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("twice".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        task.archive().unwrap();
+        let archive_dir = tasks_dir.join(ARCHIVE_DIR_NAME);
+        let first = archive_dir.join(format!("{}.md", task.id));
+        let first_content = fs::read_to_string(&first).unwrap();
+        task.name = "twice again".to_string();
+        task.save().unwrap();
+
+        // WHEN
+        let archived = Task::archive_done_from(&tasks_dir).unwrap();
+        task.save().unwrap();
+        task.archive().unwrap();
+
+        // THEN
+        assert_eq!(archived, 1);
+        assert_eq!(fs::read_to_string(&first).unwrap(), first_content);
+        assert!(archive_dir.join(format!("{}-1.md", task.id)).exists());
+        assert!(archive_dir.join(format!("{}-2.md", task.id)).exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn unknown_frontmatter_fields_survive_status_move() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("custom".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let path = task.file_path();
+        let content =
+            fs::read_to_string(&path)
+                .unwrap()
+                .replacen("---\n", "---\ncustom: value\n", 1);
+        fs::write(&path, content).unwrap();
+        let mut loaded = Task::load(&path, TaskStatus::Todo).unwrap();
+
+        // WHEN
+        loaded.update_status(TaskStatus::Doing).unwrap();
+
+        // THEN
+        let moved = fs::read_to_string(loaded.file_path()).unwrap();
+        assert!(moved.contains("custom: value"));
+        assert!(moved.contains("entered: doing"));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn sanitize_name_replaces_newlines_and_tabs_with_spaces() {
+        // GIVEN
+        let name = "  first line\nsecond\tpart\r\n";
+
+        // WHEN
+        let actual = Task::sanitize_name(name);
+
+        // THEN
+        assert_eq!(actual, "first line second part");
+    }
+
+    #[test]
+    fn load_normalizes_multiline_name_and_keeps_body() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("placeholder".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let path = task.file_path();
+        let content = fs::read_to_string(&path)
+            .unwrap()
+            .replace("name: placeholder", "name: \"broken\\nname\"");
+        fs::write(&path, format!("{content}line one\n\tline two\n")).unwrap();
+
+        // WHEN
+        let loaded = Task::load(&path, TaskStatus::Todo).unwrap();
+
+        // THEN
+        assert_eq!(loaded.name, "broken name");
+        assert_eq!(loaded.load_body().unwrap(), "line one\n\tline two\n");
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn created_between_includes_both_bounds_and_allows_open_ends() {
+        // GIVEN
+        let mut task = Task::new("reviewed".to_string());
+        task.created_at = NaiveDate::from_ymd_opt(2024, 1, 7)
+            .unwrap()
+            .and_hms_opt(23, 59, 59)
+            .unwrap();
+        let date = |day| NaiveDate::from_ymd_opt(2024, 1, day);
+
+        // WHEN
+        let actual = [
+            task.created_between(date(1), date(7)),
+            task.created_between(date(7), date(7)),
+            task.created_between(date(8), None),
+            task.created_between(None, date(6)),
+            task.created_between(None, None),
+            task.created_between(date(7), None),
+        ];
+
+        // THEN
+        assert_eq!(actual, [true, true, false, false, true, true]);
+    }
+
+    #[test]
+    fn sort_in_place_groups_by_status_then_order() {
+        // GIVEN
+        let mut done = Task::new("done".to_string());
+        done.status = TaskStatus::Done;
+        let mut todo_unordered = Task::new("todo unordered".to_string());
+        todo_unordered.status = TaskStatus::Todo;
+        let mut todo_ordered = Task::new("todo ordered".to_string());
+        todo_ordered.status = TaskStatus::Todo;
+        todo_ordered.order = Some(0);
+        let mut parking = Task::new("parking".to_string());
+        parking.status = TaskStatus::Parking;
+        let mut tasks = vec![done, todo_unordered, todo_ordered, parking];
+
+        // WHEN
+        Task::sort_in_place(&mut tasks);
+
+        // THEN
+        let actual = tasks
+            .iter()
+            .map(|task| task.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            ["parking", "todo ordered", "todo unordered", "done"]
+        );
+    }
+
+    #[test]
+    fn sort_matches_split_and_concat_ordering_on_large_lists() {
+        // GIVEN
+        let created_at = NaiveDate::from_ymd_opt(2026, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let tasks = (0..10_000)
+            .map(|index: u32| {
+                let mut task = Task::new(format!("task {index}"));
+                task.status = TaskStatus::ALL[(index * 7 % 4) as usize];
+                task.order = index.is_multiple_of(5).then_some(index % 13);
+                task.created_at = created_at + TimeDelta::minutes(i64::from(index * 31 % 997));
+                task
+            })
+            .collect::<Vec<_>>();
+        let expected = TaskStatus::ALL
+            .iter()
+            .flat_map(|status| {
+                let mut column = Task::filter_by_status(&tasks, *status);
+                column.sort_by_key(Task::column_sort_key);
+                column
+            })
+            .map(|task| task.id)
+            .collect::<Vec<_>>();
+
+        // WHEN
+        let actual = Task::sort(tasks)
+            .into_iter()
+            .map(|task| task.id)
+            .collect::<Vec<_>>();
+
+        // THEN
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn next_and_previous_follow_custom_status_flow() {
+        // GIVEN
+        let flow = [
+            TaskStatus::Todo,
+            TaskStatus::Doing,
+            TaskStatus::Parking,
+            TaskStatus::Done,
+        ];
+
+        // WHEN
+        let forward = std::iter::successors(Some(TaskStatus::Todo), |status| status.next_in(&flow))
+            .collect::<Vec<_>>();
+        let backward =
+            std::iter::successors(Some(TaskStatus::Done), |status| status.previous_in(&flow))
+                .collect::<Vec<_>>();
+
+        // THEN
+        assert_eq!(forward, flow);
+        assert_eq!(backward, flow.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn status_outside_flow_moves_to_nearest_listed_status() {
+        // GIVEN
+        let flow = [TaskStatus::Todo, TaskStatus::Done];
+
+        // WHEN
+        let forward = TaskStatus::Doing.next_in(&flow);
+        let backward = TaskStatus::Doing.previous_in(&flow);
+        let before_first = TaskStatus::Parking.previous_in(&flow);
+
+        // THEN
+        assert_eq!(forward, Some(TaskStatus::Done));
+        assert_eq!(backward, Some(TaskStatus::Todo));
+        assert_eq!(before_first, None);
+    }
+
+    #[test]
+    fn save_body_replaces_body_and_keeps_frontmatter() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("body".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+
+        // WHEN
+        task.save_body("first line\nsecond line\n").unwrap();
+
+        // THEN
+        assert_eq!(task.load_body().unwrap(), "first line\nsecond line\n");
+        assert_eq!(task.reload().unwrap().name, "body");
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn load_body_up_to_refuses_files_over_the_limit() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("large".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        task.save_body(&"x".repeat(4096)).unwrap();
+
+        // WHEN
+        let within = task.load_body_up_to(8192).map(|body| body.len());
+        let over = task.load_body_up_to(1024).map(|body| body.len());
+
+        // THEN
+        assert_eq!(within.unwrap(), 4096);
+        assert_eq!(over.unwrap_err().kind(), io::ErrorKind::FileTooLarge);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn load_body_up_to_reports_multi_byte_body_over_the_limit_as_too_large() {
+        // GIVEN: a body whose last character straddles the limit
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("large".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        task.save_body(&"あ".repeat(1024)).unwrap();
+        let file_len = fs::metadata(task.file_path()).unwrap().len();
+
+        // WHEN
+        let over = task.load_body_up_to(file_len - 2);
+
+        // THEN
+        assert_eq!(over.unwrap_err().kind(), io::ErrorKind::FileTooLarge);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn referenced_ids_finds_hyphenated_uuids_once() {
+        // GIVEN
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let body = format!(
+            "see #{first}\nblocked by [{second}](../todo/{second}.md)\nagain {first}\n{}\n",
+            first.simple()
+        );
+
+        // WHEN
+        let actual = Task::referenced_ids(&body);
+
+        // THEN
+        assert_eq!(actual, vec![first, second]);
+    }
+
+    #[test]
+    fn checklist_counts_checked_and_unchecked_items() {
+        // GIVEN
+        let mixed = "Plan\n- [x] draft\n  - [ ] review\n* [X] send\n- [ ] merge\n- [link](x)\n";
+        let plain = "Just notes\n- bullet\n";
+
+        // WHEN
+        let actual = [mixed, plain].map(Task::checklist_of);
+
+        // THEN
+        assert_eq!(actual, [Some((2, 4)), None]);
+    }
+
+    #[test]
+    fn checklist_progress_follows_saved_body() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("checklist".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+
+        // WHEN
+        task.save_body("- [x] one\n- [ ] two\n").unwrap();
+        let reloaded = task.reload().unwrap();
+
+        // THEN
+        assert_eq!(task.checklist_progress(), Some((1, 2)));
+        assert_eq!(reloaded.checklist_progress(), Some((1, 2)));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn has_notes_reflects_body_content() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let with_notes = Task::new_in("with notes".to_string(), tasks_dir.clone());
+        let without_notes = Task::new_in("without notes".to_string(), tasks_dir.clone());
+        with_notes.save().unwrap();
+        without_notes.save().unwrap();
+        fs::write(
+            with_notes.file_path(),
+            format!(
+                "{}remember the milk\n",
+                fs::read_to_string(with_notes.file_path()).unwrap()
+            ),
+        )
+        .unwrap();
+
+        // WHEN
+        let actual = [&with_notes, &without_notes].map(|task| task.reload().unwrap().has_notes());
+
+        // THEN
+        assert_eq!(actual, [true, false]);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn time_in_status_sums_spans_from_history() {
+        // GIVEN
+        let at = |day: u32, hour: u32| {
+            NaiveDate::from_ymd_opt(2026, 6, day)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+        let mut task = Task::new("history".to_string());
+        task.history = [
+            (TaskStatus::Todo, at(1, 9)),
+            (TaskStatus::Doing, at(3, 9)),
+            (TaskStatus::Todo, at(3, 13)),
+            (TaskStatus::Doing, at(4, 9)),
+        ]
+        .into_iter()
+        .map(|(status, at)| StatusChange { status, at })
+        .collect();
+
+        // WHEN
+        let actual = task.time_in_status(at(4, 11));
+
+        // THEN
+        let expected = vec![
+            (TaskStatus::Todo, TimeDelta::hours(48 + 20)),
+            (TaskStatus::Doing, TimeDelta::hours(4 + 2)),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn update_status_appends_history_to_file() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("history file".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+
+        // WHEN
+        task.update_status(TaskStatus::Doing).unwrap();
+
+        // THEN
+        let loaded = task.reload().unwrap();
+        let actual = loaded
+            .history
+            .iter()
+            .map(|change| change.status)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, [TaskStatus::Todo, TaskStatus::Doing]);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn parking_file_path_contains_parking_directory() {
+        // GIVEN
+        let mut task = Task::new("parking path test".to_string());
+        task.status = TaskStatus::Parking;
+
+        // WHEN
+        let path = task.file_path();
+
+        // THEN
+        assert!(path.to_str().unwrap().contains("/parking/"));
+        assert!(path.to_str().unwrap().ends_with(&format!("{}.md", task.id)));
+    }
+
+    #[test]
+    fn from_name_accepts_directory_names_in_any_case() {
+        // GIVEN
+        let names = ["parking", "TODO", "Doing", "done", "later"];
+
+        // WHEN
+        let actual = names.map(TaskStatus::from_name);
+
+        // THEN
+        let expected = [
+            Some(TaskStatus::Parking),
+            Some(TaskStatus::Todo),
+            Some(TaskStatus::Doing),
+            Some(TaskStatus::Done),
+            None,
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn all_from_loads_every_status_in_lifecycle_order() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        for (name, status) in [
+            ("finished", TaskStatus::Done),
+            ("parked", TaskStatus::Parking),
+            ("started", TaskStatus::Doing),
+        ] {
+            let mut task = Task::new_in(name.to_string(), tasks_dir.clone());
+            task.save().unwrap();
+            task.update_status(status).unwrap();
+        }
+
+        // WHEN
+        let tasks = Task::all_from(&tasks_dir).unwrap();
+
+        // THEN
+        let actual = tasks
+            .iter()
+            .map(|task| (task.name.as_str(), task.status, task.base_dir()))
+            .collect::<Vec<_>>();
+        let expected = [
+            ("parked", TaskStatus::Parking, tasks_dir.as_path()),
+            ("started", TaskStatus::Doing, tasks_dir.as_path()),
+            ("finished", TaskStatus::Done, tasks_dir.as_path()),
+        ];
+        assert_eq!(actual, expected);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn purge_done_deletes_done_files_only() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut paths = Vec::new();
+        for status in [TaskStatus::Todo, TaskStatus::Doing, TaskStatus::Done] {
+            let mut task = Task::new_in(format!("{status:?}"), tasks_dir.clone());
+            task.save().unwrap();
+            task.update_status(status).unwrap();
+            paths.push(task.file_path());
+        }
+
+        // WHEN
+        let purged = Task::purge_done_from(&tasks_dir).unwrap();
+
+        // THEN
+        assert_eq!(purged, 1);
+        let actual = paths.iter().map(|path| path.exists()).collect::<Vec<_>>();
+        assert_eq!(actual, [true, true, false]);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn filter_by_status_returns_matching_tasks() {
+        // GIVEN: tasks with mixed statuses
+        let todo = Task::new("todo".to_string());
+        let mut doing = Task::new("doing".to_string());
+        doing.status = TaskStatus::Doing;
+        let tasks = vec![todo, doing];
+
+        // WHEN: filter_by_status is called with Todo
+        let filtered = Task::filter_by_status(&tasks, TaskStatus::Todo);
+
+        // THEN: only TODO tasks are returned
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].name, "todo");
+    }
+
+    #[test]
+    fn save_and_load_roundtrip() {
+        // GIVEN: a task saved to disk
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("roundtrip test".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+
+        // WHEN: Task::load is called with the file path
+        let loaded = Task::load(&task.file_path(), TaskStatus::Todo).unwrap();
+
+        // THEN: the loaded task matches the original
+        assert_eq!(loaded.id, task.id);
+        assert_eq!(loaded.name, "roundtrip test");
         assert_eq!(loaded.status, TaskStatus::Todo);
         assert_eq!(loaded.deadline, task.deadline);
         let content = fs::read_to_string(task.file_path()).unwrap();
@@ -755,6 +2213,191 @@ mod tests {
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
+    #[test]
+    fn json_frontmatter_round_trips_and_survives_status_change() {
+        // GIVEN: a task saved with JSON frontmatter and a body
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("json \"quoted\" task".to_string(), tasks_dir.clone());
+        task.frontmatter_format = FrontmatterFormat::Json;
+        task.reminders = vec![task.created_at];
+        task.save().unwrap();
+        task.save_body("## Notes\n- [ ] check\n").unwrap();
+
+        // WHEN
+        let loaded = Task::load(&task.file_path(), TaskStatus::Todo).unwrap();
+        let mut moved = loaded.clone();
+        moved.update_status(TaskStatus::Doing).unwrap();
+
+        // THEN
+        let content = fs::read_to_string(moved.file_path()).unwrap();
+        assert!(content.starts_with("{\n"));
+        assert_eq!(loaded.frontmatter_format, FrontmatterFormat::Json);
+        assert_eq!(loaded.id, task.id);
+        assert_eq!(loaded.name, task.name);
+        assert_eq!(loaded.deadline, task.deadline);
+        assert_eq!(loaded.reminders, task.reminders);
+        assert_eq!(loaded.checklist_progress(), Some((0, 1)));
+        assert_eq!(moved.load_body().unwrap(), "## Notes\n- [ ] check\n");
+        assert_eq!(moved.reload().unwrap().status, TaskStatus::Doing);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn toml_frontmatter_round_trips_and_survives_status_change() {
+        // GIVEN: a task saved with TOML frontmatter, history, tags, and a body
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("toml \"quoted\" task".to_string(), tasks_dir.clone());
+        task.frontmatter_format = FrontmatterFormat::Toml;
+        task.reminders = vec![task.created_at];
+        task.priority = Some(Priority::High);
+        task.add_tag("work");
+        task.save().unwrap();
+        task.save_body("## Notes\n- [x] check\n").unwrap();
+
+        // WHEN
+        let loaded = Task::load(&task.file_path(), TaskStatus::Todo).unwrap();
+        let mut moved = loaded.clone();
+        moved.update_status(TaskStatus::Doing).unwrap();
+
+        // THEN
+        let content = fs::read_to_string(moved.file_path()).unwrap();
+        assert!(content.starts_with("+++\n"));
+        assert_eq!(loaded.frontmatter_format, FrontmatterFormat::Toml);
+        assert_eq!(loaded.id, task.id);
+        assert_eq!(loaded.name, task.name);
+        assert_eq!(loaded.created_at, task.created_at);
+        assert_eq!(loaded.deadline, task.deadline);
+        assert_eq!(loaded.reminders, task.reminders);
+        assert_eq!(loaded.priority, task.priority);
+        assert_eq!(loaded.tags, task.tags);
+        assert_eq!(loaded.history, task.history);
+        assert_eq!(loaded.checklist_progress(), Some((1, 1)));
+        assert_eq!(moved.load_body().unwrap(), "## Notes\n- [x] check\n");
+        let reloaded = moved.reload().unwrap();
+        assert_eq!(reloaded.status, TaskStatus::Doing);
+        assert_eq!(reloaded.history.len(), 2);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn load_renames_file_to_match_frontmatter_id() {
+        // GIVEN: a task file copied by hand under another name
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("copied".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let copied = task.file_path().with_file_name("copy of task.md");
+        fs::rename(task.file_path(), &copied).unwrap();
+
+        // WHEN
+        let loaded = Task::load(&copied, TaskStatus::Todo).unwrap();
+
+        // THEN
+        assert_eq!(loaded.id, task.id);
+        assert!(!copied.exists());
+        assert!(loaded.file_path().exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn load_keeps_mismatched_file_when_id_name_is_taken() {
+        // GIVEN: a copy next to the original it was made from
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("original".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let copied = task.file_path().with_file_name("copy.md");
+        fs::copy(task.file_path(), &copied).unwrap();
+
+        // WHEN
+        let loaded = Task::load(&copied, TaskStatus::Todo).unwrap();
+
+        // THEN
+        assert_eq!(loaded.id, task.id);
+        assert!(copied.exists());
+        assert!(task.file_path().exists());
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn load_keeps_mismatched_file_when_directory_is_read_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // GIVEN: a hand-made copy in a directory that cannot be written
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("copied".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let copied = task.file_path().with_file_name("copy of task.md");
+        fs::rename(task.file_path(), &copied).unwrap();
+        let todo_dir = tasks_dir.join("todo");
+        fs::set_permissions(&todo_dir, fs::Permissions::from_mode(0o555)).unwrap();
+
+        // WHEN
+        let loaded = Task::load(&copied, TaskStatus::Todo);
+
+        // THEN: the task loads; unless permissions are bypassed, as for root, it stays put
+        let bypassed = fs::write(todo_dir.join("bypass"), "").is_ok();
+        assert_eq!(loaded.unwrap().id, task.id);
+        assert_eq!(copied.exists(), !bypassed);
+        fs::set_permissions(&todo_dir, fs::Permissions::from_mode(0o755)).unwrap();
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn reassign_id_renames_the_loaded_copy_not_the_original() {
+        // GIVEN: two files with the same id in one directory
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("original".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let copied = task.file_path().with_file_name("copy.md");
+        fs::copy(task.file_path(), &copied).unwrap();
+        let mut loaded = Task::load(&copied, TaskStatus::Todo).unwrap();
+
+        // WHEN
+        loaded.reassign_id().unwrap();
+
+        // THEN: the copy got the new id and the original is untouched
+        assert!(!copied.exists());
+        assert!(loaded.file_path().exists());
+        assert_ne!(loaded.id, task.id);
+        let original = Task::load(&task.file_path(), TaskStatus::Todo).unwrap();
+        assert_eq!(original.id, task.id);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn reassign_id_renames_file_and_keeps_content() {
+        // GIVEN: a saved task with a markdown body
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("reassign test".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let body = "## Notes\n\nkeep me\n";
+        let existing = fs::read_to_string(task.file_path()).unwrap();
+        fs::write(task.file_path(), format!("{}{}", existing, body)).unwrap();
+        let old_id = task.id;
+        let old_path = task.file_path();
+
+        // WHEN
+        task.reassign_id().unwrap();
+
+        // THEN: the file is renamed to the new id, which is also in its frontmatter
+        assert_ne!(task.id, old_id);
+        assert!(!old_path.exists());
+        let new_path = task.file_path();
+        assert_eq!(new_path, old_path.with_file_name(format!("{}.md", task.id)));
+        let loaded = Task::load(&new_path, TaskStatus::Todo).unwrap();
+        assert_eq!(loaded.id, task.id);
+        assert_eq!(loaded.name, "reassign test");
+        assert_eq!(loaded.load_body().unwrap(), body);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
     #[test]
     fn update_status_moves_file_between_directories() {
         // GIVEN: a saved task with TODO status and a markdown body appended to the file
@@ -917,6 +2560,63 @@ mod tests {
         fs::remove_dir_all(tasks_dir).unwrap();
     }
 
+    #[test]
+    fn load_by_status_loads_large_directories_in_parallel() {
+        // GIVEN: more files than the parallel threshold, split over two statuses
+        let tasks_dir = temporary_tasks_dir();
+        let base = NaiveDate::from_ymd_opt(2026, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        for index in 0..1000 {
+            let mut task = Task::new_in(format!("task {index}"), tasks_dir.clone());
+            task.created_at = base + TimeDelta::minutes(index);
+            task.status = if index % 2 == 0 {
+                TaskStatus::Todo
+            } else {
+                TaskStatus::Doing
+            };
+            task.save().unwrap();
+        }
+
+        // WHEN
+        let tasks =
+            Task::load_by_status(&tasks_dir, &[TaskStatus::Todo, TaskStatus::Doing]).unwrap();
+
+        // THEN: every task is loaded once, in created_at order, with its directory status
+        assert_eq!(tasks.len(), 1000);
+        assert!(tasks.iter().enumerate().all(|(index, task)| {
+            let expected_status = if index % 2 == 0 {
+                TaskStatus::Todo
+            } else {
+                TaskStatus::Doing
+            };
+            task.name == format!("task {index}") && task.status == expected_status
+        }));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn parallel_load_reports_invalid_task_file() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        for index in 0..PARALLEL_LOAD_THRESHOLD {
+            Task::new_in(format!("task {index}"), tasks_dir.clone())
+                .save()
+                .unwrap();
+        }
+        fs::write(tasks_dir.join("todo/invalid.md"), "invalid frontmatter").unwrap();
+
+        // WHEN
+        let result = Task::load_by_status(&tasks_dir, &[TaskStatus::Todo]);
+
+        // THEN
+        assert!(result.err().unwrap().to_string().contains("invalid.md"));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
     #[test]
     fn load_by_status_returns_error_for_invalid_task_file() {
         // GIVEN
@@ -935,4 +2635,155 @@ mod tests {
 
         fs::remove_dir_all(tasks_dir).unwrap();
     }
+
+    #[test]
+    fn load_entry_skips_file_removed_after_listing() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let missing = (tasks_dir.join("todo/removed.md"), TaskStatus::Todo, None);
+
+        // WHEN
+        let result = Task::load_entry(&missing);
+
+        // THEN
+        assert!(result.unwrap().is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn load_entry_reports_symlink_with_missing_target() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let todo_dir = tasks_dir.join("todo");
+        fs::create_dir_all(&todo_dir).unwrap();
+        let link = todo_dir.join("dangling.md");
+        std::os::unix::fs::symlink(tasks_dir.join("missing.md"), &link).unwrap();
+
+        // WHEN
+        let result = Task::load_entry(&(link.clone(), TaskStatus::Todo, None));
+
+        // THEN
+        let error = result.err().expect("dangling symlink should fail loading");
+        assert!(error.to_string().contains(link.to_str().unwrap()));
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn reminders_round_trip_sorted_and_default_to_empty() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let mut task = Task::new_in("reminded".to_string(), tasks_dir.clone());
+        let at = |hour| {
+            NaiveDate::from_ymd_opt(2026, 6, 15)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+        task.save().unwrap();
+        let without_reminders = task.reload().unwrap().reminders;
+        let saved_without_reminders = fs::read_to_string(task.file_path()).unwrap();
+
+        // WHEN
+        assert!(task.toggle_reminder(at(18)));
+        assert!(task.toggle_reminder(at(9)));
+        assert!(task.toggle_reminder(at(12)));
+        assert!(!task.toggle_reminder(at(12)));
+        task.save_metadata().unwrap();
+        let reloaded = task.reload().unwrap();
+
+        // THEN
+        assert!(without_reminders.is_empty());
+        assert!(!saved_without_reminders.contains("reminders"));
+        assert_eq!(reloaded.reminders, [at(9), at(18)]);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn hand_edited_priority_is_loaded_and_kept_on_save() {
+        // GIVEN
+        let tasks_dir = temporary_tasks_dir();
+        let task = Task::new_in("prioritized".to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        let saved_without_priority = fs::read_to_string(task.file_path()).unwrap();
+        let edited = saved_without_priority.replacen("---\n", "---\npriority: high\n", 1);
+        fs::write(task.file_path(), edited).unwrap();
+
+        // WHEN
+        let mut loaded = task.reload().unwrap();
+        loaded.name = "renamed".to_string();
+        loaded.save_metadata().unwrap();
+        let reloaded = task.reload().unwrap();
+
+        // THEN
+        assert_eq!(task.priority, None);
+        assert!(!saved_without_priority.contains("priority"));
+        assert_eq!(loaded.priority, Some(Priority::High));
+        assert_eq!(reloaded.priority, Some(Priority::High));
+        assert!(Priority::High > Priority::Medium && Priority::Medium > Priority::Low);
+
+        fs::remove_dir_all(tasks_dir).unwrap();
+    }
+
+    #[test]
+    fn tags_are_normalized_and_added_once() {
+        // GIVEN
+        let mut task = Task::new_in("tagged".to_string(), temporary_tasks_dir());
+
+        // WHEN
+        let normalized =
+            [" #work ", "work", "##work", "", "#", "two words"].map(Task::normalize_tag);
+        let first = task.add_tag("work");
+        let second = task.add_tag("work");
+        let removed = task.remove_tag("home");
+
+        // THEN
+        assert_eq!(
+            normalized,
+            [
+                Some("work".to_string()),
+                Some("work".to_string()),
+                Some("#work".to_string()),
+                None,
+                None,
+                None
+            ]
+        );
+        assert!(first);
+        assert!(!second);
+        assert!(!removed);
+        assert_eq!(task.tags, ["work"]);
+        assert!(task.matches("#WORK"));
+    }
+
+    #[test]
+    fn collect_tags_counts_each_distinct_tag_once_per_task() {
+        // GIVEN
+        let tagged = |tags: &[&str]| {
+            let mut task = Task::new_in("tagged".to_string(), temporary_tasks_dir());
+            task.tags = tags.iter().map(|tag| tag.to_string()).collect();
+            task
+        };
+        let tasks = vec![
+            tagged(&["work", "urgent"]),
+            tagged(&["home"]),
+            tagged(&["urgent", "work"]),
+            tagged(&[]),
+            tagged(&["work"]),
+        ];
+
+        // WHEN
+        let actual = Task::collect_tags(&tasks);
+
+        // THEN
+        assert_eq!(
+            actual,
+            [
+                ("home".to_string(), 1),
+                ("urgent".to_string(), 2),
+                ("work".to_string(), 3)
+            ]
+        );
+    }
 }
@@ -1,9 +1,11 @@
 use chrono::{Days, Local};
 use crossterm::event::KeyCode;
 use rem_cli::app::App;
-use rem_cli::task::{DEADLINE_DATE_FORMAT, TaskStatus};
+use rem_cli::task::{DEADLINE_DATE_FORMAT, Task, TaskStatus};
 use std::fs;
+use std::io::Write;
 use std::path::PathBuf;
+use std::process::{Command, Stdio};
 use uuid::Uuid;
 
 fn temporary_tasks_dir() -> PathBuf {
@@ -56,6 +58,36 @@ fn adding_task_creates_md_file() {
     fs::remove_dir_all(tasks_dir).unwrap();
 }
 
+/// Scenario: Adding a task whose md file cannot be written leaves nothing on disk.
+///
+/// Replaces the todo/ directory with a regular file so saving fails, then restores it.
+/// Verifies that nothing was written: a fresh App started on the repaired directory
+/// does not load the task either.
+#[test]
+fn adding_task_that_fails_to_save_is_not_added() {
+    // GIVEN: a tasks directory where todo/ is a regular file
+    let tasks_dir = temporary_tasks_dir();
+    fs::create_dir_all(&tasks_dir).unwrap();
+    fs::write(tasks_dir.join("todo"), "not a directory").unwrap();
+    let mut app = App::with_tasks_dir(tasks_dir.clone());
+
+    // WHEN: press 'a', type a task name, press Enter, then repair todo/ and restart
+    app.handle_key_event(KeyCode::Char('a'));
+    for c in "unsaved task".chars() {
+        app.handle_key_event(KeyCode::Char(c));
+    }
+    app.handle_key_event(KeyCode::Enter);
+    fs::remove_file(tasks_dir.join("todo")).unwrap();
+    fs::create_dir_all(tasks_dir.join("todo")).unwrap();
+    let reloaded = App::with_tasks_dir(tasks_dir.clone());
+
+    // THEN: no task file exists and the restarted app does not show the task
+    assert_eq!(fs::read_dir(tasks_dir.join("todo")).unwrap().count(), 0);
+    assert!(reloaded.tasks.iter().all(|t| t.name != "unsaved task"));
+
+    fs::remove_dir_all(tasks_dir).unwrap();
+}
+
 /// Scenario: Pressing 'n' moves the md file to the next status directory.
 ///
 /// Creates a task, then presses 'n' to forward status from TODO to DOING.
@@ -253,3 +285,443 @@ fn moving_task_to_hidden_done_selects_nearby_visible_task() {
 
     fs::remove_dir_all(tasks_dir).unwrap();
 }
+
+/// Scenario: `rem --version` and `rem --help` print to stdout without starting the TUI.
+#[test]
+fn version_and_help_flags_print_without_starting_tui() {
+    // GIVEN
+    let binary = env!("CARGO_BIN_EXE_rem");
+
+    // WHEN
+    let version = Command::new(binary).arg("--version").output().unwrap();
+    let help = Command::new(binary).arg("--help").output().unwrap();
+
+    // THEN
+    assert!(version.status.success());
+    assert_eq!(
+        String::from_utf8(version.stdout).unwrap(),
+        format!("rem {}\n", env!("CARGO_PKG_VERSION"))
+    );
+    assert!(help.status.success());
+    let help_output = String::from_utf8(help.stdout).unwrap();
+    assert!(help_output.contains("Usage: rem"));
+    assert!(help_output.contains("Keybindings:"));
+}
+
+/// Scenario: an unknown flag prints the help to stderr and exits with a failure status.
+#[test]
+fn unknown_flag_prints_help_to_stderr_and_fails() {
+    // GIVEN
+    let binary = env!("CARGO_BIN_EXE_rem");
+
+    // WHEN
+    let output = Command::new(binary).arg("--unknown").output().unwrap();
+
+    // THEN
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("unknown argument: --unknown"));
+    assert!(stderr.contains("Usage: rem"));
+}
+
+/// Scenario: Pressing 'D' on a DOING task completes it and moves its md file to archive/.
+#[test]
+fn complete_and_archive_moves_doing_file_to_archive_directory() {
+    // GIVEN: a task forwarded to DOING
+    let tasks_dir = temporary_tasks_dir();
+    let mut app = App::with_tasks_dir(tasks_dir.clone());
+    app.handle_key_event(KeyCode::Char('a'));
+    for c in "archive in one step".chars() {
+        app.handle_key_event(KeyCode::Char(c));
+    }
+    app.handle_key_event(KeyCode::Enter);
+    app.selected_index = app
+        .tasks
+        .iter()
+        .position(|task| task.name == "archive in one step");
+    app.handle_key_event(KeyCode::Char('n'));
+    let task_index = app
+        .tasks
+        .iter()
+        .position(|task| task.name == "archive in one step")
+        .unwrap();
+    app.selected_index = Some(task_index);
+    let doing_path = app.tasks[task_index].file_path();
+    let archive_path = tasks_dir
+        .join("archive")
+        .join(format!("{}.md", app.tasks[task_index].id));
+
+    // WHEN
+    app.handle_key_event(KeyCode::Char('D'));
+
+    // THEN
+    assert!(!doing_path.exists());
+    assert!(archive_path.exists());
+    assert!(
+        fs::read_to_string(&archive_path)
+            .unwrap()
+            .contains("completed_at:")
+    );
+    assert!(
+        app.tasks
+            .iter()
+            .all(|task| task.name != "archive in one step")
+    );
+
+    fs::remove_dir_all(tasks_dir).unwrap();
+}
+
+/// Scenario: `rem list --status todo,doing` prints TODO and DOING tasks but not PARKING or DONE.
+#[test]
+fn list_subcommand_prints_tasks_in_requested_statuses() {
+    // GIVEN: a home directory whose config points at tasks in every status
+    let home_dir = temporary_tasks_dir();
+    let tasks_dir = home_dir.join("tasks");
+    fs::create_dir_all(home_dir.join(".rem-cli")).unwrap();
+    fs::write(
+        home_dir.join(".rem-cli/config.yaml"),
+        format!("tasks_dir: \"{}\"\n", tasks_dir.display()),
+    )
+    .unwrap();
+    for (name, status) in [
+        ("parked", TaskStatus::Parking),
+        ("planned", TaskStatus::Todo),
+        ("started", TaskStatus::Doing),
+        ("finished", TaskStatus::Done),
+    ] {
+        let mut task = Task::new_in(name.to_string(), tasks_dir.clone());
+        task.save().unwrap();
+        task.update_status(status).unwrap();
+    }
+
+    // WHEN
+    let output = Command::new(env!("CARGO_BIN_EXE_rem"))
+        .args(["list", "--status", "doing,todo"])
+        .env("HOME", &home_dir)
+        .output()
+        .unwrap();
+
+    // THEN: TODO is listed before DOING
+    assert!(output.status.success());
+    let names = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| line.split('\t').map(str::to_string).collect::<Vec<_>>())
+        .map(|fields| (fields[0].clone(), fields[2].clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            ("TODO".to_string(), "planned".to_string()),
+            ("DOING".to_string(), "started".to_string()),
+        ]
+    );
+
+    fs::remove_dir_all(home_dir).unwrap();
+}
+
+/// Scenario: `rem list` on a directory that does not exist prints nothing and creates nothing.
+#[test]
+fn list_subcommand_does_not_create_task_directories() {
+    // GIVEN
+    let home_dir = temporary_tasks_dir();
+    let tasks_dir = home_dir.join("missing");
+
+    // WHEN
+    let output = Command::new(env!("CARGO_BIN_EXE_rem"))
+        .arg("--dir")
+        .arg(&tasks_dir)
+        .arg("list")
+        .env("HOME", &home_dir)
+        .output()
+        .unwrap();
+
+    // THEN
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(!tasks_dir.exists());
+
+    fs::remove_dir_all(home_dir).ok();
+}
+
+/// Scenario: `--dir` points each invocation at its own board, independent of the configured one.
+#[test]
+fn dir_flag_selects_independent_task_roots() {
+    // GIVEN: two boards with one task each and a home without a config
+    let home_dir = temporary_tasks_dir();
+    let boards = [home_dir.join("work"), home_dir.join("private")];
+    for (board, name) in boards.iter().zip(["work task", "private task"]) {
+        Task::new_in(name.to_string(), board.clone())
+            .save()
+            .unwrap();
+    }
+
+    // WHEN
+    let outputs = boards.clone().map(|board| {
+        let output = Command::new(env!("CARGO_BIN_EXE_rem"))
+            .arg("--dir")
+            .arg(&board)
+            .arg("list")
+            .env("HOME", &home_dir)
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    });
+
+    // THEN
+    assert!(outputs[0].contains("work task") && !outputs[0].contains("private task"));
+    assert!(outputs[1].contains("private task") && !outputs[1].contains("work task"));
+    assert!(!home_dir.join(".rem-cli/tasks").exists());
+
+    fs::remove_dir_all(home_dir).unwrap();
+}
+
+/// Scenario: `rem count` prints the number of TODO and DOING files, and `rem count todo` only TODO.
+#[test]
+fn count_subcommand_prints_number_of_task_files() {
+    // GIVEN: two TODO tasks, one DOING task, and one DONE task
+    let home_dir = temporary_tasks_dir();
+    let tasks_dir = home_dir.join("tasks");
+    for (index, status) in [
+        TaskStatus::Todo,
+        TaskStatus::Todo,
+        TaskStatus::Doing,
+        TaskStatus::Done,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let mut task = Task::new_in(format!("task {index}"), tasks_dir.clone());
+        task.save().unwrap();
+        task.update_status(status).unwrap();
+    }
+
+    // WHEN
+    let count = |extra: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_rem"))
+            .arg("--dir")
+            .arg(&tasks_dir)
+            .arg("count")
+            .args(extra)
+            .env("HOME", &home_dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // THEN
+    assert_eq!(count(&[]), "3\n");
+    assert_eq!(count(&["todo"]), "2\n");
+
+    fs::remove_dir_all(home_dir).unwrap();
+}
+
+/// Scenario: `rem add <NAME> --body -` creates a TODO task whose body is read from stdin.
+#[test]
+fn add_subcommand_reads_body_from_stdin() {
+    // GIVEN: an empty task directory and command output to capture
+    let home_dir = temporary_tasks_dir();
+    let tasks_dir = home_dir.join("tasks");
+
+    // WHEN
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rem"))
+        .arg("--dir")
+        .arg(&tasks_dir)
+        .args(["add", "captured output", "--body", "-"])
+        .env("HOME", &home_dir)
+        .stdin(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"line one\nline two\n")
+        .unwrap();
+    let status = child.wait().unwrap();
+
+    // THEN: the task file in todo/ holds the piped text as its body
+    assert!(status.success());
+    let tasks = Task::load_by_status(&tasks_dir, &[TaskStatus::Todo]).unwrap();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].name, "captured output");
+    assert_eq!(tasks[0].load_body().unwrap(), "line one\nline two\n");
+
+    fs::remove_dir_all(home_dir).unwrap();
+}
+
+/// Scenario: running `rem add --skip-duplicates` twice with the same name creates one task.
+#[test]
+fn add_subcommand_skips_duplicate_names() {
+    // GIVEN: an empty task directory
+    let home_dir = temporary_tasks_dir();
+    let tasks_dir = home_dir.join("tasks");
+    let add = || {
+        Command::new(env!("CARGO_BIN_EXE_rem"))
+            .arg("--dir")
+            .arg(&tasks_dir)
+            .args(["add", "weekly report", "--skip-duplicates"])
+            .env("HOME", &home_dir)
+            .output()
+            .unwrap()
+    };
+
+    // WHEN
+    let first = add();
+    let second = add();
+
+    // THEN: the second run reports the skip and only one task file exists
+    assert!(first.status.success());
+    assert!(second.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&first.stdout),
+        "1 created, 0 skipped\n"
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&second.stdout),
+        "0 created, 1 skipped\n"
+    );
+    let tasks = Task::load_by_status(&tasks_dir, &[TaskStatus::Todo]).unwrap();
+    assert_eq!(tasks.len(), 1);
+
+    fs::remove_dir_all(home_dir).unwrap();
+}
+
+/// Scenario: `rem doctor` passes on a healthy directory and fails once a file is renamed.
+#[test]
+fn doctor_subcommand_reports_problems_with_exit_code() {
+    // GIVEN: one healthy TODO task
+    let home_dir = temporary_tasks_dir();
+    let tasks_dir = home_dir.join("tasks");
+    let task = Task::new_in("healthy".to_string(), tasks_dir.clone());
+    task.save().unwrap();
+    let doctor = || {
+        Command::new(env!("CARGO_BIN_EXE_rem"))
+            .arg("--dir")
+            .arg(&tasks_dir)
+            .arg("doctor")
+            .env("HOME", &home_dir)
+            .output()
+            .unwrap()
+    };
+    let healthy = doctor();
+
+    // WHEN: the file no longer matches its id
+    fs::rename(
+        task.file_path(),
+        task.file_path().with_file_name("renamed.md"),
+    )
+    .unwrap();
+    let broken = doctor();
+
+    // THEN
+    assert!(healthy.status.success());
+    assert_eq!(broken.status.code(), Some(1));
+    let report = String::from_utf8(broken.stdout).unwrap();
+    assert!(report.contains("id mismatch: "));
+    assert!(report.contains(&task.id.to_string()));
+    assert!(report.contains("1 problem found"));
+
+    fs::remove_dir_all(home_dir).unwrap();
+}
+
+/// Scenario: `rem export-md` on a directory that does not exist prints empty sections and
+/// creates nothing.
+#[test]
+fn export_md_does_not_create_task_directories() {
+    // GIVEN
+    let home_dir = temporary_tasks_dir();
+    let tasks_dir = home_dir.join("missing");
+
+    // WHEN
+    let output = Command::new(env!("CARGO_BIN_EXE_rem"))
+        .arg("--dir")
+        .arg(&tasks_dir)
+        .arg("export-md")
+        .env("HOME", &home_dir)
+        .output()
+        .unwrap();
+
+    // THEN
+    assert!(output.status.success());
+    assert!(!tasks_dir.exists());
+
+    fs::remove_dir_all(home_dir).ok();
+}
+
+/// Scenario: `rem export-md --output` writes the whole document to a new directory, and a
+/// failed export leaves an existing file untouched. Only the output's directory is created.
+#[test]
+fn export_md_output_writes_file_atomically() {
+    // GIVEN
+    let home_dir = temporary_tasks_dir();
+    let tasks_dir = home_dir.join("tasks");
+    Task::new_in("exported".to_string(), tasks_dir.clone())
+        .save()
+        .unwrap();
+    let output = home_dir.join("reports").join("status.md");
+    let export = || {
+        Command::new(env!("CARGO_BIN_EXE_rem"))
+            .arg("--dir")
+            .arg(&tasks_dir)
+            .args(["export-md", "--output"])
+            .arg(&output)
+            .env("HOME", &home_dir)
+            .output()
+            .unwrap()
+    };
+
+    // WHEN
+    let written = export();
+    let exported = fs::read_to_string(&output).unwrap();
+    fs::remove_dir_all(tasks_dir.join("done")).ok();
+    fs::write(tasks_dir.join("done"), "not a directory").unwrap();
+    let failed = export();
+
+    // THEN
+    assert!(written.status.success());
+    assert!(written.stdout.is_empty());
+    assert!(exported.starts_with("## TODO"));
+    assert!(exported.contains("exported"));
+    assert!(exported.contains("## DONE"));
+    assert!(!tasks_dir.join("parking").exists());
+    assert_eq!(failed.status.code(), Some(1));
+    assert_eq!(fs::read_to_string(&output).unwrap(), exported);
+    assert_eq!(fs::read_dir(output.parent().unwrap()).unwrap().count(), 1);
+
+    fs::remove_dir_all(home_dir).unwrap();
+}
+
+/// Scenario: launching rem against a read-only task directory gets past the instance lock
+/// instead of exiting with "Failed to create lock file", so the board can open read-only.
+#[cfg(unix)]
+#[test]
+fn read_only_tasks_dir_skips_instance_lock() {
+    use std::os::unix::fs::PermissionsExt;
+
+    // GIVEN
+    let home_dir = temporary_tasks_dir();
+    let tasks_dir = home_dir.join("tasks");
+    for status in ["parking", "todo", "doing", "done", "archive"] {
+        fs::create_dir_all(tasks_dir.join(status)).unwrap();
+    }
+    fs::set_permissions(&tasks_dir, fs::Permissions::from_mode(0o555)).unwrap();
+
+    // WHEN: without a terminal the TUI itself cannot start, but the lock comes first
+    let output = Command::new(env!("CARGO_BIN_EXE_rem"))
+        .arg("--dir")
+        .arg(&tasks_dir)
+        .env("HOME", &home_dir)
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+
+    // THEN
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("Failed to create lock file"), "{stderr}");
+
+    fs::set_permissions(&tasks_dir, fs::Permissions::from_mode(0o755)).unwrap();
+    fs::remove_dir_all(home_dir).unwrap();
+}